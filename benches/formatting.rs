@@ -0,0 +1,149 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use humanly::{HumanNumber, HumanPercent, HumanSize, HumanTime};
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::Duration;
+
+const VALUES: u64 = 100_000;
+
+fn allocating(c: &mut Criterion) {
+    let mut group = c.benchmark_group("concise (String per call)");
+
+    group.bench_function("HumanNumber", |b| {
+        b.iter(|| {
+            for n in 0..VALUES {
+                black_box(HumanNumber::from(black_box(n * 7_919) as f64).concise());
+            }
+        })
+    });
+    group.bench_function("HumanSize", |b| {
+        b.iter(|| {
+            for n in 0..VALUES {
+                black_box(HumanSize::from(black_box(n * 104_729)).concise());
+            }
+        })
+    });
+    group.bench_function("HumanTime", |b| {
+        b.iter(|| {
+            for n in 0..VALUES {
+                black_box(HumanTime::from(Duration::from_secs(black_box(n))).concise());
+            }
+        })
+    });
+    group.bench_function("HumanPercent", |b| {
+        b.iter(|| {
+            for n in 0..VALUES {
+                black_box(HumanPercent::from(black_box(n as f64 / 1_000.0), 1).concise());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+fn reusing_buffer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("write_concise (reused buffer)");
+    let mut buf = String::with_capacity(64);
+
+    group.bench_function("HumanNumber", |b| {
+        b.iter(|| {
+            for n in 0..VALUES {
+                buf.clear();
+                HumanNumber::from(black_box(n * 7_919) as f64)
+                    .write_concise(&mut buf)
+                    .unwrap();
+                black_box(&buf);
+            }
+        })
+    });
+    group.bench_function("HumanSize", |b| {
+        b.iter(|| {
+            for n in 0..VALUES {
+                buf.clear();
+                HumanSize::from(black_box(n * 104_729))
+                    .write_concise(&mut buf)
+                    .unwrap();
+                black_box(&buf);
+            }
+        })
+    });
+    group.bench_function("HumanTime", |b| {
+        b.iter(|| {
+            for n in 0..VALUES {
+                buf.clear();
+                HumanTime::from(Duration::from_secs(black_box(n)))
+                    .write_concise(&mut buf)
+                    .unwrap();
+                black_box(&buf);
+            }
+        })
+    });
+    group.bench_function("HumanPercent", |b| {
+        b.iter(|| {
+            for n in 0..VALUES {
+                buf.clear();
+                write!(
+                    buf,
+                    "{:#}",
+                    HumanPercent::from(black_box(n as f64 / 1_000.0), 1)
+                )
+                .unwrap();
+                black_box(&buf);
+            }
+        })
+    });
+
+    group.finish();
+}
+
+// Values spread over every magnitude up to u64::MAX, so each unit is picked
+// about equally often.
+fn spread(n: u64) -> u64 {
+    n.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> (n % 64)
+}
+
+fn magnitudes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("unit selection (all magnitudes)");
+    let mut buf = String::with_capacity(64);
+
+    group.bench_function("HumanNumber", |b| {
+        b.iter(|| {
+            for n in 0..VALUES {
+                buf.clear();
+                HumanNumber::from(black_box(spread(n)) as f64)
+                    .write_concise(&mut buf)
+                    .unwrap();
+                black_box(&buf);
+            }
+        })
+    });
+    group.bench_function("HumanSize binary", |b| {
+        b.iter(|| {
+            for n in 0..VALUES {
+                buf.clear();
+                HumanSize::from(black_box(spread(n)))
+                    .binary()
+                    .write_concise(&mut buf)
+                    .unwrap();
+                black_box(&buf);
+            }
+        })
+    });
+    group.bench_function("HumanSize decimal", |b| {
+        b.iter(|| {
+            for n in 0..VALUES {
+                buf.clear();
+                HumanSize::from(black_box(spread(n)))
+                    .decimal()
+                    .write_concise(&mut buf)
+                    .unwrap();
+                black_box(&buf);
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, allocating, reusing_buffer, magnitudes);
+criterion_main!(benches);
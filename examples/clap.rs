@@ -0,0 +1,38 @@
+//! Run with `cargo run --example clap --features clap -- --max-size 512MiB --timeout 1h30m`.
+
+use clap::{Arg, Command};
+use humanly::clap::{DurationValueParser, SizeValueParser};
+use humanly::{HumanSize, HumanTime};
+use std::time::Duration;
+
+fn main() {
+    let matches = Command::new("limits")
+        .about("Prints the configured limits in human-readable form")
+        .arg(
+            Arg::new("max-size")
+                .long("max-size")
+                .help("Largest file to accept, e.g. 512MiB or 1.5GB")
+                .value_parser(SizeValueParser::new())
+                .default_value("512MiB"),
+        )
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .help("How long to wait, e.g. 1h30m or 90s")
+                .value_parser(DurationValueParser::new())
+                .default_value("30s"),
+        )
+        .get_matches();
+
+    let max_size = *matches.get_one::<u64>("max-size").expect("has a default");
+    let timeout = *matches
+        .get_one::<Duration>("timeout")
+        .expect("has a default");
+
+    println!(
+        "max size: {} ({} bytes)",
+        HumanSize::from(max_size),
+        max_size
+    );
+    println!("timeout:  {}", HumanTime::from(timeout));
+}
@@ -0,0 +1,48 @@
+#ifndef HUMANLY_H
+#define HUMANLY_H
+
+/* Generated by cbindgen from src/ffi.rs; do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// A byte count in binary units: "5 MiB".
+//
+// # Safety
+//
+// `buf` must be null with `len` zero, or valid for writes of `len` bytes.
+size_t humanly_size(uint64_t bytes, char *buf, size_t len);
+
+// A number: "1.8M". NaN and infinities render as "-".
+//
+// # Safety
+//
+// `buf` must be null with `len` zero, or valid for writes of `len` bytes.
+size_t humanly_number(double number, char *buf, size_t len);
+
+// A duration in seconds: "1h 30m".
+//
+// # Safety
+//
+// `buf` must be null with `len` zero, or valid for writes of `len` bytes.
+size_t humanly_time_secs(double secs, char *buf, size_t len);
+
+// The time since a Unix timestamp in seconds, as from `time(NULL)`: "5m ago".
+//
+// # Safety
+//
+// `buf` must be null with `len` zero, or valid for writes of `len` bytes.
+size_t humanly_ago_unix_secs(int64_t unix_secs, char *buf, size_t len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* HUMANLY_H */
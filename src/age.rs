@@ -0,0 +1,103 @@
+use std::fmt::{self, Write};
+use std::time::SystemTime;
+
+use crate::core::{HumanFormat, Style, human_display, typeset};
+use crate::date::CivilDateTime;
+use crate::locale::{Locale, TimeUnit};
+
+/* -------------------- HumanAge -------------------- */
+
+/// Ages from a birth date: "34y" concise, "34 years old" full.
+///
+/// Years and months are counted on the calendar, so an age goes up on the
+/// birthday itself; people born on 29 February turn a year older on 1 March in
+/// common years. From two years the age is given in years, from two months in
+/// months, from two weeks in weeks, and in days below that. Birth dates in the
+/// future render as "not yet born" ("-" concise).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HumanAge {
+    pub(crate) birth: SystemTime,
+    now: Option<SystemTime>,
+    offset_minutes: i32,
+}
+
+human_display!(HumanAge);
+
+impl HumanAge {
+    pub fn from(birth: SystemTime) -> Self {
+        Self {
+            birth,
+            now: None,
+            offset_minutes: 0,
+        }
+    }
+
+    /// Compute the age at `now` instead of the current time.
+    pub fn as_of(mut self, now: SystemTime) -> Self {
+        self.now = Some(now);
+        self
+    }
+
+    /// Offset from UTC in minutes of the calendar that birthdays follow, e.g.
+    /// `-300` for US Eastern Standard Time.
+    pub fn utc_offset(mut self, minutes: i32) -> Self {
+        self.offset_minutes = minutes;
+        self
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    // Age in the largest fitting unit, or `None` before birth.
+    fn age(&self) -> Option<(i64, TimeUnit)> {
+        let now = self.now.unwrap_or_else(SystemTime::now);
+        let birth = CivilDateTime::from_system_time(self.birth, self.offset_minutes);
+        let now = CivilDateTime::from_system_time(now, self.offset_minutes);
+
+        let days = now.days - birth.days;
+        if days < 0 {
+            return None;
+        }
+        let months = (now.year - birth.year) * 12 + now.month as i64
+            - birth.month as i64
+            - i64::from(now.day < birth.day);
+
+        Some(if months >= 24 {
+            (months / 12, TimeUnit::Year)
+        } else if months >= 2 {
+            (months, TimeUnit::Month)
+        } else if days >= 14 {
+            (days / 7, TimeUnit::Week)
+        } else {
+            (days, TimeUnit::Day)
+        })
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        let Some((count, unit)) = self.age() else {
+            return w.write_str(match style {
+                Style::Concise => "-",
+                Style::Full => "not yet born",
+            });
+        };
+
+        match style {
+            Style::Concise => write!(w, "{}{}", count, unit.symbol()),
+            Style::Full => write!(
+                w,
+                "{} {} old",
+                count,
+                Locale::English.time_unit(unit, count == 1)
+            ),
+        }
+    }
+}
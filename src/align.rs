@@ -0,0 +1,104 @@
+use std::fmt::Display;
+
+use crate::config::decimal_mark;
+use crate::core::Style;
+
+/* -------------------- Column alignment -------------------- */
+
+// A formatted value split where the number ends: "976.6 KiB" -> ("976.6", " KiB").
+struct Cell {
+    number: String,
+    unit: String,
+}
+
+impl Cell {
+    fn split(text: String) -> Self {
+        let Some(first_digit) = text.find(|c: char| c.is_ascii_digit()) else {
+            // "-", "just now": no number to line up, keep it whole
+            return Self {
+                number: text,
+                unit: String::new(),
+            };
+        };
+        let end = text[first_digit..]
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+            .map_or(text.len(), |i| first_digit + i);
+
+        Self {
+            unit: text[end..].to_string(),
+            number: {
+                let mut number = text;
+                number.truncate(end);
+                number
+            },
+        }
+    }
+}
+
+fn width(s: &str) -> usize {
+    s.chars().count()
+}
+
+fn format_cells<T: Display>(values: &[T], style: Style) -> Vec<Cell> {
+    values
+        .iter()
+        .map(|value| {
+            Cell::split(match style {
+                Style::Concise => format!("{:#}", value),
+                Style::Full => format!("{}", value),
+            })
+        })
+        .collect()
+}
+
+/// Formats `values` for a table column: every string has the same width, the
+/// numbers are right-aligned and the units after them left-aligned.
+///
+/// ```rust
+/// use humanly::{HumanSize, Style, align};
+///
+/// let sizes = [HumanSize::from(1_000_000), HumanSize::from(5_242_880)];
+/// assert_eq!(align(&sizes, Style::Concise), ["976.6 KiB", "    5 MiB"]);
+/// ```
+pub fn align<T: Display>(values: &[T], style: Style) -> Vec<String> {
+    let cells = format_cells(values, style);
+    let number_width = cells.iter().map(|c| width(&c.number)).max().unwrap_or(0);
+    let unit_width = cells.iter().map(|c| width(&c.unit)).max().unwrap_or(0);
+
+    cells
+        .iter()
+        .map(|c| format!("{:>number_width$}{:<unit_width$}", c.number, c.unit))
+        .collect()
+}
+
+/// Like [`align`], but lines up the decimal points, padding missing fractions
+/// with spaces.
+///
+/// ```rust
+/// use humanly::{HumanSize, Style, align_decimal};
+///
+/// let sizes = [HumanSize::from(1_000_000), HumanSize::from(5_242_880)];
+/// assert_eq!(align_decimal(&sizes, Style::Concise), ["976.6 KiB", "  5   MiB"]);
+/// ```
+pub fn align_decimal<T: Display>(values: &[T], style: Style) -> Vec<String> {
+    let mark = decimal_mark(None, style);
+    let cells: Vec<(String, String, String)> = format_cells(values, style)
+        .into_iter()
+        .map(|c| {
+            let split = c.number.rfind(mark).unwrap_or(c.number.len());
+            let (integer, fraction) = c.number.split_at(split);
+            (integer.to_string(), fraction.to_string(), c.unit)
+        })
+        .collect();
+
+    let integer_width = cells.iter().map(|c| width(&c.0)).max().unwrap_or(0);
+    let fraction_width = cells.iter().map(|c| width(&c.1)).max().unwrap_or(0);
+    let unit_width = cells.iter().map(|c| width(&c.2)).max().unwrap_or(0);
+
+    cells
+        .iter()
+        .map(|(integer, fraction, unit)| {
+            format!("{integer:>integer_width$}{fraction:<fraction_width$}{unit:<unit_width$}")
+        })
+        .collect()
+}
@@ -0,0 +1,75 @@
+//! Conversions from [`bigdecimal`](https://docs.rs/bigdecimal) amounts,
+//! enabled with the `bigdecimal` feature.
+//!
+//! The types' own `from` constructors take primitive numbers, so convert
+//! with `into`. The unit and the displayed digits are worked out on the
+//! decimal itself, so values that `f64` can't hold still round correctly:
+//!
+//! ```rust
+//! use bigdecimal::BigDecimal;
+//! use humanly::HumanNumber;
+//!
+//! let amount: BigDecimal = "-2449999.99999999999999999999999999999999999".parse().unwrap();
+//! let number: HumanNumber = amount.into();
+//! assert_eq!(number.concise(), "-2.4M");
+//! ```
+
+use std::num::NonZeroU64;
+
+use ::bigdecimal::{BigDecimal, RoundingMode};
+
+use crate::decimal::Exact;
+use crate::{HumanNumber, HumanPercent};
+
+// Digits kept from longer values: well past anything displayed, and short
+// enough for an `i128`.
+const PRECISION: NonZeroU64 = NonZeroU64::new(37).unwrap();
+
+// `None` for exponents past `i32`, which are left to `f64`.
+fn exact(value: &BigDecimal) -> Option<Exact> {
+    // Truncating keeps rounding exact: what is cut off can only push the
+    // value away from zero, which half-away-from-zero rounding already does
+    // at a tie.
+    let value = value.with_precision_round(PRECISION, RoundingMode::Down);
+    let (digits, scale) = value.as_bigint_and_exponent();
+    Some(Exact {
+        mantissa: i128::try_from(digits).ok()?,
+        exponent: i32::try_from(-scale).ok()?,
+    })
+}
+
+fn to_f64(value: &BigDecimal) -> f64 {
+    value.to_string().parse().unwrap_or(f64::NAN)
+}
+
+impl From<BigDecimal> for HumanNumber {
+    fn from(value: BigDecimal) -> Self {
+        (&value).into()
+    }
+}
+
+impl From<&BigDecimal> for HumanNumber {
+    fn from(value: &BigDecimal) -> Self {
+        match exact(value) {
+            Some(exact) => HumanNumber::from_exact(exact),
+            None => HumanNumber::from(to_f64(value)),
+        }
+    }
+}
+
+/// A percentage with one decimal place: "12.5%".
+impl From<BigDecimal> for HumanPercent {
+    fn from(value: BigDecimal) -> Self {
+        (&value).into()
+    }
+}
+
+/// A percentage with one decimal place: "12.5%".
+impl From<&BigDecimal> for HumanPercent {
+    fn from(value: &BigDecimal) -> Self {
+        match exact(value) {
+            Some(exact) => HumanPercent::from_exact(exact, 1),
+            None => HumanPercent::from(to_f64(value), 1),
+        }
+    }
+}
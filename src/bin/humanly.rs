@@ -0,0 +1,179 @@
+//! Command-line front end, built with the `cli` feature:
+//!
+//! ```text
+//! $ humanly size 5242880
+//! 5 MiB
+//! $ humanly number 1800000 --full
+//! 1.8 million
+//! $ printf '90\n3661\n' | humanly time
+//! 1m 30s
+//! 1h 1m 1s
+//! ```
+//!
+//! Values come from the arguments, or one per line on stdin when none are
+//! given. Invalid values are reported on stderr and make the exit status 1;
+//! the remaining values are still printed.
+
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+use std::time::{Duration, UNIX_EPOCH};
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use humanly::{HumanDuration, HumanNumber, HumanPercent, HumanSize, HumanTime, Style};
+
+fn command() -> Command {
+    let values = Arg::new("values")
+        .help("Values to format; read from stdin, one per line, when omitted")
+        .num_args(0..);
+    let concise = Arg::new("concise")
+        .long("concise")
+        .help("Short form, e.g. \"5 MiB\" (the default)")
+        .action(ArgAction::SetTrue)
+        .global(true);
+    let full = Arg::new("full")
+        .long("full")
+        .help("Long form, e.g. \"5 mebibytes\"")
+        .action(ArgAction::SetTrue)
+        .conflicts_with("concise")
+        .global(true);
+
+    Command::new("humanly")
+        .about("Formats numbers, sizes and durations for humans")
+        .version(env!("CARGO_PKG_VERSION"))
+        .subcommand_required(true)
+        .arg(concise)
+        .arg(full)
+        .subcommand(
+            Command::new("size")
+                .about("Byte counts: 5242880 -> 5 MiB")
+                .arg(
+                    Arg::new("decimal")
+                        .long("decimal")
+                        .help("Use SI units (kB, MB, …) instead of KiB, MiB, …")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(values.clone()),
+        )
+        .subcommand(
+            Command::new("number")
+                .about("Large numbers: 1800000 -> 1.8M")
+                .arg(values.clone()),
+        )
+        .subcommand(
+            Command::new("time")
+                .about("Durations in seconds: 3661 -> 1h 1m 1s")
+                .arg(values.clone()),
+        )
+        .subcommand(
+            Command::new("ago")
+                .about("Unix timestamps relative to now, e.g. 3h ago")
+                .arg(values.clone()),
+        )
+        .subcommand(
+            Command::new("percent")
+                .about("Percentages: 12.3456 -> 12.3%")
+                .arg(
+                    Arg::new("precision")
+                        .long("precision")
+                        .help("Decimal places to round to")
+                        .value_parser(clap::value_parser!(usize))
+                        .default_value("1"),
+                )
+                .arg(values),
+        )
+}
+
+// Formats one input value, or explains why it can't be.
+fn format(name: &str, matches: &ArgMatches, input: &str, style: Style) -> Result<String, String> {
+    let mut out = String::new();
+    let invalid = |kind: &str| format!("invalid {} '{}'", kind, input);
+    let written = match name {
+        "size" => {
+            let bytes: u64 = input.parse().map_err(|_| invalid("byte count"))?;
+            let size = HumanSize::from(bytes);
+            let size = if matches.get_flag("decimal") {
+                size.decimal()
+            } else {
+                size.binary()
+            };
+            size.write_into(&mut out, style)
+        }
+        "number" => {
+            let number: f64 = input.parse().map_err(|_| invalid("number"))?;
+            HumanNumber::from(number).write_into(&mut out, style)
+        }
+        "time" => {
+            let secs: f64 = input.parse().map_err(|_| invalid("number of seconds"))?;
+            let duration = Duration::try_from_secs_f64(secs).map_err(|_| invalid("duration"))?;
+            HumanTime::from(duration).write_into(&mut out, style)
+        }
+        "ago" => {
+            let secs: f64 = input.parse().map_err(|_| invalid("timestamp"))?;
+            let offset =
+                Duration::try_from_secs_f64(secs.abs()).map_err(|_| invalid("timestamp"))?;
+            let time = if secs < 0.0 {
+                UNIX_EPOCH.checked_sub(offset)
+            } else {
+                UNIX_EPOCH.checked_add(offset)
+            };
+            let time = time.ok_or_else(|| invalid("timestamp"))?;
+            HumanDuration::from(Some(time)).write_into(&mut out, style)
+        }
+        "percent" => {
+            let value: f64 = input.parse().map_err(|_| invalid("number"))?;
+            let precision = *matches
+                .get_one::<usize>("precision")
+                .expect("has a default");
+            HumanPercent::from(value, precision).write_into(&mut out, style)
+        }
+        _ => unreachable!("subcommand_required"),
+    };
+    written.expect("writing to a String cannot fail");
+    Ok(out)
+}
+
+fn main() -> ExitCode {
+    let matches = command().get_matches();
+    let style = if matches.get_flag("full") {
+        Style::Full
+    } else {
+        Style::Concise
+    };
+    let (name, sub) = matches.subcommand().expect("subcommand_required");
+
+    let inputs: Box<dyn Iterator<Item = io::Result<String>>> =
+        match sub.get_many::<String>("values") {
+            Some(values) => Box::new(values.cloned().map(Ok).collect::<Vec<_>>().into_iter()),
+            None => Box::new(io::stdin().lock().lines()),
+        };
+
+    let mut stdout = io::stdout().lock();
+    let mut status = ExitCode::SUCCESS;
+    for input in inputs {
+        let input = match input {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("humanly: {}", err);
+                return ExitCode::FAILURE;
+            }
+        };
+        let input = input.trim();
+        if input.is_empty() {
+            continue;
+        }
+
+        match format(name, sub, input, style) {
+            Ok(formatted) => {
+                // A closed pipe (`humanly ... | head`) just ends the output
+                if writeln!(stdout, "{}", formatted).is_err() {
+                    return status;
+                }
+            }
+            Err(message) => {
+                eprintln!("humanly {}: {}", name, message);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    status
+}
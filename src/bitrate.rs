@@ -0,0 +1,112 @@
+use std::fmt::{self, Write};
+use std::time::Duration;
+
+use crate::config::decimal_mark;
+use crate::core::{HumanFormat, Style, human_display, scale_units, typeset, write_trimmed};
+
+/* -------------------- HumanBitrate -------------------- */
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BitrateSystem {
+    Decimal, // SI, 1000-based
+    Binary,  // IEC, 1024-based
+}
+
+/// Network throughput: "2.4 Mbps" concise, "2.4 megabits per second" full.
+///
+/// Unlike [`HumanSize`](crate::HumanSize), this defaults to decimal units
+/// because link speeds are conventionally quoted in powers of 1000. A rate
+/// that can't be computed (a transfer over a zero duration) renders as "-".
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HumanBitrate {
+    pub(crate) bits_per_sec: Option<f64>,
+    system: BitrateSystem,
+}
+
+human_display!(HumanBitrate);
+
+impl HumanBitrate {
+    pub fn from_bits_per_sec(bits_per_sec: u64) -> Self {
+        Self {
+            bits_per_sec: Some(bits_per_sec as f64),
+            system: BitrateSystem::Decimal,
+        }
+    }
+
+    pub fn from_bytes_per_sec(bytes_per_sec: u64) -> Self {
+        Self {
+            bits_per_sec: Some(bytes_per_sec as f64 * 8.0),
+            system: BitrateSystem::Decimal,
+        }
+    }
+
+    /// Average rate of `bytes` transferred over `elapsed`.
+    pub fn from_transfer(bytes: u64, elapsed: Duration) -> Self {
+        let secs = elapsed.as_secs_f64();
+        Self {
+            bits_per_sec: (secs > 0.0).then(|| bytes as f64 * 8.0 / secs),
+            system: BitrateSystem::Decimal,
+        }
+    }
+
+    pub fn decimal(mut self) -> Self {
+        self.system = BitrateSystem::Decimal;
+        self
+    }
+
+    pub fn binary(mut self) -> Self {
+        self.system = BitrateSystem::Binary;
+        self
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        let Some(bits_per_sec) = self.bits_per_sec else {
+            return w.write_str("-");
+        };
+
+        let (units_short, units_full, step) = match self.system {
+            BitrateSystem::Decimal => (
+                ["bps", "kbps", "Mbps", "Gbps", "Tbps", "Pbps", "Ebps"],
+                [
+                    "bit", "kilobit", "megabit", "gigabit", "terabit", "petabit", "exabit",
+                ],
+                1000.0,
+            ),
+            BitrateSystem::Binary => (
+                [
+                    "bit/s", "Kibit/s", "Mibit/s", "Gibit/s", "Tibit/s", "Pibit/s", "Eibit/s",
+                ],
+                [
+                    "bit", "kibibit", "mebibit", "gibibit", "tebibit", "pebibit", "exbibit",
+                ],
+                1024.0,
+            ),
+        };
+
+        let (rounded, idx) = scale_units(bits_per_sec, step, units_short.len(), 1);
+        write_trimmed(w, rounded, 1, decimal_mark(None, style))?;
+
+        match style {
+            Style::Concise => write!(w, " {}", units_short[idx]),
+            Style::Full => {
+                write!(w, " {}", units_full[idx])?;
+                if rounded != 1.0 {
+                    w.write_char('s')?;
+                }
+                w.write_str(" per second")
+            }
+        }
+    }
+}
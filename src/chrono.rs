@@ -0,0 +1,47 @@
+//! Conversions from [`chrono`](https://docs.rs/chrono) types, enabled with the
+//! `chrono` feature.
+//!
+//! The types' own `from` constructors take std values, so convert with `into`:
+//!
+//! ```rust
+//! use chrono::TimeDelta;
+//! use humanly::HumanTime;
+//!
+//! let time: HumanTime = TimeDelta::minutes(-90).into();
+//! assert_eq!(time.concise(), "-1h 30m");
+//! ```
+
+use std::time::{Duration, SystemTime};
+
+use ::chrono::{DateTime, NaiveDate, TimeDelta, TimeZone};
+
+use crate::{HumanDate, HumanDuration, HumanTime};
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for HumanDuration {
+    fn from(date_time: DateTime<Tz>) -> Self {
+        HumanDuration::from(Some(SystemTime::from(date_time)))
+    }
+}
+
+/// Negative deltas keep their sign: "-1h 30m".
+impl From<TimeDelta> for HumanTime {
+    fn from(delta: TimeDelta) -> Self {
+        let duration = delta.abs().to_std().unwrap_or(Duration::MAX);
+        HumanTime::from_signed(duration, delta < TimeDelta::zero())
+    }
+}
+
+impl<Tz: TimeZone> From<DateTime<Tz>> for HumanDate {
+    fn from(date_time: DateTime<Tz>) -> Self {
+        HumanDate::from(SystemTime::from(date_time))
+    }
+}
+
+/// The date at midnight UTC.
+impl From<NaiveDate> for HumanDate {
+    fn from(date: NaiveDate) -> Self {
+        HumanDate::from(SystemTime::from(
+            date.and_time(Default::default()).and_utc(),
+        ))
+    }
+}
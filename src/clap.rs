@@ -0,0 +1,73 @@
+//! [`clap`](https://docs.rs/clap) value parsers, enabled with the `clap` feature.
+//!
+//! ```rust
+//! use clap::{Arg, Command};
+//! use humanly::clap::{DurationValueParser, SizeValueParser};
+//! use std::time::Duration;
+//!
+//! let matches = Command::new("app")
+//!     .arg(Arg::new("max-size").long("max-size").value_parser(SizeValueParser::new()))
+//!     .arg(Arg::new("timeout").long("timeout").value_parser(DurationValueParser::new()))
+//!     .get_matches_from(["app", "--max-size", "512MiB", "--timeout", "1h30m"]);
+//!
+//! assert_eq!(matches.get_one::<u64>("max-size"), Some(&536_870_912));
+//! assert_eq!(matches.get_one::<Duration>("timeout"), Some(&Duration::from_secs(5400)));
+//! ```
+
+use std::ffi::OsStr;
+use std::time::Duration;
+
+use ::clap::builder::{NonEmptyStringValueParser, TypedValueParser};
+use ::clap::{Arg, Command, Error};
+
+use crate::parse::{parse_duration, parse_size};
+
+/// Parses sizes such as `512MiB`, `1.5 GB` or `2048` into a `u64` byte count.
+///
+/// Both SI (`kB`, `MB`, …) and binary (`KiB`, `MiB`, …) suffixes are accepted;
+/// a bare number is a count of bytes.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SizeValueParser;
+
+impl SizeValueParser {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl TypedValueParser for SizeValueParser {
+    type Value = u64;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<u64, Error> {
+        NonEmptyStringValueParser::new()
+            .try_map(|s| parse_size(&s))
+            .parse_ref(cmd, arg, value)
+    }
+}
+
+/// Parses durations such as `1h30m`, `90s` or `2h 15m` into a `Duration`.
+///
+/// Accepts `d`, `h`, `m` and `s` components; a bare number is a count of seconds.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DurationValueParser;
+
+impl DurationValueParser {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl TypedValueParser for DurationValueParser {
+    type Value = Duration;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Duration, Error> {
+        NonEmptyStringValueParser::new()
+            .try_map(|s| parse_duration(&s))
+            .parse_ref(cmd, arg, value)
+    }
+}
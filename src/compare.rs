@@ -0,0 +1,180 @@
+use std::fmt::{self, Write};
+use std::time::Duration;
+
+use crate::config::decimal_mark;
+use crate::core::{HumanFormat, Style, human_display, typeset, write_trimmed};
+use crate::{HumanSize, HumanTime};
+
+/* -------------------- HumanComparison -------------------- */
+
+/// How one value compares with another: "2.3× larger", "12% smaller",
+/// "about the same".
+///
+/// The first value is described relative to the second, the baseline: the
+/// ratio is `value / baseline`, so `numbers(230, 100)` is "2.3× larger" and
+/// `numbers(88, 100)` is "12% smaller".
+///
+/// Ratios of at least the [`threshold`](HumanComparison::threshold) (default
+/// 2) are written as a factor, smaller differences as a percentage. Decreases
+/// past the same factor read "2.5× smaller" in full and as the ratio, "0.4×",
+/// concisely. Within [`same_within`](HumanComparison::same_within) percent
+/// (default 1) the values are "about the same" ("≈1×").
+///
+/// A zero baseline makes any other value "infinitely larger" ("+∞"), and two
+/// zeros are the same. Values of opposite signs or that aren't finite have no
+/// meaningful ratio and render as "-".
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HumanComparison {
+    value: f64,
+    baseline: f64,
+    threshold: f64,
+    same_within: f64,
+    more: &'static str,
+    less: &'static str,
+}
+
+human_display!(HumanComparison);
+
+impl HumanComparison {
+    /// Compares `value` with `baseline`.
+    pub fn numbers(value: impl Into<f64>, baseline: impl Into<f64>) -> Self {
+        Self {
+            value: value.into(),
+            baseline: baseline.into(),
+            threshold: 2.0,
+            same_within: 1.0,
+            more: "larger",
+            less: "smaller",
+        }
+    }
+
+    /// Compares byte counts: `sizes(2_300, 1_000)` is "2.3× larger".
+    pub fn sizes(value: u64, baseline: u64) -> Self {
+        Self::numbers(value as f64, baseline as f64)
+    }
+
+    /// Compares durations, which read "longer" and "shorter":
+    /// `times(3s, 2s)` is "50% longer".
+    pub fn times(value: Duration, baseline: Duration) -> Self {
+        Self::numbers(value.as_secs_f64(), baseline.as_secs_f64()).words("longer", "shorter")
+    }
+
+    /// Ratio from which the difference is written as a factor rather than a
+    /// percentage (default 2, at least 1).
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = if threshold >= 1.0 { threshold } else { 1.0 };
+        self
+    }
+
+    /// Percentage difference still considered "about the same" (default 1).
+    /// Zero only treats equal values as the same.
+    pub fn same_within(mut self, percent: f64) -> Self {
+        self.same_within = if percent >= 0.0 { percent } else { 0.0 };
+        self
+    }
+
+    /// Words for an increase and a decrease (default "larger" and
+    /// "smaller"), e.g. `words("slower", "faster")` for benchmark timings.
+    pub fn words(mut self, more: &'static str, less: &'static str) -> Self {
+        self.more = more;
+        self.less = less;
+        self
+    }
+
+    /// `value / baseline`, or `None` when the values can't be compared.
+    /// A zero baseline gives infinity unless the value is zero too.
+    pub fn ratio(&self) -> Option<f64> {
+        let (value, baseline) = (self.value, self.baseline);
+        if !value.is_finite() || !baseline.is_finite() || value * baseline < 0.0 {
+            return None;
+        }
+        match (value == 0.0, baseline == 0.0) {
+            (true, true) => Some(1.0),
+            (false, true) => Some(f64::INFINITY),
+            _ => Some(value.abs() / baseline.abs()),
+        }
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        let Some(ratio) = self.ratio() else {
+            return w.write_char('-');
+        };
+        let mark = decimal_mark(None, style);
+        let concise = style == Style::Concise;
+
+        let percent = (ratio - 1.0) * 100.0;
+        if percent.abs() <= self.same_within {
+            return w.write_str(if concise { "≈1×" } else { "about the same" });
+        }
+
+        if ratio.is_infinite() {
+            return if concise {
+                w.write_str("+∞")
+            } else {
+                write!(w, "infinitely {}", self.more)
+            };
+        }
+
+        let round = |v: f64| (v * 10.0).round() / 10.0;
+        if ratio >= self.threshold {
+            write_trimmed(w, round(ratio), 1, mark)?;
+            return if concise {
+                w.write_char('×')
+            } else {
+                write!(w, "× {}", self.more)
+            };
+        }
+        if ratio > 0.0 && 1.0 / ratio >= self.threshold {
+            if concise {
+                // Enough places for two significant digits: "0.4×", "0.0012×".
+                let decimals = (1.0 - ratio.log10().floor()).clamp(2.0, 15.0) as usize;
+                write_trimmed(w, ratio, decimals, mark)?;
+                return w.write_char('×');
+            }
+            write_trimmed(w, round(1.0 / ratio), 1, mark)?;
+            return write!(w, "× {}", self.less);
+        }
+
+        let rounded = round(percent.abs());
+        if concise {
+            w.write_char(if percent > 0.0 { '+' } else { '-' })?;
+            write_trimmed(w, rounded, 1, mark)?;
+            w.write_char('%')
+        } else {
+            write_trimmed(w, rounded, 1, mark)?;
+            let word = if percent > 0.0 { self.more } else { self.less };
+            write!(w, "% {}", word)
+        }
+    }
+}
+
+impl HumanSize {
+    /// Compares this size with a baseline, see [`HumanComparison`].
+    pub fn compare(&self, baseline: &HumanSize) -> HumanComparison {
+        HumanComparison::numbers(self.signed_bytes() as f64, baseline.signed_bytes() as f64)
+    }
+}
+
+impl HumanTime {
+    /// Compares this duration with a baseline, see [`HumanComparison`].
+    pub fn compare(&self, baseline: &HumanTime) -> HumanComparison {
+        HumanComparison::numbers(self.seconds(), baseline.seconds()).words("longer", "shorter")
+    }
+
+    fn seconds(&self) -> f64 {
+        let seconds = self.duration.as_secs_f64();
+        if self.negative { -seconds } else { seconds }
+    }
+}
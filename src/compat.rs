@@ -0,0 +1,4 @@
+//! Functions matching the output of other humanization libraries, for ports
+//! that need byte-for-byte identical strings.
+
+pub mod python;
@@ -0,0 +1,204 @@
+//! The output of the Python [`humanize`](https://github.com/python-humanize/humanize)
+//! package (4.x, English), for services ported from Python.
+//!
+//! The functions keep the Python names; keyword arguments become positional
+//! ones. Integers are passed as `f64`, so inputs above 2^53 lose precision.
+//!
+//! ```rust
+//! use humanly::compat::python::{intword, naturalsize};
+//!
+//! assert_eq!(intword(1_200_000), "1.2 million");
+//! assert_eq!(naturalsize(3_000, true, false), "2.9 KiB");
+//! ```
+
+use std::time::{Duration, SystemTime};
+
+use crate::core::{collect, write_grouped};
+use crate::locale::{Locale, TimeUnit};
+
+const POWERS: [f64; 12] = [
+    1e3, 1e6, 1e9, 1e12, 1e15, 1e18, 1e21, 1e24, 1e27, 1e30, 1e33, 1e100,
+];
+
+const POWER_WORDS: [&str; 12] = [
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "googol",
+];
+
+// Python's spelling of non-finite floats.
+fn not_finite(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value > 0.0 {
+        "+Inf".to_string()
+    } else {
+        "-Inf".to_string()
+    }
+}
+
+/// `humanize.intcomma`: "1,000,000", or "1,234.55" with `ndigits` of 2.
+///
+/// Without `ndigits`, whole numbers are written like Python integers
+/// ("1,000", not "1,000.0").
+pub fn intcomma(value: impl Into<f64>, ndigits: Option<usize>) -> String {
+    let value = value.into();
+    if !value.is_finite() {
+        return not_finite(value);
+    }
+    let text = match ndigits {
+        Some(ndigits) => format!("{:.*}", ndigits, value),
+        None => format!("{}", value),
+    };
+
+    let (sign, unsigned) = match text.strip_prefix('-') {
+        Some(unsigned) => ("-", unsigned),
+        None => ("", text.as_str()),
+    };
+    let split = unsigned.find('.').unwrap_or(unsigned.len());
+    let (integer, fraction) = unsigned.split_at(split);
+    collect(|out| {
+        out.push_str(sign);
+        write_grouped(out, integer, ',')?;
+        out.push_str(fraction);
+        Ok(())
+    })
+}
+
+/// `humanize.intword`: "1.2 million", "12.4 thousand"; values below 1,000 are
+/// returned as they are.
+pub fn intword(value: impl Into<f64>) -> String {
+    let value = value.into();
+    if !value.is_finite() {
+        return not_finite(value);
+    }
+    let value = value.trunc();
+    let sign = if value < 0.0 { "-" } else { "" };
+    let value = value.abs();
+
+    if value < POWERS[0] {
+        return format!("{}{}", sign, value);
+    }
+    for i in 1..POWERS.len() {
+        if value < POWERS[i] {
+            let chopped = value / POWERS[i - 1];
+            // "999.95 thousand" would round to "1000.0 thousand": move up
+            let rounded: f64 = format!("{:.1}", chopped).parse().unwrap_or(chopped);
+            if rounded == POWERS[i] / POWERS[i - 1] {
+                return format!("{}{:.1} {}", sign, value / POWERS[i], POWER_WORDS[i]);
+            }
+            return format!("{}{:.1} {}", sign, chopped, POWER_WORDS[i - 1]);
+        }
+    }
+    format!("{}{:.0}", sign, value)
+}
+
+/// `humanize.naturalsize`: "3.0 MB"; "2.9 KiB" with `binary`, "2.9K" with
+/// `gnu`.
+pub fn naturalsize(value: impl Into<f64>, binary: bool, gnu: bool) -> String {
+    const DECIMAL: [&str; 10] = [
+        " kB", " MB", " GB", " TB", " PB", " EB", " ZB", " YB", " RB", " QB",
+    ];
+    const BINARY: [&str; 10] = [
+        " KiB", " MiB", " GiB", " TiB", " PiB", " EiB", " ZiB", " YiB", " RiB", " QiB",
+    ];
+    const GNU: [&str; 10] = ["K", "M", "G", "T", "P", "E", "Z", "Y", "R", "Q"];
+
+    let bytes = value.into();
+    let (suffixes, base) = match (gnu, binary) {
+        (true, _) => (GNU, 1024.0),
+        (false, true) => (BINARY, 1024.0),
+        (false, false) => (DECIMAL, 1000.0),
+    };
+    let abs_bytes = bytes.abs();
+
+    // Python's "%d" truncates towards zero (and has no "-0")
+    if abs_bytes == 1.0 && !gnu {
+        return format!("{} Byte", bytes.trunc() + 0.0);
+    }
+    if abs_bytes < base {
+        return if gnu {
+            format!("{}B", bytes.trunc() + 0.0)
+        } else {
+            format!("{} Bytes", bytes.trunc() + 0.0)
+        };
+    }
+
+    let (i, suffix) = suffixes
+        .iter()
+        .enumerate()
+        .find(|(i, _)| abs_bytes < base.powi(*i as i32 + 2))
+        .unwrap_or((suffixes.len() - 1, &suffixes[suffixes.len() - 1]));
+    let unit = base.powi(i as i32 + 2);
+    format!("{:.1}{}", base * bytes / unit, suffix)
+}
+
+fn count(n: u64, unit: TimeUnit) -> String {
+    format!("{} {}", n, Locale::English.time_unit(unit, n == 1))
+}
+
+/// `humanize.naturaldelta`: "a moment", "16 minutes", "an hour",
+/// "1 year, 4 months".
+///
+/// Months are 30.5 days and years 365 days, as in Python.
+pub fn naturaldelta(value: Duration) -> String {
+    let secs = value.as_secs();
+    let seconds = secs % 86_400;
+    let days = secs / 86_400;
+    let years = days / 365;
+    let days = days % 365;
+    let months = (days as f64 / 30.5) as u64;
+
+    match years {
+        0 if days == 0 => match seconds {
+            0 => "a moment".to_string(),
+            1 => "a second".to_string(),
+            2..60 => count(seconds, TimeUnit::Second),
+            60..120 => "a minute".to_string(),
+            120..3_600 => count(seconds / 60, TimeUnit::Minute),
+            3_600..7_200 => "an hour".to_string(),
+            _ => count(seconds / 3_600, TimeUnit::Hour),
+        },
+        0 => match (days, months) {
+            (1, _) => "a day".to_string(),
+            (days, 0) => count(days, TimeUnit::Day),
+            (_, 1) => "a month".to_string(),
+            (_, months) => count(months, TimeUnit::Month),
+        },
+        1 => match (days, months) {
+            (0, _) => "a year".to_string(),
+            (days, 0) => format!("1 year, {}", count(days, TimeUnit::Day)),
+            (_, months) => format!("1 year, {}", count(months, TimeUnit::Month)),
+        },
+        years => format!("{} years", intcomma(years as f64, None)),
+    }
+}
+
+/// `humanize.naturaltime`: "now", "a minute ago", "3 hours from now".
+///
+/// The time is compared with `when`, or the current time when `None`.
+pub fn naturaltime(value: SystemTime, when: Option<SystemTime>) -> String {
+    let when = when.unwrap_or_else(SystemTime::now);
+    let (delta, future) = match when.duration_since(value) {
+        Ok(past) => (past, false),
+        Err(err) => (err.duration(), true),
+    };
+
+    let delta = naturaldelta(delta);
+    if delta == "a moment" {
+        "now".to_string()
+    } else if future {
+        format!("{} from now", delta)
+    } else {
+        format!("{}{}", delta, Locale::English.ago().1)
+    }
+}
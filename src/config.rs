@@ -0,0 +1,152 @@
+use std::cell::Cell;
+use std::sync::OnceLock;
+
+use crate::Locale;
+use crate::core::{Style, UnitSystem};
+
+/* -------------------- Config -------------------- */
+
+static GLOBAL: OnceLock<Config> = OnceLock::new();
+
+thread_local! {
+    static SCOPED: Cell<Option<Config>> = const { Cell::new(None) };
+}
+
+/// Letter case of the [`HumanNumber`](crate::HumanNumber) concise suffixes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SuffixCase {
+    /// "k", "M", "B", "T"
+    #[default]
+    Mixed,
+    /// "K", "M", "B", "T"
+    Upper,
+    /// "k", "m", "b", "t"
+    Lower,
+}
+
+impl SuffixCase {
+    pub(crate) fn apply(self, suffix: &'static str) -> &'static str {
+        const UPPER: [(&str, &str); 1] = [("k", "K")];
+        const LOWER: [(&str, &str); 5] =
+            [("M", "m"), ("B", "b"), ("T", "t"), ("Q", "q"), ("Qi", "qi")];
+        let table: &[(&str, &str)] = match self {
+            SuffixCase::Mixed => return suffix,
+            SuffixCase::Upper => &UPPER,
+            SuffixCase::Lower => &LOWER,
+        };
+        table
+            .iter()
+            .find(|(from, _)| *from == suffix)
+            .map_or(suffix, |&(_, to)| to)
+    }
+}
+
+/// How formatted output is typeset.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Typography {
+    /// Plain spaces and hyphen-minus: "-5 MiB".
+    #[default]
+    Ascii,
+    /// A narrow no-break space (U+202F) after a number and a minus sign
+    /// (U+2212) before one: "−5\u{202F}MiB".
+    Unicode,
+}
+
+/// Defaults applied to every value that doesn't set the option itself.
+///
+/// The default `Config` reproduces the crate's standard output. A process-wide
+/// configuration can be installed once with [`set_global`](Config::set_global),
+/// and [`with`](Config::with) overrides it for the current thread while a
+/// closure runs, which keeps tests independent of each other:
+///
+/// ```rust
+/// use humanly::{Config, HumanNumber, HumanSize, SuffixCase, UnitSystem};
+///
+/// let config = Config::default()
+///     .decimal_separator(',')
+///     .suffix_case(SuffixCase::Upper)
+///     .unit_system(UnitSystem::Decimal);
+///
+/// Config::with(config, |_| {
+///     assert_eq!(HumanNumber::from(1_500).concise(), "1,5K");
+///     assert_eq!(HumanSize::from(5_000_000).concise(), "5 MB");
+///     // Builder options on the value still win
+///     assert_eq!(HumanSize::from(5_242_880).binary().concise(), "5 MiB");
+/// });
+/// assert_eq!(HumanNumber::from(1_500).concise(), "1.5k");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Config {
+    decimal_separator: Option<char>,
+    pub(crate) suffix_case: SuffixCase,
+    pub(crate) unit_system: UnitSystem,
+    pub(crate) typography: Typography,
+}
+
+impl Config {
+    /// Decimal mark for values without an explicit [`Locale`], in both styles.
+    pub fn decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = Some(separator);
+        self
+    }
+
+    pub fn suffix_case(mut self, case: SuffixCase) -> Self {
+        self.suffix_case = case;
+        self
+    }
+
+    /// Unit system for [`HumanSize`](crate::HumanSize) values that don't call
+    /// `.binary()` or `.decimal()`.
+    pub fn unit_system(mut self, system: UnitSystem) -> Self {
+        self.unit_system = system;
+        self
+    }
+
+    /// Spacing and minus signs in every formatter's output. Parsing accepts
+    /// both forms whatever this is set to.
+    pub fn typography(mut self, typography: Typography) -> Self {
+        self.typography = typography;
+        self
+    }
+
+    /// Installs the process-wide configuration. It can only be set once; later
+    /// calls return the rejected config.
+    pub fn set_global(config: Config) -> Result<(), Config> {
+        GLOBAL.set(config)
+    }
+
+    /// Runs `f` with `config` in effect on the current thread, then restores
+    /// the previous configuration.
+    pub fn with<R>(config: Config, f: impl FnOnce(&Config) -> R) -> R {
+        struct Restore(Option<Config>);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                SCOPED.with(|scoped| scoped.set(self.0));
+            }
+        }
+
+        let _restore = Restore(SCOPED.with(|scoped| scoped.replace(Some(config))));
+        f(&config)
+    }
+
+    /// The configuration in effect: the innermost [`with`](Config::with), else
+    /// the global one, else the default.
+    pub fn current() -> Config {
+        SCOPED
+            .with(Cell::get)
+            .or_else(|| GLOBAL.get().copied())
+            .unwrap_or_default()
+    }
+}
+
+// Decimal mark for a value: an explicit locale wins, then the configured
+// separator, then '.' concise and the default locale's mark full.
+pub(crate) fn decimal_mark(locale: Option<Locale>, style: Style) -> char {
+    match (locale, Config::current().decimal_separator) {
+        (None, Some(separator)) => separator,
+        (locale, _) => match style {
+            Style::Concise => '.',
+            Style::Full => locale.unwrap_or_default().decimal_separator(),
+        },
+    }
+}
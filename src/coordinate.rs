@@ -0,0 +1,156 @@
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+use crate::config::decimal_mark;
+use crate::core::{HumanFormat, Style, human_display, typeset, write_localized};
+use crate::error::{CoordinateError, ParseError};
+use crate::parse::parse_coordinate;
+
+/* -------------------- HumanCoordinate -------------------- */
+
+/// A latitude and longitude: "48°51′24″N 2°21′08″E" concise (degrees,
+/// minutes and seconds), "48.8567° N, 2.3522° E" full (decimal degrees).
+///
+/// Seconds are rounded to whole seconds, carrying into minutes and degrees,
+/// and decimal degrees to four places. A value that rounds to zero takes the
+/// north or east hemisphere. With [`ascii`](HumanCoordinate::ascii), minutes
+/// and seconds are marked with `'` and `"` instead of the prime marks.
+///
+/// Both forms parse back with [`str::parse`], as do signed decimal degrees
+/// like "-33.8688, 151.2093":
+///
+/// ```rust
+/// use humanly::HumanCoordinate;
+///
+/// let paris = HumanCoordinate::from_lat_lon(48.8567, 2.3522).unwrap();
+/// assert_eq!(paris.concise(), "48°51′24″N 2°21′08″E");
+///
+/// let parsed: HumanCoordinate = "48°51'24\"N 2°21'8\"E".parse().unwrap();
+/// assert_eq!(parsed.concise(), paris.concise());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HumanCoordinate {
+    latitude: f64,
+    longitude: f64,
+    ascii: bool,
+}
+
+human_display!(HumanCoordinate);
+
+impl HumanCoordinate {
+    /// Fails unless the latitude is within ±90 and the longitude within ±180.
+    pub fn from_lat_lon(latitude: f64, longitude: f64) -> Result<Self, CoordinateError> {
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(CoordinateError::Latitude(latitude));
+        }
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(CoordinateError::Longitude(longitude));
+        }
+        Ok(Self {
+            latitude,
+            longitude,
+            ascii: false,
+        })
+    }
+
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Mark minutes and seconds with `'` and `"`: `48°51'24"N`.
+    pub fn ascii(mut self) -> Self {
+        self.ascii = true;
+        self
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        let axes = [(self.latitude, 'N', 'S'), (self.longitude, 'E', 'W')];
+        match style {
+            Style::Concise => {
+                for (i, (value, positive, negative)) in axes.into_iter().enumerate() {
+                    if i > 0 {
+                        w.write_char(' ')?;
+                    }
+                    self.write_dms(w, value, positive, negative)?;
+                }
+            }
+            Style::Full => {
+                let mark = decimal_mark(None, style);
+                for (i, (value, positive, negative)) in axes.into_iter().enumerate() {
+                    if i > 0 {
+                        w.write_str(", ")?;
+                    }
+                    let rounded = (value.abs() * 1e4).round() / 1e4;
+                    let hemisphere = hemisphere(value, rounded == 0.0, positive, negative);
+                    write_localized(w, mark, format_args!("{:.4}", rounded))?;
+                    write!(w, "° {}", hemisphere)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn write_dms(
+        &self,
+        w: &mut impl Write,
+        value: f64,
+        positive: char,
+        negative: char,
+    ) -> fmt::Result {
+        let (minute, second) = if self.ascii {
+            ('\'', '"')
+        } else {
+            ('′', '″')
+        };
+
+        let total = (value.abs() * 3600.0).round() as u64;
+        let hemisphere = hemisphere(value, total == 0, positive, negative);
+        write!(
+            w,
+            "{}°{:02}{}{:02}{}{}",
+            total / 3600,
+            total % 3600 / 60,
+            minute,
+            total % 60,
+            second,
+            hemisphere
+        )
+    }
+}
+
+fn hemisphere(value: f64, zero: bool, positive: char, negative: char) -> char {
+    if zero || value > 0.0 {
+        positive
+    } else {
+        negative
+    }
+}
+
+impl FromStr for HumanCoordinate {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let (latitude, longitude) = parse_coordinate(input)?;
+        Ok(Self {
+            latitude,
+            longitude,
+            ascii: false,
+        })
+    }
+}
@@ -1,382 +1,3099 @@
-use std::fmt;
+use crate::config::{Config, Typography, decimal_mark};
+use crate::date::CivilDateTime;
+use crate::decimal::{Exact, write_scaled};
+use crate::error::{CapacityError, ParseError};
+use crate::locale::{Locale, TimeUnit};
+use crate::parse::{parse_duration, parse_human_number, parse_iso8601_duration, parse_size};
+use crate::parts::Parts;
+use crate::words::spell_cardinal;
+use std::cmp::Ordering;
+use std::fmt::{self, Write};
+use std::fs::{self, Metadata};
+use std::io;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::path::Path;
+use std::str::FromStr;
 use std::time::{Duration, SystemTime};
 
-#[derive(Clone, Copy)]
-enum HumanFormat {
+/// The two renderings every `Human*` type offers, behind one object-safe trait
+/// so differently typed values can be formatted side by side.
+///
+/// The inherent `concise()` and `full()` methods call into this, so the two
+/// can never disagree.
+///
+/// ```rust
+/// use humanly::{HumanFormat, HumanNumber, HumanSize};
+///
+/// let cells: Vec<Box<dyn HumanFormat>> = vec![
+///     Box::new(HumanSize::from(1_500_000u64).decimal()),
+///     Box::new(HumanNumber::from(42_000)),
+/// ];
+///
+/// let row: Vec<String> = cells.iter().map(|cell| cell.concise()).collect();
+/// assert_eq!(row, ["1.5 MB", "42k"]);
+/// ```
+pub trait HumanFormat {
+    fn concise(&self) -> String;
+    fn full(&self) -> String;
+}
+
+/// Output style accepted by the `write_into` methods.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Style {
     Concise,
     Full,
 }
 
+// Adds `write_concise` and `write_full`, the `HumanFormat` methods built on
+// them, and `Display`.
+//
+// `{}` renders the full form, `{:#}` the concise one. Going through `pad`
+// keeps width, fill and alignment flags working; either way the value is
+// written straight into the formatter.
 macro_rules! human_display {
     ($t:ty) => {
-        impl fmt::Display for $t {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(f, "{}", self.full())
+        impl $t {
+            /// Writes the `concise()` form into `w`, which can be a reused
+            /// buffer or a formatter, without an intermediate `String` where
+            /// possible.
+            pub fn write_concise(&self, w: &mut impl std::fmt::Write) -> std::fmt::Result {
+                self.write_into(w, $crate::core::Style::Concise)
+            }
+
+            /// Writes the `full()` form into `w`, without an intermediate
+            /// `String` where possible.
+            pub fn write_full(&self, w: &mut impl std::fmt::Write) -> std::fmt::Result {
+                self.write_into(w, $crate::core::Style::Full)
+            }
+        }
+
+        impl $crate::core::HumanFormat for $t {
+            fn concise(&self) -> String {
+                $crate::core::collect(|out| self.write_concise(out))
+            }
+
+            fn full(&self) -> String {
+                $crate::core::collect(|out| self.write_full(out))
+            }
+        }
+
+        impl std::fmt::Display for $t {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let style = if f.alternate() {
+                    $crate::core::Style::Concise
+                } else {
+                    $crate::core::Style::Full
+                };
+
+                if f.width().is_some() || f.precision().is_some() {
+                    $crate::core::pad(f, |mut out| self.write_into(&mut out, style))
+                } else {
+                    self.write_into(f, style)
+                }
             }
         }
     };
 }
 
+pub(crate) use human_display;
+
 human_display!(HumanNumber);
 human_display!(HumanSize);
 human_display!(HumanDuration);
 human_display!(HumanTime);
 human_display!(HumanPercent);
 
-/* -------------------- HumanNumber -------------------- */
-
-pub struct HumanNumber {
-    number: f64,
+pub(crate) fn collect(write: impl FnOnce(&mut String) -> fmt::Result) -> String {
+    let mut out = String::new();
+    write(&mut out).expect("writing to a String cannot fail");
+    out
 }
 
-impl HumanNumber {
-    pub fn from(number: impl Into<f64>) -> Self {
-        Self {
-            number: number.into(),
-        }
-    }
+// `Formatter::pad` for the text `write` produces, which runs twice (once to
+// measure it, once to write it) so nothing is collected into a String.
+pub(crate) fn pad(
+    f: &mut fmt::Formatter<'_>,
+    write: impl Fn(&mut dyn Write) -> fmt::Result,
+) -> fmt::Result {
+    let mut counter = CountChars(0);
+    write(&mut counter)?;
+    let len = f.precision().map_or(counter.0, |max| counter.0.min(max));
+    let padding = f.width().unwrap_or(0).saturating_sub(len);
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (0, padding),
+    };
 
-    pub fn concise(&self) -> String {
-        self.format(HumanFormat::Concise)
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    write(&mut Truncate {
+        inner: f,
+        remaining: len,
+    })?;
+    for _ in 0..after {
+        f.write_char(fill)?;
     }
+    Ok(())
+}
 
-    pub fn full(&self) -> String {
-        self.format(HumanFormat::Full)
+struct CountChars(usize);
+
+impl Write for CountChars {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
     }
+}
 
-    fn format(&self, format: HumanFormat) -> String {
-        let number = self.number;
-        let abs_number = number.abs();
+// Passes on the first `remaining` characters and drops the rest.
+struct Truncate<'a, W: Write> {
+    inner: &'a mut W,
+    remaining: usize,
+}
 
-        let (divisor, short_suffix, long_suffix) = if abs_number < 1_000.0 {
-            (1.0, "", "")
-        } else if abs_number < 1_000_000.0 {
-            (1_000.0, "k", " thousand")
-        } else if abs_number < 1_000_000_000.0 {
-            (1_000_000.0, "M", " million")
-        } else if abs_number < 1_000_000_000_000.0 {
-            (1_000_000_000.0, "B", " billion")
-        } else {
-            (1_000_000_000_000.0, "T", " trillion")
-        };
+impl<W: Write> Write for Truncate<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = s
+            .char_indices()
+            .nth(self.remaining)
+            .map_or(s.len(), |(i, _)| i);
+        self.remaining -= s[..end].chars().count();
+        self.inner.write_str(&s[..end])
+    }
+}
 
-        let value = number / divisor;
-        let formatted = if value.fract() == 0.0 {
-            format!("{}", value as i64)
-        } else {
-            let s = format!("{:.1}", value);
-            s.trim_end_matches('0').trim_end_matches('.').to_string()
-        };
+// Writes into a byte slice, counting on past its end so a too-small buffer
+// can report the size it needed.
+pub(crate) struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
 
-        match format {
-            HumanFormat::Concise => format!("{}{}", formatted, short_suffix),
-            HumanFormat::Full => format!("{}{}", formatted, long_suffix),
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if let Some(dest) = self.buf.get_mut(self.len..end) {
+            dest.copy_from_slice(s.as_bytes());
         }
+        self.len = end;
+        Ok(())
     }
 }
-/* -------------------- HumanSize -------------------- */
 
-#[derive(Clone, Copy, Debug)]
-enum UnitSystem {
-    Binary,  // IEC, 1024-based
-    Decimal, // SI, 1000-based
+// `collect` for a caller's buffer: the written text, or how much room it needed.
+pub(crate) fn collect_into(
+    buf: &mut [u8],
+    write: impl FnOnce(&mut SliceWriter<'_>) -> fmt::Result,
+) -> Result<&str, CapacityError> {
+    let mut writer = SliceWriter { buf, len: 0 };
+    write(&mut writer).expect("writing to a slice cannot fail");
+
+    let SliceWriter { buf, len } = writer;
+    if len > buf.len() {
+        return Err(CapacityError::new(len, buf.len()));
+    }
+    // Every write was a whole `str` copied in order, so this is UTF-8.
+    Ok(std::str::from_utf8(&buf[..len]).expect("written text is UTF-8"))
 }
 
-#[derive(Clone, Copy, Debug)]
-pub struct HumanSize {
-    bytes: u64,
-    system: UnitSystem,
+/* -------------------- Typography -------------------- */
+
+// Applies `Typography::Unicode` to what is written through it: a narrow
+// no-break space for a space after a digit, and a minus sign for a hyphen that
+// starts a number. A hyphen waits for the next character to tell which it is.
+pub(crate) struct Typeset<'a, W: Write> {
+    inner: &'a mut W,
+    unicode: bool,
+    previous: Option<char>,
+    hyphen: bool,
 }
 
-impl HumanSize {
-    pub fn from(bytes: u64) -> Self {
-        Self {
-            bytes,
-            system: UnitSystem::Binary,
+impl<W: Write> Write for Typeset<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if !self.unicode {
+            return self.inner.write_str(s);
         }
+        s.chars().try_for_each(|c| self.write_char(c))
     }
 
-    pub fn decimal(mut self) -> Self {
-        self.system = UnitSystem::Decimal;
-        self
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        if !self.unicode {
+            return self.inner.write_char(c);
+        }
+        if self.hyphen {
+            self.hyphen = false;
+            self.inner
+                .write_char(if c.is_ascii_digit() { '\u{2212}' } else { '-' })?;
+        }
+        match (c, self.previous) {
+            ('-', None | Some(' ' | '(' | '\u{202F}')) => self.hyphen = true,
+            (' ', Some(previous)) if previous.is_ascii_digit() => {
+                self.inner.write_char('\u{202F}')?
+            }
+            _ => self.inner.write_char(c)?,
+        }
+        self.previous = Some(c);
+        Ok(())
     }
+}
 
-    pub fn binary(mut self) -> Self {
-        self.system = UnitSystem::Binary;
-        self
+// Runs `write` through a `Typeset` for the configured typography.
+pub(crate) fn typeset<W: Write>(
+    w: &mut W,
+    write: impl FnOnce(&mut Typeset<'_, W>) -> fmt::Result,
+) -> fmt::Result {
+    let mut typeset = Typeset {
+        inner: w,
+        unicode: Config::current().typography == Typography::Unicode,
+        previous: None,
+        hyphen: false,
+    };
+    write(&mut typeset)?;
+    if typeset.hyphen {
+        typeset.inner.write_char('-')?;
     }
+    Ok(())
+}
 
-    pub fn concise(&self) -> String {
-        self.format(HumanFormat::Concise)
-    }
+/* -------------------- Number writers -------------------- */
 
-    pub fn full(&self) -> String {
-        self.format(HumanFormat::Full)
-    }
+// Swaps the '.' emitted by float formatting for the locale's decimal mark.
+struct DecimalMark<'a, W: Write> {
+    inner: &'a mut W,
+    mark: char,
+}
 
-    fn format(&self, format: HumanFormat) -> String {
-        // If bytes, just return the number without suffix
-        if self.bytes < 1024 {
-            return match format {
-                HumanFormat::Concise => format!("{}", self.bytes),
-                HumanFormat::Full => {
-                    if self.bytes == 1 {
-                        "1 byte".to_string()
-                    } else {
-                        format!("{} bytes", self.bytes)
-                    }
-                }
-            };
+impl<W: Write> Write for DecimalMark<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.inner
+                .write_char(if c == '.' { self.mark } else { c })?;
         }
+        Ok(())
+    }
+}
 
-        // Unit arrays
-        let (units_short, units_full, step) = match self.system {
-            UnitSystem::Binary => (
-                ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB", "ZiB", "YiB"],
-                [
-                    "kibibyte", "mebibyte", "gibibyte", "tebibyte", "pebibyte", "exbibyte",
-                    "zebibyte", "yobibyte",
-                ],
-                1024.0,
-            ),
-            UnitSystem::Decimal => (
-                ["kB", "MB", "GB", "TB", "PB", "EB", "ZB", "YB"],
-                [
-                    "kilobyte",
-                    "megabyte",
-                    "gigabyte",
-                    "terabyte",
-                    "petabyte",
-                    "exabyte",
-                    "zettabyte",
-                    "yottabyte",
-                ],
-                1000.0,
-            ),
-        };
+// Like `DecimalMark`, but also puts `separator` between groups of three in the
+// first `integer_digits` digits: "-12345.5" comes out as "-12,345.5".
+struct GroupDigits<'a, W: Write> {
+    inner: &'a mut W,
+    separator: char,
+    mark: char,
+    integer_digits: usize,
+    written: usize,
+}
 
-        let mut size = self.bytes as f64;
-        let mut idx = 0;
+impl<W: Write> Write for GroupDigits<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if c == '.' {
+                self.inner.write_char(self.mark)?;
+                continue;
+            }
+            if c.is_ascii_digit() && self.written < self.integer_digits {
+                if self.written > 0 && (self.integer_digits - self.written).is_multiple_of(3) {
+                    self.inner.write_char(self.separator)?;
+                }
+                self.written += 1;
+            }
+            self.inner.write_char(c)?;
+        }
+        Ok(())
+    }
+}
 
-        // First division to get to first unit (KiB or kB)
-        size /= step;
+// Counts the digits before the decimal point, for `GroupDigits`.
+#[derive(Default)]
+struct IntegerDigits {
+    count: usize,
+    in_fraction: bool,
+}
 
-        // Continue dividing if needed
-        while size >= step && idx < units_short.len() - 1 {
-            size /= step;
-            idx += 1;
+impl Write for IntegerDigits {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if c == '.' {
+                self.in_fraction = true;
+            } else if c.is_ascii_digit() && !self.in_fraction {
+                self.count += 1;
+            }
         }
+        Ok(())
+    }
+}
 
-        let rounded = (size * 10.0).round() / 10.0;
-        let formatted = if rounded.fract() == 0.0 {
-            format!("{}", rounded as u64)
-        } else {
-            format!("{:.1}", rounded)
-        };
+// Writes the number that `write` produces with thousands separators and the
+// locale's decimal mark, running it twice rather than buffering the digits.
+pub(crate) fn write_grouped_with(
+    w: &mut impl Write,
+    separator: char,
+    mark: char,
+    write: impl Fn(&mut dyn Write) -> fmt::Result,
+) -> fmt::Result {
+    let mut counter = IntegerDigits::default();
+    write(&mut counter)?;
+    write(&mut GroupDigits {
+        inner: w,
+        separator,
+        mark,
+        integer_digits: counter.count,
+        written: 0,
+    })
+}
+
+// Like `DecimalMark`, but holds back zeros after the decimal point (and the
+// point itself) until a non-zero digit shows they are significant, so
+// "1.50" comes out as "1.5" and "2.0" as "2" without a temporary String.
+struct TrimZeros<'a, W: Write> {
+    inner: &'a mut W,
+    mark: char,
+    in_fraction: bool,
+    pending_mark: bool,
+    pending_zeros: usize,
+}
 
-        match format {
-            HumanFormat::Concise => format!("{} {}", formatted, units_short[idx]),
-            HumanFormat::Full => {
-                let unit = units_full[idx];
-                let pluralized = if rounded == 1.0 {
-                    unit.to_string()
+impl<W: Write> Write for TrimZeros<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if !self.in_fraction {
+                if c == '.' {
+                    self.in_fraction = true;
+                    self.pending_mark = true;
                 } else {
-                    format!("{}s", unit)
-                };
-                format!("{} {}", formatted, pluralized)
+                    self.inner.write_char(c)?;
+                }
+            } else if c == '0' {
+                self.pending_zeros += 1;
+            } else {
+                if self.pending_mark {
+                    self.inner.write_char(self.mark)?;
+                    self.pending_mark = false;
+                }
+                for _ in 0..self.pending_zeros {
+                    self.inner.write_char('0')?;
+                }
+                self.pending_zeros = 0;
+                self.inner.write_char(c)?;
             }
         }
+        Ok(())
     }
 }
 
-/* -------------------- HumanDuration -------------------- */
+pub(crate) fn write_localized(w: &mut impl Write, mark: char, args: fmt::Arguments) -> fmt::Result {
+    DecimalMark { inner: w, mark }.write_fmt(args)
+}
 
-pub struct HumanDuration {
-    system_time: Option<SystemTime>,
+// Writes a run of digits with thousands separators: "12345" -> "12,345".
+pub(crate) fn write_grouped(w: &mut impl Write, digits: &str, separator: char) -> fmt::Result {
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            w.write_char(separator)?;
+        }
+        w.write_char(digit)?;
+    }
+    Ok(())
 }
 
-impl HumanDuration {
-    pub fn from(system_time: Option<SystemTime>) -> Self {
-        Self { system_time }
+pub(crate) fn write_trimmed(
+    w: &mut impl Write,
+    value: f64,
+    decimals: usize,
+    mark: char,
+) -> fmt::Result {
+    let mut trim = TrimZeros {
+        inner: w,
+        mark,
+        in_fraction: false,
+        pending_mark: false,
+        pending_zeros: 0,
+    };
+    write!(trim, "{:.*}", decimals, value)
+}
+
+// Picks the largest of `units` ladder steps that keeps the value at or above 1,
+// rounding to `decimals` places first so 1023.96 KiB becomes 1 MiB rather than
+// "1024 KiB". Returns the rounded value and the unit index.
+pub(crate) fn scale_units(value: f64, step: f64, units: usize, decimals: usize) -> (f64, usize) {
+    let multiplier = 10_f64.powi(decimals as i32);
+    // a value too large to scale has no fraction left to round
+    let round = |v: f64| match v * multiplier {
+        scaled if scaled.is_finite() => scaled.round() / multiplier,
+        _ => v,
+    };
+
+    let mut scaled = value;
+    let mut idx = 0;
+    while idx < units - 1 && round(scaled.abs()) >= step {
+        scaled /= step;
+        idx += 1;
     }
+    (round(scaled), idx)
+}
 
-    pub fn concise(&self) -> String {
-        self.format(HumanFormat::Concise)
+// One rung of a ladder of irregular units, e.g. feet, yards and miles.
+pub(crate) struct Unit {
+    pub(crate) size: f64,      // in the base unit
+    pub(crate) threshold: f64, // smallest value, in this unit, shown with it
+    pub(crate) symbol: &'static str,
+    pub(crate) singular: &'static str,
+    pub(crate) plural: &'static str,
+}
+
+impl Unit {
+    pub(crate) const fn new(
+        size: f64,
+        threshold: f64,
+        symbol: &'static str,
+        singular: &'static str,
+        plural: &'static str,
+    ) -> Self {
+        Self {
+            size,
+            threshold,
+            symbol,
+            singular,
+            plural,
+        }
     }
+}
+
+// Picks the largest unit (`units` is ordered smallest first) whose value
+// reaches its threshold. Zero uses `base`. Returns the rounded value.
+//
+// A value that reaches a threshold once rounded in the unit below it counts,
+// so 999.96 g is "1 kg" rather than "1000 g", but 960 g stays in grams.
+pub(crate) fn pick_unit(value: f64, units: &[Unit], base: usize, decimals: usize) -> (f64, &Unit) {
+    let multiplier = 10_f64.powi(decimals as i32);
+    // a value too large to scale has no fraction left to round
+    let round = |v: f64| match v * multiplier {
+        scaled if scaled.is_finite() => scaled.round() / multiplier,
+        _ => v,
+    };
+    let value = value.abs();
+
+    let idx = if value == 0.0 {
+        base
+    } else {
+        (1..units.len())
+            .rev()
+            .find(|&i| {
+                let (below, unit) = (&units[i - 1], &units[i]);
+                let rounded = round(value / below.size) * below.size;
+                // allow for float error in the unit sizes
+                value.max(rounded) / unit.size >= unit.threshold - 1e-9
+            })
+            .unwrap_or(0)
+    };
+    (round(value / units[idx].size), &units[idx])
+}
+
+const POWERS_OF_1000: [u64; 7] = [
+    1,
+    1_000,
+    1_000_000,
+    1_000_000_000,
+    1_000_000_000_000,
+    1_000_000_000_000_000,
+    1_000_000_000_000_000_000,
+];
+
+const TWO_POW_64: f64 = 18_446_744_073_709_551_616.0;
+
+// Picks the largest unit on the `system` ladder (up to index `max_idx`) that
+// is not above `whole + fraction`, and returns its index with the value in
+// that unit. The unit comes from the digit or bit count of `whole` and the
+// quotient from integer division, so only the remainder goes through a float.
+// A value that rounds to a whole next unit at `decimals` places moves up to
+// it: "1 MiB" rather than "1024 KiB".
+pub(crate) fn magnitude(
+    whole: u64,
+    fraction: f64,
+    system: UnitSystem,
+    max_idx: usize,
+    decimals: usize,
+) -> (usize, f64) {
+    let in_unit = |idx: usize| {
+        system
+            .in_unit(whole, fraction, idx)
+            // past u64::MAX, so `whole` is all remainder
+            .unwrap_or_else(|| (whole as f64 + fraction) / system.step().powi(idx as i32))
+    };
 
-    fn full(&self) -> String {
-        self.format(HumanFormat::Full)
+    // past 10^300 the multiplier would overflow, and every value is below
+    // the step anyway
+    let multiplier = 10_f64.powi(decimals.min(300) as i32);
+    let idx = system.index(whole).min(max_idx);
+    let value = in_unit(idx);
+    if idx < max_idx && value * multiplier >= system.step() * multiplier - 0.5 {
+        (idx + 1, in_unit(idx + 1))
+    } else {
+        (idx, value)
     }
+}
 
-    fn format(&self, format: HumanFormat) -> String {
-        let now = SystemTime::now();
-        if let Some(st) = self.system_time {
-            let elapsed = match now.duration_since(st) {
-                Ok(dur) => dur.as_secs() as i64,
-                Err(err) => -(err.duration().as_secs() as i64),
-            };
+/* -------------------- HumanNumber -------------------- */
 
-            if elapsed.abs() < 1 {
-                return "just now".to_string();
-            }
-
-            let (count, concise_suffix, singular, plural) = if elapsed < 0 {
-                // future
-                let secs = -elapsed as u64;
-                if secs < 60 {
-                    (secs, "s from now", "second", "seconds")
-                } else if secs < 3600 {
-                    (secs / 60, "m from now", "minute", "minutes")
-                } else if secs < 86_400 {
-                    (secs / 3600, "h from now", "hour", "hours")
-                } else if secs < 604_800 {
-                    (secs / 86_400, "d from now", "day", "days")
-                } else if secs < 2_592_000 {
-                    (secs / 604_800, "wk from now", "week", "weeks")
-                } else if secs < 31_536_000 {
-                    (secs / 2_592_000, "mo from now", "month", "months")
-                } else {
-                    (secs / 31_536_000, "yr from now", "year", "years")
-                }
-            } else {
-                let secs = elapsed as u64;
-                if secs < 60 {
-                    (secs, "s ago", "second", "seconds")
-                } else if secs < 3600 {
-                    (secs / 60, "m ago", "minute", "minutes")
-                } else if secs < 86_400 {
-                    (secs / 3600, "h ago", "hour", "hours")
-                } else if secs < 604_800 {
-                    (secs / 86_400, "d ago", "day", "days")
-                } else if secs < 2_592_000 {
-                    (secs / 604_800, "w ago", "week", "weeks")
-                } else if secs < 31_536_000 {
-                    (secs / 2_592_000, "mo ago", "month", "months")
-                } else {
-                    (secs / 31_536_000, "y ago", "year", "years")
-                }
-            };
+const NUMBER_SUFFIXES: [&str; 7] = ["", "k", "M", "B", "T", "Q", "Qi"];
+
+// Divisor, concise suffix and word magnitude (see `Locale::number_word`) for
+// a non-negative number.
+pub(crate) fn number_scale(abs_number: f64) -> (f64, &'static str, usize) {
+    let max_idx = NUMBER_SUFFIXES.len() - 1;
+    let idx = if abs_number < TWO_POW_64 {
+        UnitSystem::Decimal.index(abs_number as u64).min(max_idx)
+    } else {
+        max_idx
+    };
+    (1000_f64.powi(idx as i32), NUMBER_SUFFIXES[idx], idx)
+}
+
+// Thousands, then lakhs (10^5) and crores (10^7), which also count everything
+// larger: "1000Cr".
+const INDIAN_UNITS: [Unit; 4] = [
+    Unit::new(1.0, 0.0, "", "", ""),
+    Unit::new(1e3, 1.0, "k", "thousand", "thousand"),
+    Unit::new(1e5, 1.0, "L", "lakh", "lakh"),
+    Unit::new(1e7, 1.0, "Cr", "crore", "crore"),
+];
+
+/// How [`HumanNumber`] groups large numbers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NumberSystem {
+    /// Powers of 1000: "1.5M", "1.5 million"
+    #[default]
+    Western,
+    /// Lakhs (10^5) and crores (10^7): "1.5L", "1.5 lakh", "2Cr", "2 crore"
+    Indian,
+}
+
+// Suffixes from the number system, or a power of ten.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Notation {
+    Suffix,
+    Scientific,
+    Engineering,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HumanNumber {
+    pub(crate) number: f64,
+    exact: Option<Exact>,
+    locale: Option<Locale>,
+    precision: usize,
+    keep_trailing_zeros: bool,
+    system: NumberSystem,
+    threshold: u64,
+    notation: Notation,
+}
+
+impl Default for HumanNumber {
+    fn default() -> Self {
+        Self::from(0)
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The primitive numbers [`HumanNumber::from`] accepts: every integer and
+/// float type. Integers wider than 32 bits keep all their digits, so
+/// `u64::MAX` and `u128::MAX` are scaled exactly rather than through `f64`.
+///
+/// This trait is sealed and can't be implemented outside the crate.
+pub trait IntoHumanNumber: sealed::Sealed {
+    #[doc(hidden)]
+    fn into_human_number(self) -> HumanNumber;
+}
 
-            match format {
-                HumanFormat::Concise => {
-                    format!("{}{}", count, concise_suffix)
+macro_rules! into_human_number {
+    (float: $($t:ty),*; exact: $($wide:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+            impl IntoHumanNumber for $t {
+                fn into_human_number(self) -> HumanNumber {
+                    HumanNumber::from_f64(self.into())
                 }
-                HumanFormat::Full => {
-                    if count == 1 && singular == "day" && elapsed >= 0 {
-                        "yesterday".to_string()
-                    } else if count == 1 && singular == "day" && elapsed < 0 {
-                        "tomorrow".to_string()
-                    } else if count == 1 {
-                        format!("1 {} ago", singular)
-                    } else {
-                        format!("{} {} ago", count, plural)
-                    }
+            }
+        )*
+        $(
+            impl sealed::Sealed for $wide {}
+            impl IntoHumanNumber for $wide {
+                fn into_human_number(self) -> HumanNumber {
+                    HumanNumber::from_integer(self as i128, self as f64)
                 }
             }
-        } else {
-            "-".to_string()
+        )*
+    };
+}
+
+into_human_number!(
+    float: u8, u16, u32, i8, i16, i32, f32, f64;
+    exact: u64, usize, i64, isize, i128
+);
+
+impl sealed::Sealed for u128 {}
+impl IntoHumanNumber for u128 {
+    fn into_human_number(self) -> HumanNumber {
+        match i128::try_from(self) {
+            Ok(number) => HumanNumber::from_integer(number, self as f64),
+            // Past i128::MAX the last digit is far below any shown decimal,
+            // so round it off to fit
+            Err(_) => HumanNumber {
+                exact: Some(Exact {
+                    mantissa: (self / 10 + u128::from(self % 10 >= 5)) as i128,
+                    exponent: 1,
+                }),
+                ..HumanNumber::from_f64(self as f64)
+            },
         }
     }
 }
 
-/* -------------------- HumanTime -------------------- */
-
-pub struct HumanTime {
-    duration: Duration,
+impl<T: IntoHumanNumber> From<T> for HumanNumber {
+    fn from(number: T) -> Self {
+        number.into_human_number()
+    }
 }
 
-impl HumanTime {
-    pub fn from(duration: Duration) -> Self {
-        Self { duration }
+impl HumanNumber {
+    /// Any integer or float, see [`IntoHumanNumber`]. Negative numbers keep
+    /// their sign, with the suffix chosen by magnitude: "-1.5M", "-1.5
+    /// million". One that rounds to zero is written "0".
+    pub fn from(number: impl IntoHumanNumber) -> Self {
+        number.into_human_number()
     }
 
-    pub fn concise(&self) -> String {
-        self.format(HumanFormat::Concise)
+    fn from_f64(number: f64) -> Self {
+        Self {
+            number,
+            exact: None,
+            locale: None,
+            precision: 1,
+            keep_trailing_zeros: false,
+            system: NumberSystem::Western,
+            threshold: 0,
+            notation: Notation::Suffix,
+        }
     }
 
-    fn full(&self) -> String {
-        self.format(HumanFormat::Full)
+    fn from_integer(number: i128, approx: f64) -> Self {
+        Self {
+            exact: Some(Exact {
+                mantissa: number,
+                exponent: 0,
+            }),
+            ..Self::from_f64(approx)
+        }
     }
 
-    fn format(&self, format: HumanFormat) -> String {
-        let secs = self.duration.as_secs();
-        let hours = secs / 3600;
-        let minutes = (secs % 3600) / 60;
-        let seconds = secs % 60;
-
-        match format {
-            HumanFormat::Concise => {
-                let mut parts = Vec::new();
-                if hours > 0 {
-                    parts.push(format!("{}h", hours));
-                }
-                if minutes > 0 || hours > 0 {
-                    parts.push(format!("{}m", minutes));
-                }
-                if seconds > 0 || parts.is_empty() {
-                    parts.push(format!("{}s", seconds));
-                }
-                parts.join(" ")
-            }
-            HumanFormat::Full => {
-                let mut parts = Vec::new();
-                if hours > 0 {
-                    parts.push(format!(
-                        "{} {}",
-                        hours,
-                        if hours == 1 { "hour" } else { "hours" }
-                    ));
-                }
-                if minutes > 0 {
-                    parts.push(format!(
-                        "{} {}",
-                        minutes,
-                        if minutes == 1 { "minute" } else { "minutes" }
-                    ));
-                }
-                if seconds > 0 || parts.is_empty() {
-                    parts.push(format!(
-                        "{} {}",
-                        seconds,
-                        if seconds == 1 { "second" } else { "seconds" }
-                    ));
-                }
-                parts.join(" ")
-            }
+    #[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+    pub(crate) fn from_exact(exact: Exact) -> Self {
+        Self {
+            exact: Some(exact),
+            ..Self::from_f64(exact.to_f64())
         }
     }
-}
 
-/* -------------------- HumanPercent -------------------- */
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = Some(locale);
+        self
+    }
 
-pub struct HumanPercent {
-    value: f64,
-    decimals: usize,
-}
+    /// Maximum number of decimal places (default 1); trailing zeros are
+    /// trimmed, so `precision(2)` gives "1.23M" but still "1.2M" for 1,200,000.
+    /// The suffix moves up when the rounded value reaches the next one:
+    /// 999,999 is "1M" at two places and "999.999k" at three.
+    ///
+    /// ```rust
+    /// use humanly::HumanNumber;
+    ///
+    /// assert_eq!(HumanNumber::from(1_234_567).precision(2).concise(), "1.23M");
+    /// assert_eq!(HumanNumber::from(1_234_567).precision(0).full(), "1 million");
+    /// assert_eq!(
+    ///     HumanNumber::from(1_200_000).precision(2).keep_trailing_zeros().concise(),
+    ///     "1.20M"
+    /// );
+    /// ```
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision.min(300);
+        self
+    }
 
-impl HumanPercent {
-    pub fn from(value: f64, decimals: usize) -> Self {
-        Self { value, decimals }
+    /// Always write [`precision`](HumanNumber::precision) decimal places,
+    /// padding with zeros ("1.20M", "42.0"), for columns and tiles that
+    /// should keep the same shape.
+    pub fn keep_trailing_zeros(mut self) -> Self {
+        self.keep_trailing_zeros = true;
+        self
     }
 
-    pub fn concise(&self) -> String {
-        self.format(HumanFormat::Concise)
+    /// Group by lakhs and crores instead of millions and billions.
+    ///
+    /// ```rust
+    /// use humanly::{HumanNumber, NumberSystem};
+    ///
+    /// let number = HumanNumber::from(150_000).system(NumberSystem::Indian);
+    /// assert_eq!(number.concise(), "1.5L");
+    /// assert_eq!(number.full(), "1.5 lakh");
+    /// assert_eq!(HumanNumber::from(20_000_000).system(NumberSystem::Indian).concise(), "2Cr");
+    /// ```
+    pub fn system(mut self, system: NumberSystem) -> Self {
+        self.system = system;
+        self
+    }
+
+    /// Write the number as a mantissa with one digit before the decimal mark
+    /// and a power of ten: "2.5e9" concise, "2.5×10⁹" full. The mantissa is
+    /// rounded to [`precision`](HumanNumber::precision) places. Zero is "0",
+    /// and NaN and infinities are "-".
+    ///
+    /// ```rust
+    /// use humanly::HumanNumber;
+    ///
+    /// assert_eq!(HumanNumber::from(2_500_000_000_u64).scientific().concise(), "2.5e9");
+    /// assert_eq!(HumanNumber::from(45_000).scientific().full(), "4.5×10⁴");
+    /// assert_eq!(HumanNumber::from(0.0003).scientific().concise(), "3e-4");
+    /// ```
+    pub fn scientific(mut self) -> Self {
+        self.notation = Notation::Scientific;
+        self
+    }
+
+    /// Like [`scientific`](HumanNumber::scientific), with the exponent a
+    /// multiple of three and one to three digits before the decimal mark, so
+    /// it lines up with kilo, mega, milli…: "45e3", "2.5e9", "300e-6".
+    ///
+    /// ```rust
+    /// use humanly::HumanNumber;
+    ///
+    /// assert_eq!(HumanNumber::from(45_000).engineering().concise(), "45e3");
+    /// assert_eq!(HumanNumber::from(-0.0003).engineering().full(), "-300×10⁻⁶");
+    /// ```
+    pub fn engineering(mut self) -> Self {
+        self.notation = Notation::Engineering;
+        self
+    }
+
+    /// Write values that round below `threshold` in full, grouped like
+    /// [`HumanCount`](crate::HumanCount), and abbreviate only from there up.
+    /// The default of 0 abbreviates from a thousand as usual.
+    ///
+    /// ```rust
+    /// use humanly::HumanNumber;
+    ///
+    /// assert_eq!(HumanNumber::from(1_200).threshold(1_000_000).concise(), "1,200");
+    /// assert_eq!(HumanNumber::from(1_234.56).threshold(1_000_000).full(), "1,234.6");
+    /// assert_eq!(HumanNumber::from(1_200_000).threshold(1_000_000).concise(), "1.2M");
+    /// ```
+    pub fn threshold(mut self, threshold: u64) -> Self {
+        self.threshold = threshold;
+        self
     }
 
-    fn full(&self) -> String {
-        self.format(HumanFormat::Full)
+    /// Reads a number back from text like "3.4M", "10k" or "2.5 million".
+    ///
+    /// Suffixes are the ones `concise()` writes (k, M, B, T, Q, Qi) or the words
+    /// `full()` writes (thousand … quintillion), matched without regard to case
+    /// and with optional whitespace before them. The mantissa may group its
+    /// digits with commas. The `concise()` and `full()` output of any number
+    /// reads back to within its rounding.
+    ///
+    /// ```rust
+    /// use humanly::{HumanNumber, ParseErrorKind};
+    ///
+    /// assert_eq!(HumanNumber::parse("3.4M"), Ok(3_400_000.0));
+    /// assert_eq!(HumanNumber::parse("10k"), Ok(10_000.0));
+    /// assert_eq!(HumanNumber::parse("2.5 million"), Ok(2_500_000.0));
+    /// assert_eq!(HumanNumber::parse("1,250 thousand"), Ok(1_250_000.0));
+    /// assert_eq!(
+    ///     HumanNumber::parse("3.4 zillion").unwrap_err().kind(),
+    ///     ParseErrorKind::UnknownUnit
+    /// );
+    /// ```
+    pub fn parse(input: &str) -> Result<f64, ParseError> {
+        parse_human_number(input)
     }
 
-    fn format(&self, format: HumanFormat) -> String {
-        let multiplier = 10_f64.powi(self.decimals as i32);
-        let rounded = (self.value * multiplier).round() / multiplier;
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
 
-        if !rounded.is_finite() {
+    /// The number spelled out in English words, for cheques, contracts and
+    /// screen readers: "one thousand two hundred thirty-four". Fractions are
+    /// rounded to the nearest whole number first; NaN, infinities and values
+    /// past `u64::MAX` give "-".
+    ///
+    /// ```rust
+    /// use humanly::HumanNumber;
+    ///
+    /// assert_eq!(HumanNumber::from(1_234).words(), "one thousand two hundred thirty-four");
+    /// assert_eq!(HumanNumber::from(-42).words(), "negative forty-two");
+    /// assert_eq!(HumanNumber::from(2.6).words(), "three");
+    /// ```
+    pub fn words(&self) -> String {
+        let rounded = self.number.round();
+        let abs = rounded.abs();
+        if abs.is_nan() || abs >= TWO_POW_64 {
             return "-".to_string();
         }
-        match format {
-            HumanFormat::Concise => format!("{}%", rounded),
-            HumanFormat::Full => format!("{} percent", rounded),
+        let words = spell_cardinal(abs as u64);
+        if rounded < 0.0 {
+            format!("negative {}", words)
+        } else {
+            words
+        }
+    }
+
+    /// The formatted value split into sign, number and unit; see [`Parts`].
+    pub fn into_parts(self, style: Style) -> Parts {
+        Parts::split(&collect(|out| self.write_into(out, style)), &[])
+    }
+
+    // The unit index and the value in that unit, for `f64` numbers.
+    fn scaled(&self) -> (usize, f64) {
+        let number = self.number;
+        let abs_number = number.abs();
+
+        let (magnitude, abs_value) = if abs_number < TWO_POW_64 {
+            let max_idx = NUMBER_SUFFIXES.len() - 1;
+            let whole = abs_number as u64;
+            let fraction = abs_number - whole as f64;
+            magnitude(
+                whole,
+                fraction,
+                UnitSystem::Decimal,
+                max_idx,
+                self.precision,
+            )
+        } else {
+            // past u64: keep to the largest suffix
+            let (divisor, _, magnitude) = number_scale(abs_number);
+            (magnitude, abs_number / divisor)
+        };
+        (magnitude, abs_value.copysign(number))
+    }
+
+    /// Bytes needed by `concise()` output at the default precision, whatever
+    /// the value, locale or [`Config`]: enough for a fixed buffer passed to
+    /// [`format_into`](HumanNumber::format_into). Each decimal place of
+    /// [`precision`](HumanNumber::precision) past the first adds a byte, and a
+    /// [`threshold`](HumanNumber::threshold) adds the digits it keeps.
+    pub const MAX_CONCISE_LEN: usize = 307;
+
+    /// Bytes needed by `full()` output at the default precision, whatever the
+    /// value, locale or [`Config`].
+    pub const MAX_FULL_LEN: usize = 313;
+
+    /// Writes into a byte buffer without allocating and returns the written
+    /// text, or a [`CapacityError`] when it doesn't fit. At the default
+    /// precision a buffer of
+    /// [`MAX_CONCISE_LEN`](HumanNumber::MAX_CONCISE_LEN) or
+    /// [`MAX_FULL_LEN`](HumanNumber::MAX_FULL_LEN) bytes always does.
+    ///
+    /// ```rust
+    /// use humanly::{HumanNumber, Style};
+    ///
+    /// let mut buf = [0u8; HumanNumber::MAX_CONCISE_LEN];
+    /// let text = HumanNumber::from(1_800_000)
+    ///     .format_into(&mut buf, Style::Concise)
+    ///     .unwrap();
+    /// assert_eq!(text, "1.8M");
+    /// ```
+    pub fn format_into<'b>(
+        &self,
+        buf: &'b mut [u8],
+        style: Style,
+    ) -> Result<&'b str, CapacityError> {
+        collect_into(buf, |w| self.write_into(w, style))
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    // Writes `value` to `precision` places and returns it rounded to them.
+    fn write_value(&self, w: &mut impl Write, value: f64, mark: char) -> Result<f64, fmt::Error> {
+        let precision = self.precision;
+        let multiplier = 10_f64.powi(precision as i32);
+        let rounded = match value * multiplier {
+            scaled if scaled.is_finite() => scaled.round() / multiplier,
+            _ => value,
+        };
+        // no "-0" for a small negative, or for -0.0 itself
+        let value = if rounded == 0.0 { 0.0 } else { value };
+        if self.keep_trailing_zeros {
+            write_localized(w, mark, format_args!("{:.*}", precision, value))?;
+        } else if value.fract() == 0.0 {
+            // every digit of the whole part, even past `i64`
+            write!(w, "{}", value)?;
+        } else {
+            write_trimmed(w, value, precision, mark)?;
+        }
+        Ok(rounded)
+    }
+
+    // Whether the value rounds below the threshold, compared exactly for
+    // integers and decimals.
+    fn below_threshold(&self) -> bool {
+        let precision = self.precision as i32;
+        if let Some(rounded) = self.exact.and_then(|exact| exact.round_to(-precision)) {
+            // a limit past i128 is above every value
+            return 10_i128
+                .checked_pow(precision as u32)
+                .and_then(|scale| i128::from(self.threshold).checked_mul(scale))
+                .is_none_or(|limit| rounded.unsigned_abs() < limit.unsigned_abs());
+        }
+        let multiplier = 10_f64.powi(precision);
+        (self.number.abs() * multiplier).round() / multiplier < self.threshold as f64
+    }
+
+    // Every digit of a value under the threshold, grouped in threes.
+    fn write_unabbreviated(&self, w: &mut impl Write, mark: char) -> fmt::Result {
+        let precision = self.precision;
+        let keep_zeros = self.keep_trailing_zeros;
+        let separator = if mark == ',' { '.' } else { ',' };
+        match self
+            .exact
+            .and_then(|exact| exact.round_to(-(precision as i32)))
+        {
+            Some(rounded) => write_grouped_with(w, separator, mark, |mut out| {
+                write_scaled(&mut out, rounded, precision as u32, '.', keep_zeros)
+            }),
+            None => {
+                let multiplier = 10_f64.powi(precision as i32);
+                let rounded = (self.number * multiplier).round() / multiplier;
+                // no "-0" for a small negative
+                let value = if rounded == 0.0 { 0.0 } else { self.number };
+                write_grouped_with(w, separator, mark, |mut out| {
+                    if keep_zeros {
+                        write!(out, "{:.*}", precision, value)
+                    } else {
+                        write_trimmed(&mut out, value, precision, '.')
+                    }
+                })
+            }
+        }
+    }
+
+    // Scientific or engineering notation: a mantissa and a power of ten.
+    fn write_power_of_ten(&self, w: &mut impl Write, style: Style, mark: char) -> fmt::Result {
+        let abs = self.number.abs();
+        if abs == 0.0 {
+            return w.write_char('0');
+        }
+
+        // `{:e}` splits the number exactly, where `log10` can be off by one
+        // and dividing by a power of ten can overflow it. The longest `f64`
+        // it writes is 23 bytes ("2.2250738585072014e-308").
+        let mut buf = [0u8; 32];
+        let shortest = collect_into(&mut buf, |w| write!(w, "{:e}", abs))
+            .expect("`{:e}` of an `f64` fits in 32 bytes");
+        let (mantissa, exponent) = shortest.split_once('e').expect("`{:e}` writes an exponent");
+        let mut mantissa: f64 = mantissa.parse().expect("`{:e}` writes a decimal mantissa");
+        let mut exponent: i32 = exponent.parse().expect("`{:e}` writes an integer exponent");
+        let step = match self.notation {
+            Notation::Engineering => 3,
+            _ => 1,
+        };
+        let shift = exponent.rem_euclid(step);
+        mantissa *= 10_f64.powi(shift);
+        exponent -= shift;
+        // 9.96e5 at one place rounds up to the next power: "1e6"
+        let multiplier = 10_f64.powi(self.precision as i32);
+        let limit = 10_f64.powi(step);
+        if (mantissa * multiplier).round() / multiplier >= limit {
+            mantissa /= limit;
+            exponent += step;
+        }
+
+        self.write_value(w, mantissa.copysign(self.number), mark)?;
+        match style {
+            Style::Concise => write!(w, "e{}", exponent),
+            Style::Full => {
+                w.write_str("×10")?;
+                let mut buf = [0u8; 16];
+                let exponent = collect_into(&mut buf, |w| write!(w, "{}", exponent))
+                    .expect("an `i32` fits in 16 bytes");
+                for c in exponent.chars() {
+                    w.write_char(superscript(c))?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        let mark = decimal_mark(self.locale, style);
+        let locale = self.locale.unwrap_or_default();
+        let precision = self.precision;
+
+        // no suffix fits NaN or infinity, in any notation
+        if !self.number.is_finite() {
+            return w.write_char('-');
+        }
+        if self.below_threshold() {
+            return self.write_unabbreviated(w, mark);
+        }
+        if self.notation != Notation::Suffix {
+            return self.write_power_of_ten(w, style, mark);
+        }
+
+        // Exact decimals only cover the Western ladder; Indian units go
+        // through `f64`.
+        if self.system == NumberSystem::Indian {
+            let (abs_rounded, unit) = pick_unit(self.number, &INDIAN_UNITS, 0, precision);
+            let rounded = self.write_value(w, abs_rounded.copysign(self.number), mark)?;
+            let singular = locale.is_singular(rounded);
+            return match (style, unit.symbol) {
+                (Style::Concise, symbol) => {
+                    w.write_str(Config::current().suffix_case.apply(symbol))
+                }
+                (Style::Full, "") => Ok(()),
+                (Style::Full, "k") => write!(w, " {}", locale.number_word(1, singular)),
+                (Style::Full, _) if singular => write!(w, " {}", unit.singular),
+                (Style::Full, _) => write!(w, " {}", unit.plural),
+            };
+        }
+
+        let exact = self
+            .exact
+            .and_then(|exact| exact.magnitude(NUMBER_SUFFIXES.len() - 1, precision));
+        let (magnitude, rounded) = match exact {
+            Some((magnitude, scaled)) => {
+                let keep_zeros = self.keep_trailing_zeros;
+                write_scaled(w, scaled, precision as u32, mark, keep_zeros)?;
+                (magnitude, scaled as f64 / 10_f64.powi(precision as i32))
+            }
+            None => {
+                let (magnitude, value) = self.scaled();
+                (magnitude, self.write_value(w, value, mark)?)
+            }
+        };
+
+        match style {
+            Style::Concise => {
+                let suffix = NUMBER_SUFFIXES[magnitude];
+                w.write_str(Config::current().suffix_case.apply(suffix))
+            }
+            Style::Full if magnitude == 0 => Ok(()),
+            Style::Full => {
+                let singular = locale.is_singular(rounded);
+                write!(w, " {}", locale.number_word(magnitude, singular))
+            }
+        }
+    }
+}
+
+impl FromStr for HumanNumber {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        parse_human_number(input).map(HumanNumber::from)
+    }
+}
+
+// The superscript form of a digit or minus sign.
+fn superscript(c: char) -> char {
+    match c {
+        '0' => '⁰',
+        '1' => '¹',
+        '2' => '²',
+        '3' => '³',
+        '4' => '⁴',
+        '5' => '⁵',
+        '6' => '⁶',
+        '7' => '⁷',
+        '8' => '⁸',
+        '9' => '⁹',
+        '-' => '⁻',
+        other => other,
+    }
+}
+
+/* -------------------- HumanSize -------------------- */
+
+/// Unit ladder used by [`HumanSize`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UnitSystem {
+    /// IEC, 1024-based: KiB, MiB, GiB…
+    #[default]
+    Binary,
+    /// SI, 1000-based: kB, MB, GB…
+    Decimal,
+    /// JEDEC, 1024-based with the SI letters, as Windows Explorer shows
+    /// sizes: KB, MB, GB… [`HumanSize::parse`] reads these labels as SI.
+    Jedec,
+}
+
+impl UnitSystem {
+    // Short and full names from kilo upwards, and the step between them.
+    pub(crate) fn units(self) -> ([&'static str; 8], [&'static str; 8], f64) {
+        match self {
+            UnitSystem::Binary => (
+                ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB", "ZiB", "YiB"],
+                [
+                    "kibibyte", "mebibyte", "gibibyte", "tebibyte", "pebibyte", "exbibyte",
+                    "zebibyte", "yobibyte",
+                ],
+                1024.0,
+            ),
+            UnitSystem::Decimal => (
+                ["kB", "MB", "GB", "TB", "PB", "EB", "ZB", "YB"],
+                [
+                    "kilobyte",
+                    "megabyte",
+                    "gigabyte",
+                    "terabyte",
+                    "petabyte",
+                    "exabyte",
+                    "zettabyte",
+                    "yottabyte",
+                ],
+                1000.0,
+            ),
+            UnitSystem::Jedec => (
+                ["KB", "MB", "GB", "TB", "PB", "EB", "ZB", "YB"],
+                UnitSystem::Decimal.units().1,
+                1024.0,
+            ),
+        }
+    }
+
+    fn step(self) -> f64 {
+        match self {
+            UnitSystem::Binary | UnitSystem::Jedec => 1024.0,
+            UnitSystem::Decimal => 1000.0,
+        }
+    }
+
+    // `whole + fraction` in the `idx`th power of the step, if that fits in a
+    // `u64`: the quotient by integer division (a shift for binary powers), and
+    // the remainder by one float step.
+    pub(crate) fn in_unit(self, whole: u64, fraction: f64, idx: usize) -> Option<f64> {
+        match (self, idx) {
+            (UnitSystem::Binary | UnitSystem::Jedec, 0..=6) => {
+                let shift = 10 * idx;
+                let remainder = whole & ((1 << shift) - 1);
+                let per_unit = 1.0 / (1_u64 << shift) as f64;
+                Some((whole >> shift) as f64 + (remainder as f64 + fraction) * per_unit)
+            }
+            (UnitSystem::Decimal, 0..=6) => {
+                let unit = POWERS_OF_1000[idx];
+                Some((whole / unit) as f64 + ((whole % unit) as f64 + fraction) / unit as f64)
+            }
+            _ => None,
+        }
+    }
+
+    // The `idx`th power of the step, if it fits in a `u64`.
+    pub(crate) fn power(self, idx: usize) -> Option<u64> {
+        match self {
+            UnitSystem::Binary | UnitSystem::Jedec => 1_u64.checked_shl(10 * idx as u32),
+            UnitSystem::Decimal => POWERS_OF_1000.get(idx).copied(),
+        }
+    }
+
+    // Index of the largest power of the step not above `value`, from its bit
+    // or digit count.
+    pub(crate) fn index(self, value: u64) -> usize {
+        let index = match self {
+            UnitSystem::Binary | UnitSystem::Jedec => {
+                value.checked_ilog2().map_or(0, |bits| bits / 10)
+            }
+            UnitSystem::Decimal => value.checked_ilog10().map_or(0, |digits| digits / 3),
+        };
+        index as usize
+    }
+}
+
+/// A fixed unit for [`HumanSize::unit`]: bytes, IEC (KiB, MiB…) or SI (kB,
+/// MB…).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SizeUnit {
+    B,
+    KiB,
+    MiB,
+    GiB,
+    TiB,
+    PiB,
+    EiB,
+    ZiB,
+    YiB,
+    KB,
+    MB,
+    GB,
+    TB,
+    PB,
+    EB,
+    ZB,
+    YB,
+}
+
+impl SizeUnit {
+    // The ladder the unit is on and its power of the step, 0 for bytes.
+    fn position(self) -> (UnitSystem, usize) {
+        use UnitSystem::{Binary, Decimal};
+        match self {
+            SizeUnit::B => (Binary, 0),
+            SizeUnit::KiB => (Binary, 1),
+            SizeUnit::MiB => (Binary, 2),
+            SizeUnit::GiB => (Binary, 3),
+            SizeUnit::TiB => (Binary, 4),
+            SizeUnit::PiB => (Binary, 5),
+            SizeUnit::EiB => (Binary, 6),
+            SizeUnit::ZiB => (Binary, 7),
+            SizeUnit::YiB => (Binary, 8),
+            SizeUnit::KB => (Decimal, 1),
+            SizeUnit::MB => (Decimal, 2),
+            SizeUnit::GB => (Decimal, 3),
+            SizeUnit::TB => (Decimal, 4),
+            SizeUnit::PB => (Decimal, 5),
+            SizeUnit::EB => (Decimal, 6),
+            SizeUnit::ZB => (Decimal, 7),
+            SizeUnit::YB => (Decimal, 8),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct HumanSize {
+    pub(crate) bytes: u64,
+    pub(crate) negative: bool,
+    system: Option<UnitSystem>,
+    unit: Option<SizeUnit>,
+    locale: Option<Locale>,
+    bits: bool,
+    precision: usize,
+    keep_trailing_zeros: bool,
+    compact_spacing: bool,
+    bare_bytes: bool,
+}
+
+impl Default for HumanSize {
+    fn default() -> Self {
+        Self::from(0)
+    }
+}
+
+impl HumanSize {
+    pub fn from(bytes: u64) -> Self {
+        Self {
+            bytes,
+            negative: false,
+            system: None,
+            unit: None,
+            locale: None,
+            bits: false,
+            precision: 1,
+            keep_trailing_zeros: false,
+            compact_spacing: false,
+            bare_bytes: false,
+        }
+    }
+
+    /// A size that may be negative, such as the change between two
+    /// snapshots: "-2.3 MiB", "-1 kibibyte".
+    ///
+    /// ```rust
+    /// use humanly::HumanSize;
+    ///
+    /// assert_eq!(HumanSize::from_signed(-2_411_725).concise(), "-2.3 MiB");
+    /// assert_eq!(HumanSize::from_signed(-1_024).full(), "-1 kibibyte");
+    /// ```
+    pub fn from_signed(bytes: i64) -> Self {
+        Self {
+            negative: bytes < 0,
+            ..Self::from(bytes.unsigned_abs())
+        }
+    }
+
+    /// The length recorded in a file's metadata. For a directory this is the
+    /// size of the directory entry itself, not of its contents.
+    pub fn from_metadata(metadata: &Metadata) -> Self {
+        Self::from(metadata.len())
+    }
+
+    /// The length of the file at `path`, following symlinks, or the error
+    /// from reading its metadata. Directories are not walked: like
+    /// [`from_metadata`](HumanSize::from_metadata), a directory gives the
+    /// size of its entry.
+    ///
+    /// ```rust
+    /// use humanly::HumanSize;
+    ///
+    /// let manifest = HumanSize::from_path("Cargo.toml").unwrap();
+    /// assert!(manifest.concise().ends_with(" KiB"));
+    /// assert!(HumanSize::from_path("no/such/file").is_err());
+    /// ```
+    pub fn from_path(path: impl AsRef<Path>) -> io::Result<Self> {
+        fs::metadata(path).map(|metadata| Self::from_metadata(&metadata))
+    }
+
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    pub fn decimal(mut self) -> Self {
+        self.system = Some(UnitSystem::Decimal);
+        self.unit = None;
+        self.bits = false;
+        self
+    }
+
+    pub fn binary(mut self) -> Self {
+        self.system = Some(UnitSystem::Binary);
+        self.unit = None;
+        self.bits = false;
+        self
+    }
+
+    /// 1024-based steps with the KB, MB, GB labels Windows Explorer uses:
+    /// "1 MB" and "1 megabyte" for 1,048,576 bytes. See [`UnitSystem::Jedec`].
+    ///
+    /// ```rust
+    /// use humanly::HumanSize;
+    ///
+    /// assert_eq!(HumanSize::from(1_048_576).jedec().concise(), "1 MB");
+    /// assert_eq!(HumanSize::from(1_536).jedec().full(), "1.5 kilobytes");
+    /// ```
+    pub fn jedec(mut self) -> Self {
+        self.system = Some(UnitSystem::Jedec);
+        self.unit = None;
+        self.bits = false;
+        self
+    }
+
+    /// Eight bits to the byte, in 1000-based bit units: "1.5 Mbit" concise,
+    /// "1.5 megabits" full. [`decimal`](HumanSize::decimal) and
+    /// [`binary`](HumanSize::binary) switch back to bytes.
+    ///
+    /// ```rust
+    /// use humanly::HumanSize;
+    ///
+    /// assert_eq!(HumanSize::from(125_000).bits().concise(), "1 Mbit");
+    /// assert_eq!(HumanSize::from(187_500).bits().full(), "1.5 megabits");
+    /// ```
+    pub fn bits(mut self) -> Self {
+        self.unit = None;
+        self.bits = true;
+        self
+    }
+
+    /// Always write the size in `unit`, for columns that should share one:
+    /// 5 GiB in [`SizeUnit::MiB`] is "5120 MiB" and "5120 mebibytes".
+    /// Small sizes round towards zero at the default
+    /// [`precision`](HumanSize::precision), so give more places for them.
+    /// Choosing a unit system or bits afterwards returns to picking the unit.
+    ///
+    /// ```rust
+    /// use humanly::{HumanSize, SizeUnit};
+    ///
+    /// assert_eq!(HumanSize::from(5_368_709_120).unit(SizeUnit::MiB).concise(), "5120 MiB");
+    /// assert_eq!(HumanSize::from(512).unit(SizeUnit::MiB).concise(), "0 MiB");
+    /// assert_eq!(HumanSize::from(512).unit(SizeUnit::MiB).precision(4).concise(), "0.0005 MiB");
+    /// assert_eq!(HumanSize::from(1_500_000).unit(SizeUnit::KB).full(), "1500 kilobytes");
+    /// ```
+    pub fn unit(mut self, unit: SizeUnit) -> Self {
+        self.unit = Some(unit);
+        self.bits = false;
+        self
+    }
+
+    /// Maximum number of decimal places (default 1), trimmed like
+    /// [`HumanNumber::precision`]; 0 rounds to whole units. Sizes under one
+    /// kilobyte are always whole bytes.
+    ///
+    /// ```rust
+    /// use humanly::HumanSize;
+    ///
+    /// let size = HumanSize::from(10_749_999_999).decimal();
+    /// assert_eq!(size.concise(), "10.7 GB");
+    /// assert_eq!(size.precision(2).concise(), "10.75 GB");
+    /// assert_eq!(size.precision(0).full(), "11 gigabytes");
+    /// assert_eq!(
+    ///     HumanSize::from(10_000_000_000).decimal().precision(2).keep_trailing_zeros().concise(),
+    ///     "10.00 GB"
+    /// );
+    /// ```
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision.min(300);
+        self
+    }
+
+    /// Always write [`precision`](HumanSize::precision) decimal places, so a
+    /// column of sizes lines up: "10.00 GB", "10.75 GB".
+    pub fn keep_trailing_zeros(mut self) -> Self {
+        self.keep_trailing_zeros = true;
+        self
+    }
+
+    /// No space between the value and the unit in `concise()` output, as
+    /// GitHub and htop show sizes: "5MiB", "1.5Mbit". `full()` keeps its space.
+    ///
+    /// ```rust
+    /// use humanly::HumanSize;
+    ///
+    /// assert_eq!(HumanSize::from(5_242_880).compact_spacing().concise(), "5MiB");
+    /// assert_eq!(HumanSize::from(5_242_880).compact_spacing().full(), "5 mebibytes");
+    /// ```
+    pub fn compact_spacing(mut self) -> Self {
+        self.compact_spacing = true;
+        self
+    }
+
+    /// Leave the "B" off sizes under one kilobyte in `concise()` output:
+    /// "500" rather than "500 B".
+    pub fn bare_bytes(mut self) -> Self {
+        self.bare_bytes = true;
+        self
+    }
+
+    /// Reads a byte count back from text like "512MiB", "1.5 GB" or "2048".
+    ///
+    /// Units are SI (kB, MB…) or IEC (KiB, MiB…), matched without regard to
+    /// case, with or without the trailing "B" and with optional whitespace
+    /// before them; a bare number is bytes. The `concise()` output of any size
+    /// reads back to within its rounding.
+    ///
+    /// ```rust
+    /// use humanly::{HumanSize, ParseErrorKind};
+    ///
+    /// assert_eq!(HumanSize::parse("512MiB"), Ok(536_870_912));
+    /// assert_eq!(HumanSize::parse("1.5 GB"), Ok(1_500_000_000));
+    /// assert_eq!(HumanSize::parse("2048"), Ok(2_048));
+    /// assert_eq!(
+    ///     HumanSize::parse("12 MiBs").unwrap_err().kind(),
+    ///     ParseErrorKind::UnknownUnit
+    /// );
+    /// ```
+    pub fn parse(input: &str) -> Result<u64, ParseError> {
+        parse_size(input)
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    /// The formatted value split into sign, number and unit; see [`Parts`].
+    pub fn into_parts(self, style: Style) -> Parts {
+        Parts::split(&collect(|out| self.write_into(out, style)), &[])
+    }
+
+    /// Longest `concise()` output in bytes at the default precision, under
+    /// any locale or [`Config`]. Each decimal place of
+    /// [`precision`](HumanSize::precision) past the first adds a byte, and a
+    /// pinned [`unit`](HumanSize::unit) below the size's own adds digits.
+    pub const MAX_CONCISE_LEN: usize = 18;
+
+    /// Longest `full()` output in bytes at the default precision.
+    pub const MAX_FULL_LEN: usize = 24;
+
+    /// Writes into a byte buffer without allocating, like
+    /// [`HumanNumber::format_into`].
+    ///
+    /// ```rust
+    /// use humanly::{HumanSize, Style};
+    ///
+    /// let mut buf = [0u8; HumanSize::MAX_CONCISE_LEN];
+    /// let text = HumanSize::from(1_500_000u64)
+    ///     .format_into(&mut buf, Style::Concise)
+    ///     .unwrap();
+    /// assert_eq!(text, "1.4 MiB");
+    /// ```
+    pub fn format_into<'b>(
+        &self,
+        buf: &'b mut [u8],
+        style: Style,
+    ) -> Result<&'b str, CapacityError> {
+        collect_into(buf, |w| self.write_into(w, style))
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        if self.bits {
+            return self.write_bits(w, style);
+        }
+        let locale = self.locale.unwrap_or_default();
+        let pinned = self.unit.map(SizeUnit::position);
+
+        // Whole bytes below the first unit, or when pinned to bytes
+        if pinned.map_or(self.bytes < 1024, |(_, power)| power == 0) {
+            if self.negative && self.bytes > 0 {
+                w.write_char('-')?;
+            }
+            return match style {
+                Style::Concise => {
+                    write!(w, "{}", self.bytes)?;
+                    if self.bare_bytes {
+                        return Ok(());
+                    }
+                    self.write_symbol(w, "B")
+                }
+                Style::Full => write!(
+                    w,
+                    "{} {}",
+                    self.bytes,
+                    locale.byte_word(locale.is_singular(self.bytes as f64))
+                ),
+            };
+        }
+
+        let (system, idx, size) = match pinned {
+            Some((system, power)) => {
+                let size = system
+                    .in_unit(self.bytes, 0.0, power)
+                    .unwrap_or_else(|| self.bytes as f64 / system.step().powi(power as i32));
+                (system, power - 1, size)
+            }
+            None => {
+                let system = self.system.unwrap_or(Config::current().unit_system);
+                // At 1024 bytes and up the index is at least 1 (KiB or kB)
+                let units = system.units().0.len();
+                let (idx, size) = magnitude(self.bytes, 0.0, system, units, self.precision);
+                (system, idx - 1, size)
+            }
+        };
+        let (units_short, units_full, _) = system.units();
+
+        let rounded = self.write_value(w, size, style)?;
+
+        match style {
+            Style::Concise => self.write_symbol(w, units_short[idx]),
+            Style::Full => {
+                write!(w, " {}", units_full[idx])?;
+                if !locale.is_singular(rounded) {
+                    w.write_char('s')?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    // Writes `value` to `precision` places and returns it rounded to them. The
+    // sign goes on after rounding, so a size pinned to a large unit that
+    // rounds to zero is "0 MiB" and not "-0 MiB".
+    fn write_value(&self, w: &mut impl Write, value: f64, style: Style) -> Result<f64, fmt::Error> {
+        let precision = self.precision;
+        let multiplier = 10_f64.powi(precision as i32);
+        let rounded = (value * multiplier).round() / multiplier;
+        if self.negative && rounded > 0.0 {
+            w.write_char('-')?;
+        }
+        let mark = decimal_mark(self.locale, style);
+        if self.keep_trailing_zeros {
+            write_localized(w, mark, format_args!("{:.*}", precision, rounded))?;
+        } else if rounded.fract() == 0.0 {
+            write!(w, "{}", rounded as u64)?;
+        } else {
+            write_trimmed(w, rounded, precision, mark)?;
+        }
+        Ok(rounded)
+    }
+
+    fn write_symbol(&self, w: &mut impl Write, symbol: &str) -> fmt::Result {
+        if !self.compact_spacing {
+            w.write_char(' ')?;
+        }
+        w.write_str(symbol)
+    }
+
+    fn write_bits(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        const SHORT: [&str; 7] = ["bit", "kbit", "Mbit", "Gbit", "Tbit", "Pbit", "Ebit"];
+        const FULL: [&str; 7] = [
+            "bit", "kilobit", "megabit", "gigabit", "terabit", "petabit", "exabit",
+        ];
+
+        // u64::MAX bytes is under 148 Ebit, so the ladder never runs out
+        let bits = self.bytes as f64 * 8.0;
+        let (scaled, idx) = scale_units(bits, 1000.0, SHORT.len(), self.precision);
+        let rounded = self.write_value(w, scaled, style)?;
+
+        match style {
+            Style::Concise => self.write_symbol(w, SHORT[idx]),
+            Style::Full => {
+                write!(w, " {}", FULL[idx])?;
+                if !self.locale.unwrap_or_default().is_singular(rounded) {
+                    w.write_char('s')?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromStr for HumanSize {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        parse_size(input).map(HumanSize::from)
+    }
+}
+
+impl HumanSize {
+    pub(crate) fn signed_bytes(&self) -> i128 {
+        let bytes = self.bytes as i128;
+        if self.negative { -bytes } else { bytes }
+    }
+
+    // This size's settings with a new byte count, its magnitude saturated at
+    // `u64::MAX`.
+    pub(crate) fn with_signed_bytes(self, bytes: i128) -> Self {
+        Self {
+            bytes: u64::try_from(bytes.unsigned_abs()).unwrap_or(u64::MAX),
+            negative: bytes < 0,
+            ..self
+        }
+    }
+}
+
+/// Sizes compare by their byte count alone, so `HumanSize::from(1_024)` and
+/// `HumanSize::from(1_024).decimal()` are equal.
+impl PartialEq for HumanSize {
+    fn eq(&self, other: &Self) -> bool {
+        self.signed_bytes() == other.signed_bytes()
+    }
+}
+
+impl Eq for HumanSize {}
+
+impl PartialOrd for HumanSize {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HumanSize {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.signed_bytes().cmp(&other.signed_bytes())
+    }
+}
+
+/// Adds the byte counts, keeping the left-hand size's unit system, precision
+/// and other settings. A total past `u64::MAX` bytes saturates.
+impl Add for HumanSize {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.with_signed_bytes(self.signed_bytes() + rhs.signed_bytes())
+    }
+}
+
+impl AddAssign for HumanSize {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+/// Subtracts the byte counts, keeping the left-hand size's settings. The
+/// difference is signed rather than saturating at zero and never panics:
+/// taking a larger size away gives a negative one, written "-1.5 KiB".
+///
+/// ```rust
+/// use humanly::HumanSize;
+///
+/// let free = HumanSize::from(1_024).binary() - HumanSize::from(2_560);
+/// assert_eq!(free.concise(), "-1.5 KiB");
+/// ```
+impl Sub for HumanSize {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.with_signed_bytes(self.signed_bytes() - rhs.signed_bytes())
+    }
+}
+
+impl SubAssign for HumanSize {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// Totals the sizes with the first one's settings, or gives zero bytes for an
+/// empty iterator.
+///
+/// ```rust
+/// use humanly::HumanSize;
+///
+/// let files = [1_200_000_u64, 800_000, 500_000];
+/// let total: HumanSize = files.iter().map(|&len| HumanSize::from(len)).sum();
+/// assert_eq!(total.decimal().concise(), "2.5 MB");
+/// ```
+impl Sum for HumanSize {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(Add::add).unwrap_or_default()
+    }
+}
+
+impl<'a> Sum<&'a HumanSize> for HumanSize {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/* -------------------- HumanDuration -------------------- */
+
+/// How long ago a timestamp was, or how far ahead: "3h ago" concise, "3 hours
+/// ago" full, and "2d from now" or "2 days from now" for the future.
+///
+/// The count is the largest whole unit that fits, truncated: 13 days is "1w".
+/// Months and years are the Gregorian averages of 30.436875 and 365.2425
+/// days, so 30 days is still "4w" and 31 days "1mo", and 365 days is "11mo"
+/// until the year is complete at 366. Concise suffixes are the same in both
+/// directions: s, m, h, d, w, mo and y.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HumanDuration {
+    pub(crate) system_time: Option<SystemTime>,
+    now: Option<SystemTime>,
+    elapsed: Option<Duration>,
+    future: bool,
+    locale: Locale,
+    granularity: usize,
+    calendar: bool,
+    natural: bool,
+    offset_minutes: i32,
+    just_now_within: Duration,
+    max_unit: Option<TimeUnit>,
+    min_unit: TimeUnit,
+}
+
+impl Default for HumanDuration {
+    fn default() -> Self {
+        Self::from(None)
+    }
+}
+
+impl HumanDuration {
+    pub fn from(system_time: Option<SystemTime>) -> Self {
+        Self {
+            system_time,
+            now: None,
+            elapsed: None,
+            future: false,
+            locale: Locale::default(),
+            granularity: 1,
+            calendar: false,
+            natural: false,
+            offset_minutes: 0,
+            just_now_within: Duration::from_secs(1),
+            max_unit: None,
+            min_unit: TimeUnit::Second,
+        }
+    }
+
+    /// How long ago from the time that has passed rather than a timestamp,
+    /// e.g. from `Instant::elapsed()`. Nothing reads the clock, so the output
+    /// is the same on every run; [`future`](HumanDuration::future) turns
+    /// "ago" into "from now".
+    ///
+    /// ```rust
+    /// use humanly::HumanDuration;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(HumanDuration::from_duration(Duration::from_secs(90)).concise(), "1m ago");
+    /// assert_eq!(HumanDuration::from_secs(90).to_string(), "1 minute ago");
+    /// assert_eq!(HumanDuration::from_secs(7_200).future().concise(), "2h from now");
+    /// ```
+    pub fn from_duration(elapsed: Duration) -> Self {
+        Self {
+            elapsed: Some(elapsed),
+            ..Self::from(None)
+        }
+    }
+
+    /// [`from_duration`](HumanDuration::from_duration) for a whole number of
+    /// seconds.
+    pub fn from_secs(secs: u64) -> Self {
+        Self::from_duration(Duration::from_secs(secs))
+    }
+
+    /// Read a [`from_duration`](HumanDuration::from_duration) or
+    /// [`from_secs`](HumanDuration::from_secs) value as time ahead: "2h from
+    /// now". A timestamp's direction comes from the clock, so this leaves
+    /// [`from`](HumanDuration::from) values as they are.
+    pub fn future(mut self) -> Self {
+        self.future = true;
+        self
+    }
+
+    // The moment described, for timestamp-based formats such as serde.
+    #[cfg(feature = "serde")]
+    pub(crate) fn timestamp(&self) -> Option<SystemTime> {
+        self.moment(self.now.unwrap_or_else(SystemTime::now))
+    }
+
+    fn moment(&self, now: SystemTime) -> Option<SystemTime> {
+        let Some(elapsed) = self.elapsed else {
+            return self.system_time;
+        };
+        if self.future {
+            now.checked_add(elapsed)
+        } else {
+            now.checked_sub(elapsed)
+        }
+    }
+
+    /// Measure from `now` instead of the current time, e.g. on targets
+    /// without a system clock, relative to the end of a log, or in tests
+    /// that need the same output on every run.
+    ///
+    /// ```rust
+    /// use humanly::HumanDuration;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let log_end = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    /// let event = log_end - Duration::from_secs(90);
+    /// assert_eq!(HumanDuration::from(Some(event)).as_of(log_end).to_string(), "1 minute ago");
+    /// ```
+    pub fn as_of(mut self, now: SystemTime) -> Self {
+        self.now = Some(now);
+        self
+    }
+
+    /// How many non-zero units to show (default 1): at 2, "2h 59m ago" rather
+    /// than "2h ago", and "2 hours 59 minutes ago" in full. Smaller units are
+    /// cut off, not rounded.
+    ///
+    /// ```rust
+    /// use humanly::HumanDuration;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    /// let then = HumanDuration::from(Some(now - Duration::from_secs(10_740))).as_of(now);
+    /// assert_eq!(then.concise(), "2h ago");
+    /// assert_eq!(then.granularity(2).concise(), "2h 59m ago");
+    /// ```
+    pub fn granularity(mut self, units: usize) -> Self {
+        self.granularity = units;
+        self
+    }
+
+    /// The largest unit to report in (default [`TimeUnit::Year`]); longer
+    /// times count up in it: "400 days ago" rather than "1 year ago" at
+    /// [`TimeUnit::Day`], for logs where months and years are too vague.
+    ///
+    /// ```rust
+    /// use humanly::{HumanDuration, TimeUnit};
+    ///
+    /// let then = HumanDuration::from_secs(400 * 86_400);
+    /// assert_eq!(then.full(), "1 year ago");
+    /// assert_eq!(then.max_unit(TimeUnit::Day).full(), "400 days ago");
+    /// assert_eq!(then.max_unit(TimeUnit::Week).concise(), "57w ago");
+    /// ```
+    pub fn max_unit(mut self, unit: TimeUnit) -> Self {
+        self.max_unit = Some(unit);
+        self
+    }
+
+    /// The smallest unit to report in (default [`TimeUnit::Second`]). Anything
+    /// under one of it is "just now", and [`granularity`](HumanDuration::granularity)
+    /// stops at it. It wins over a smaller [`max_unit`](HumanDuration::max_unit).
+    ///
+    /// ```rust
+    /// use humanly::{HumanDuration, TimeUnit};
+    ///
+    /// assert_eq!(HumanDuration::from_secs(59).min_unit(TimeUnit::Minute).full(), "just now");
+    /// assert_eq!(HumanDuration::from_secs(60).min_unit(TimeUnit::Minute).full(), "1 minute ago");
+    /// assert_eq!(
+    ///     HumanDuration::from_secs(3_725).granularity(3).min_unit(TimeUnit::Minute).concise(),
+    ///     "1h 2m ago"
+    /// );
+    /// ```
+    pub fn min_unit(mut self, unit: TimeUnit) -> Self {
+        self.min_unit = unit;
+        self
+    }
+
+    /// How close to now, ahead or behind, reads as "just now" (default and
+    /// least one second). A window of a few seconds absorbs clock skew between
+    /// machines; the window's own length is outside it.
+    ///
+    /// ```rust
+    /// use humanly::HumanDuration;
+    /// use std::time::Duration;
+    ///
+    /// let window = Duration::from_secs(30);
+    /// assert_eq!(HumanDuration::from_secs(29).just_now_within(window).concise(), "just now");
+    /// assert_eq!(HumanDuration::from_secs(30).just_now_within(window).concise(), "30s ago");
+    /// assert_eq!(HumanDuration::from_secs(5).future().just_now_within(window).full(), "just now");
+    /// ```
+    pub fn just_now_within(mut self, window: Duration) -> Self {
+        self.just_now_within = window;
+        self
+    }
+
+    /// Name days by the calendar rather than by hours passed: "today",
+    /// "yesterday", "tomorrow", then "last Tuesday" or "next Friday" up to six
+    /// days away, and the usual count from a week on. Days turn at local
+    /// midnight, set with [`utc_offset`](HumanDuration::utc_offset). Concise
+    /// output shortens the weekday: "last Tue".
+    ///
+    /// ```rust
+    /// use humanly::HumanDuration;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// // 2023-11-14 22:13 UTC, a Tuesday
+    /// let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    /// let then = |hours: u64| HumanDuration::from(Some(now - Duration::from_secs(hours * 3_600)));
+    /// assert_eq!(then(20).as_of(now).full(), "20 hours ago");
+    /// assert_eq!(then(20).as_of(now).calendar().full(), "today");
+    /// assert_eq!(then(47).as_of(now).full(), "yesterday");
+    /// assert_eq!(then(47).as_of(now).calendar().full(), "last Sunday");
+    /// assert_eq!(then(100).as_of(now).calendar().concise(), "last Fri");
+    /// ```
+    pub fn calendar(mut self) -> Self {
+        self.calendar = true;
+        self
+    }
+
+    /// Word a single week, month or year away the way people say it, in full
+    /// output: "last week", "next month", "last year" rather than "1 week
+    /// ago", just as one day away is already "yesterday" or "tomorrow".
+    /// Counts other than one are unchanged.
+    ///
+    /// ```rust
+    /// use humanly::HumanDuration;
+    ///
+    /// let week = 7 * 86_400;
+    /// assert_eq!(HumanDuration::from_secs(week).full(), "1 week ago");
+    /// assert_eq!(HumanDuration::from_secs(week).natural().full(), "last week");
+    /// assert_eq!(HumanDuration::from_secs(week).future().natural().full(), "next week");
+    /// assert_eq!(HumanDuration::from_secs(2 * week).natural().full(), "2 weeks ago");
+    /// ```
+    pub fn natural(mut self) -> Self {
+        self.natural = true;
+        self
+    }
+
+    /// Where local midnight falls for [`calendar`](HumanDuration::calendar)
+    /// days, in minutes east of UTC (default 0).
+    pub fn utc_offset(mut self, minutes: i32) -> Self {
+        self.offset_minutes = minutes;
+        self
+    }
+
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    /// The long form, as `Display` writes it.
+    ///
+    /// ```rust
+    /// use humanly::HumanDuration;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    /// let then = now - Duration::from_secs(7_200);
+    /// assert_eq!(HumanDuration::from(Some(then)).as_of(now).full(), "2 hours ago");
+    /// ```
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    /// The formatted value split into number, unit and qualifier ("ago"); see
+    /// [`Parts`].
+    pub fn into_parts(self, style: Style) -> Parts {
+        let (_, after) = self.locale.ago();
+        Parts::split(
+            &collect(|out| self.write_into(out, style)),
+            &[after, " ago", " from now"],
+        )
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        const UNITS: [TimeUnit; 7] = [
+            TimeUnit::Year,
+            TimeUnit::Month,
+            TimeUnit::Week,
+            TimeUnit::Day,
+            TimeUnit::Hour,
+            TimeUnit::Minute,
+            TimeUnit::Second,
+        ];
+
+        let (elapsed, future) = match (self.elapsed, self.system_time) {
+            (Some(elapsed), _) => (elapsed, self.future),
+            (None, Some(st)) => {
+                let now = self.now.unwrap_or_else(SystemTime::now);
+                match now.duration_since(st) {
+                    Ok(dur) => (dur, false),
+                    Err(err) => (err.duration(), true),
+                }
+            }
+            (None, None) => return w.write_str("-"),
+        };
+
+        let secs = elapsed.as_secs();
+        // under the smallest unit, a second by default, is "just now"
+        let min_unit = self.min_unit;
+        if secs < min_unit.seconds() || elapsed < self.just_now_within {
+            return w.write_str(self.locale.just_now());
+        }
+        if self.calendar {
+            let now = self.now.unwrap_or_else(SystemTime::now);
+            if let Some(then) = self.moment(now) {
+                let now = CivilDateTime::from_system_time(now, self.offset_minutes);
+                let then = CivilDateTime::from_system_time(then, self.offset_minutes);
+                if (then.days - now.days).abs() < 7 {
+                    return self.write_calendar_day(w, style, &then, then.days - now.days);
+                }
+            }
+        }
+
+        // The largest unit allowed that fits, then the next non-zero ones down
+        // to the smallest allowed, truncated
+        let max_unit = self.max_unit.unwrap_or(TimeUnit::Year).max(min_unit);
+        let first = UNITS
+            .iter()
+            .position(|&unit| unit <= max_unit && secs >= unit.seconds());
+        let mut rest = secs;
+        // At most one part per unit, so a fixed array holds them however large
+        // the granularity
+        let mut parts = [(0, TimeUnit::Second); UNITS.len()];
+        let mut len = 0;
+        for &unit in UNITS[first.unwrap_or(UNITS.len() - 1)..]
+            .iter()
+            .take_while(|&&unit| unit >= min_unit)
+        {
+            if len == self.granularity.max(1) {
+                break;
+            }
+            let count = rest / unit.seconds();
+            rest %= unit.seconds();
+            if count > 0 {
+                parts[len] = (count, unit);
+                len += 1;
+            }
+        }
+        let parts = &parts[..len];
+
+        match style {
+            Style::Concise => {
+                for (i, &(count, unit)) in parts.iter().enumerate() {
+                    if i > 0 {
+                        w.write_char(' ')?;
+                    }
+                    write!(w, "{}{}", count, unit.symbol())?;
+                }
+                w.write_str(if future { " from now" } else { " ago" })
+            }
+            Style::Full => {
+                if let [(1, TimeUnit::Day)] = parts[..] {
+                    return w.write_str(if future {
+                        self.locale.tomorrow()
+                    } else {
+                        self.locale.yesterday()
+                    });
+                }
+                if let [(1, unit)] = parts[..]
+                    && self.natural
+                    && let Some(phrase) = self.locale.adjacent(unit, future)
+                {
+                    return w.write_str(phrase);
+                }
+                let (before, after) = if future {
+                    self.locale.ahead()
+                } else {
+                    self.locale.ago()
+                };
+                w.write_str(before)?;
+                for (i, &(count, unit)) in parts.iter().enumerate() {
+                    if i > 0 {
+                        w.write_char(' ')?;
+                    }
+                    write!(w, "{} {}", count, self.locale.time_unit(unit, count == 1))?;
+                }
+                w.write_str(after)
+            }
+        }
+    }
+
+    // `days` is how many calendar days `then` is after today, under a week
+    fn write_calendar_day(
+        &self,
+        w: &mut impl Write,
+        style: Style,
+        then: &CivilDateTime,
+        days: i64,
+    ) -> fmt::Result {
+        match days {
+            0 => return w.write_str(self.locale.today()),
+            -1 => return w.write_str(self.locale.yesterday()),
+            1 => return w.write_str(self.locale.tomorrow()),
+            _ => {}
+        }
+        let (before, after) = self.locale.weekday_relative(days > 0);
+        let name = self.locale.weekday(then.weekday);
+        w.write_str(before)?;
+        match style {
+            Style::Concise => name.chars().take(3).try_for_each(|c| w.write_char(c))?,
+            Style::Full => w.write_str(name)?,
+        }
+        w.write_str(after)
+    }
+}
+
+/* -------------------- HumanTime -------------------- */
+
+/// How [`HumanTime::coarse`] rounds to its one decimal place.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Rounding {
+    /// To the nearest, halves up: 1.25 hours is "1.3 hours"
+    #[default]
+    Nearest,
+    /// Toward zero: 1.29 hours is "1.2 hours"
+    Down,
+    /// Away from zero, so an ETA is never short: 1.21 hours is "1.3 hours"
+    Up,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HumanTime {
+    pub(crate) duration: Duration,
+    pub(crate) negative: bool,
+    locale: Locale,
+    max_unit: Option<TimeUnit>,
+    clock_precision: usize,
+    clock_hours: bool,
+    precise: bool,
+    approximate: bool,
+    coarse: bool,
+    rounding: Rounding,
+    separator: Option<&'static str>,
+    final_separator: Option<&'static str>,
+}
+
+impl HumanTime {
+    pub fn from(duration: Duration) -> Self {
+        Self {
+            duration,
+            negative: false,
+            locale: Locale::default(),
+            max_unit: None,
+            clock_precision: 0,
+            clock_hours: false,
+            precise: false,
+            approximate: false,
+            coarse: false,
+            rounding: Rounding::Nearest,
+            separator: None,
+            final_separator: None,
+        }
+    }
+
+    // A signed duration, written with a leading "-" when negative: "-1h 5m".
+    #[cfg(any(test, feature = "serde", feature = "chrono", feature = "time"))]
+    pub(crate) fn from_signed(duration: Duration, negative: bool) -> Self {
+        Self {
+            negative,
+            ..Self::from(duration)
+        }
+    }
+
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// The largest unit to break the duration into (default
+    /// [`TimeUnit::Day`]). [`TimeUnit::Hour`] gives hour totals like
+    /// "55h 33m 20s", and [`TimeUnit::Week`] adds weeks; months and years
+    /// have no fixed length, so they count as weeks.
+    ///
+    /// ```rust
+    /// use humanly::{HumanTime, TimeUnit};
+    /// use std::time::Duration;
+    ///
+    /// let time = HumanTime::from(Duration::from_secs(200_000));
+    /// assert_eq!(time.concise(), "2d 7h 33m 20s");
+    /// assert_eq!(time.max_unit(TimeUnit::Hour).concise(), "55h 33m 20s");
+    /// ```
+    pub fn max_unit(mut self, unit: TimeUnit) -> Self {
+        self.max_unit = Some(unit);
+        self
+    }
+
+    /// Add what is left below a second to longer durations, in the largest
+    /// of ms, µs and ns that fits: "1m 30s 250ms". Durations under a second
+    /// show it anyway.
+    ///
+    /// ```rust
+    /// use humanly::HumanTime;
+    /// use std::time::Duration;
+    ///
+    /// let time = HumanTime::from(Duration::from_millis(90_250));
+    /// assert_eq!(time.concise(), "1m 30s");
+    /// assert_eq!(time.precise().concise(), "1m 30s 250ms");
+    /// assert_eq!(time.precise().full(), "1 minute 30 seconds 250 milliseconds");
+    /// ```
+    pub fn precise(mut self) -> Self {
+        self.precise = true;
+        self
+    }
+
+    /// One rounded unit for estimates, where "1h 53m 12s" would be false
+    /// precision: "about 2 hours" full, "~2h" concise. The count rounds to
+    /// the nearest whole unit, halves up, so 90 minutes is "about 2 hours".
+    /// For hours and up, a quarter to a half past the count keeps it and
+    /// reads "over 2 hours" (">2h"). Under a minute is "less than a minute"
+    /// ("<1m"). The largest unit follows [`max_unit`](HumanTime::max_unit).
+    ///
+    /// ```rust
+    /// use humanly::HumanTime;
+    /// use std::time::Duration;
+    ///
+    /// let eta = |secs| HumanTime::from(Duration::from_secs(secs)).approximate();
+    /// assert_eq!(eta(6_792).full(), "about 2 hours");
+    /// assert_eq!(eta(6_792).concise(), "~2h");
+    /// assert_eq!(eta(8_400).full(), "over 2 hours");
+    /// assert_eq!(eta(2_700).full(), "about 45 minutes");
+    /// assert_eq!(eta(42).full(), "less than a minute");
+    /// ```
+    pub fn approximate(mut self) -> Self {
+        self.approximate = true;
+        self
+    }
+
+    /// Only the largest unit that is at least one, to one decimal place:
+    /// 5,400 seconds is "1.5 hours" full and "1.5h" concise rather than
+    /// "1h 30m". Whole values drop the ".0". Under a minute stays in seconds;
+    /// the largest unit follows [`max_unit`](HumanTime::max_unit), and
+    /// [`rounding`](HumanTime::rounding) picks how the place is rounded.
+    ///
+    /// ```rust
+    /// use humanly::{HumanTime, Rounding};
+    /// use std::time::Duration;
+    ///
+    /// let time = |secs| HumanTime::from(Duration::from_secs(secs)).coarse();
+    /// assert_eq!(time(5_400).full(), "1.5 hours");
+    /// assert_eq!(time(7_200).concise(), "2h");
+    /// assert_eq!(time(42).full(), "42 seconds");
+    /// assert_eq!(time(4_330).concise(), "1.2h");
+    /// assert_eq!(time(4_330).rounding(Rounding::Up).concise(), "1.3h");
+    /// ```
+    pub fn coarse(mut self) -> Self {
+        self.coarse = true;
+        self
+    }
+
+    /// How [`coarse`](HumanTime::coarse) output rounds (default
+    /// [`Rounding::Nearest`]).
+    pub fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// What goes between the units of full output (default " "). The last
+    /// two units are joined by [`final_separator`](HumanTime::final_separator)
+    /// when one is set. Concise output is always joined by spaces.
+    ///
+    /// ```rust
+    /// use humanly::HumanTime;
+    /// use std::time::Duration;
+    ///
+    /// let time = HumanTime::from(Duration::from_secs(3_672));
+    /// assert_eq!(time.full(), "1 hour 1 minute 12 seconds");
+    /// assert_eq!(time.separator(", ").full(), "1 hour, 1 minute, 12 seconds");
+    /// assert_eq!(
+    ///     time.separator(", ").final_separator(" and ").full(),
+    ///     "1 hour, 1 minute and 12 seconds"
+    /// );
+    /// assert_eq!(time.separator(", ").concise(), "1h 1m 12s");
+    /// ```
+    pub fn separator(mut self, separator: &'static str) -> Self {
+        self.separator = Some(separator);
+        self
+    }
+
+    /// What goes between the last two units of full output, such as " and "
+    /// or ", and ": "1 hour and 5 minutes". Defaults to the
+    /// [`separator`](HumanTime::separator).
+    pub fn final_separator(mut self, separator: &'static str) -> Self {
+        self.final_separator = Some(separator);
+        self
+    }
+
+    /// Decimal places of a second in [`clock`](HumanTime::clock) output
+    /// (default 0, at most 9): "1:01:05.250".
+    pub fn clock_precision(mut self, precision: usize) -> Self {
+        self.clock_precision = precision.min(9);
+        self
+    }
+
+    /// Always write the hour field in [`clock`](HumanTime::clock) output,
+    /// so a column of times lines up: "0:07:32".
+    pub fn clock_hours(mut self) -> Self {
+        self.clock_hours = true;
+        self
+    }
+
+    /// The duration as a media player shows it: "1:01:05", or "07:32" under
+    /// an hour. Minutes and seconds take two digits, and hours grow past a day
+    /// ("36:00:00").
+    ///
+    /// ```rust
+    /// use humanly::HumanTime;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(HumanTime::from(Duration::from_secs(3_665)).clock(), "1:01:05");
+    /// assert_eq!(HumanTime::from(Duration::from_secs(452)).clock(), "07:32");
+    /// assert_eq!(HumanTime::from(Duration::from_secs(452)).clock_hours().clock(), "0:07:32");
+    /// assert_eq!(
+    ///     HumanTime::from(Duration::from_millis(3_665_250)).clock_precision(3).clock(),
+    ///     "1:01:05.250"
+    /// );
+    /// ```
+    pub fn clock(&self) -> String {
+        collect(|out| typeset(out, |w| self.write_clock(w)))
+    }
+
+    fn write_clock(&self, w: &mut impl Write) -> fmt::Result {
+        // Whole duration in units of the last digit shown, rounded, so
+        // 59.9996 s at three places carries into the minute.
+        let precision = self.clock_precision as u32;
+        let per_second = 10_u128.pow(precision);
+        let nanos_per_unit = 10_u128.pow(9 - precision);
+        let nanos = self.duration.as_nanos();
+        let units = (nanos + nanos_per_unit / 2) / nanos_per_unit;
+
+        let (secs, fraction) = (units / per_second, units % per_second);
+        let (hours, minutes, seconds) = (secs / 3600, secs % 3600 / 60, secs % 60);
+
+        if self.negative && units > 0 {
+            w.write_char('-')?;
+        }
+        if hours > 0 || self.clock_hours {
+            write!(w, "{}:", hours)?;
+        }
+        write!(w, "{:02}:{:02}", minutes, seconds)?;
+        if precision > 0 {
+            w.write_char(decimal_mark(None, Style::Concise))?;
+            write!(w, "{:0width$}", fraction, width = precision as usize)?;
+        }
+        Ok(())
+    }
+
+    /// Reads a duration back from text like "90s", "1h 30m" or "1.5h".
+    ///
+    /// Each part is a number and one of the units w, d, h, m or s (or their
+    /// names: "min", "hours"…), with optional whitespace; each unit may appear
+    /// once. A bare number is seconds. The `concise()` output of any whole
+    /// number of seconds reads back to the same duration.
+    ///
+    /// ```rust
+    /// use humanly::{HumanTime, ParseErrorKind};
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(HumanTime::parse("1h 30m"), Ok(Duration::from_secs(5_400)));
+    /// assert_eq!(HumanTime::parse("1.5h"), Ok(Duration::from_secs(5_400)));
+    /// assert_eq!(HumanTime::parse("90"), Ok(Duration::from_secs(90)));
+    /// assert_eq!(
+    ///     HumanTime::parse("1h 2h").unwrap_err().kind(),
+    ///     ParseErrorKind::DuplicateUnit
+    /// );
+    /// ```
+    pub fn parse(input: &str) -> Result<Duration, ParseError> {
+        parse_duration(input)
+    }
+
+    /// The duration in ISO 8601 form for machines: days, then hours, minutes
+    /// and seconds with any fraction, as in "P2DT3H4M5S" or "PT0.5S". Zero is
+    /// "PT0S". Years and months have no fixed length, so days are never
+    /// carried into them.
+    ///
+    /// ```rust
+    /// use humanly::HumanTime;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(HumanTime::from(Duration::from_secs(183_845)).iso8601(), "P2DT3H4M5S");
+    /// assert_eq!(HumanTime::from(Duration::from_millis(500)).iso8601(), "PT0.5S");
+    /// assert_eq!(HumanTime::from(Duration::ZERO).iso8601(), "PT0S");
+    /// ```
+    pub fn iso8601(&self) -> String {
+        collect(|out| self.write_iso8601(out))
+    }
+
+    fn write_iso8601(&self, w: &mut impl Write) -> fmt::Result {
+        let secs = self.duration.as_secs();
+        let nanos = self.duration.subsec_nanos();
+        let (days, hours, minutes, seconds) = (
+            secs / 86_400,
+            secs % 86_400 / 3_600,
+            secs % 3_600 / 60,
+            secs % 60,
+        );
+
+        if self.negative && !self.duration.is_zero() {
+            w.write_char('-')?;
+        }
+        w.write_char('P')?;
+        if days > 0 {
+            write!(w, "{}D", days)?;
+        }
+        if secs.is_multiple_of(86_400) && nanos == 0 {
+            return if days == 0 {
+                w.write_str("T0S")
+            } else {
+                Ok(())
+            };
+        }
+
+        w.write_char('T')?;
+        if hours > 0 {
+            write!(w, "{}H", hours)?;
+        }
+        if minutes > 0 {
+            write!(w, "{}M", minutes)?;
+        }
+        if seconds > 0 || nanos > 0 {
+            write!(w, "{}", seconds)?;
+            if nanos > 0 {
+                let (mut fraction, mut width) = (nanos, 9);
+                while fraction.is_multiple_of(10) {
+                    fraction /= 10;
+                    width -= 1;
+                }
+                write!(w, ".{:0width$}", fraction, width = width)?;
+            }
+            w.write_char('S')?;
+        }
+        Ok(())
+    }
+
+    /// Reads an ISO 8601 duration such as "P2DT3H4M5S", "PT1.5H" or "P1W"
+    /// back into a `Duration`. Years and months are rejected, having no
+    /// fixed length.
+    ///
+    /// ```rust
+    /// use humanly::{HumanTime, ParseErrorKind};
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(HumanTime::parse_iso8601("PT1H30M"), Ok(Duration::from_secs(5_400)));
+    /// assert_eq!(HumanTime::parse_iso8601("PT0.5S"), Ok(Duration::from_millis(500)));
+    /// assert_eq!(
+    ///     HumanTime::parse_iso8601("P1M").unwrap_err().kind(),
+    ///     ParseErrorKind::UnknownUnit
+    /// );
+    /// ```
+    pub fn parse_iso8601(input: &str) -> Result<Duration, ParseError> {
+        parse_iso8601_duration(input)
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    /// The long form, as `Display` writes it.
+    ///
+    /// ```rust
+    /// use humanly::HumanTime;
+    /// use std::time::Duration;
+    ///
+    /// let time = HumanTime::from(Duration::from_secs(3_672));
+    /// assert_eq!(time.full(), "1 hour 1 minute 12 seconds");
+    /// ```
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    /// One [`Parts`] per component: "1h", " 5s".
+    pub fn into_parts(self, style: Style) -> Vec<Parts> {
+        // Components are found by the spaces between them
+        let spaced = Self {
+            separator: None,
+            final_separator: None,
+            ..self
+        };
+        Parts::split_components(&collect(|out| spaced.write_into(out, style)))
+    }
+
+    /// Longest `concise()` output in bytes, under any locale or [`Config`].
+    pub const MAX_CONCISE_LEN: usize = 40;
+
+    /// Longest `Style::Full` output in bytes, with the default separator.
+    pub const MAX_FULL_LEN: usize = 95;
+
+    /// Writes into a byte buffer without allocating, like
+    /// [`HumanNumber::format_into`].
+    ///
+    /// ```rust
+    /// use humanly::{HumanTime, Style};
+    ///
+    /// let mut buf = [0u8; HumanTime::MAX_CONCISE_LEN];
+    /// let time = HumanTime::from(std::time::Duration::from_secs(5_400));
+    /// let text = time.format_into(&mut buf, Style::Concise).unwrap();
+    /// assert_eq!(text, "1h 30m");
+    /// ```
+    pub fn format_into<'b>(
+        &self,
+        buf: &'b mut [u8],
+        style: Style,
+    ) -> Result<&'b str, CapacityError> {
+        collect_into(buf, |w| self.write_into(w, style))
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_coarse(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        const UNITS: [(TimeUnit, u64); 5] = [
+            (TimeUnit::Week, 604_800),
+            (TimeUnit::Day, 86_400),
+            (TimeUnit::Hour, 3_600),
+            (TimeUnit::Minute, 60),
+            (TimeUnit::Second, 1),
+        ];
+
+        // The largest unit allowed that fits; a second always does
+        let secs = self.duration.as_secs();
+        let max_unit = self.max_unit.unwrap_or(TimeUnit::Day);
+        let idx = UNITS
+            .iter()
+            .position(|&(unit, size)| unit <= max_unit && secs >= size)
+            .unwrap_or(UNITS.len() - 1);
+        let (mut unit, size) = UNITS[idx];
+
+        // Tenths of the unit, rounded exactly from the nanoseconds
+        let tenth_nanos = u128::from(size) * 100_000_000;
+        let nanos = self.duration.as_nanos();
+        let (mut tenths, remainder) = (nanos / tenth_nanos, nanos % tenth_nanos);
+        tenths += match self.rounding {
+            Rounding::Nearest => u128::from(remainder * 2 >= tenth_nanos),
+            Rounding::Down => 0,
+            Rounding::Up => u128::from(remainder > 0),
+        };
+        // 59.96 minutes rounds to an hour, not to 60 minutes
+        if let Some(&(next, next_size)) = idx.checked_sub(1).map(|i| &UNITS[i])
+            && next <= max_unit
+            && tenths * u128::from(size) == u128::from(next_size) * 10
+        {
+            (tenths, unit) = (10, next);
+        }
+
+        if self.negative && tenths > 0 {
+            w.write_char('-')?;
+        }
+        let (whole, tenth) = (tenths / 10, tenths % 10);
+        write!(w, "{}", whole)?;
+        if tenth > 0 {
+            write!(w, "{}{}", decimal_mark(Some(self.locale), style), tenth)?;
+        }
+        match style {
+            Style::Concise => w.write_str(unit.symbol()),
+            Style::Full => {
+                let singular = tenths == 10;
+                write!(w, " {}", self.locale.time_unit(unit, singular))
+            }
+        }
+    }
+
+    fn write_approximate(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        const UNITS: [(TimeUnit, u64); 4] = [
+            (TimeUnit::Week, 604_800),
+            (TimeUnit::Day, 86_400),
+            (TimeUnit::Hour, 3_600),
+            (TimeUnit::Minute, 60),
+        ];
+
+        let secs = self.duration.as_secs();
+        if secs < 60 {
+            return match style {
+                Style::Concise => w.write_str("<1m"),
+                Style::Full => w.write_str(self.locale.less_than_a_minute()),
+            };
+        }
+
+        // The largest unit allowed that fits; a minute always does
+        let max_unit = self.max_unit.unwrap_or(TimeUnit::Day);
+        let idx = UNITS
+            .iter()
+            .position(|&(unit, size)| unit <= max_unit && secs >= size)
+            .unwrap_or(UNITS.len() - 1);
+        let (mut unit, size) = UNITS[idx];
+        let unit_nanos = u128::from(size) * 1_000_000_000;
+        let nanos = self.duration.as_nanos();
+        let (mut count, remainder) = ((nanos / unit_nanos) as u64, nanos % unit_nanos);
+
+        let over =
+            unit >= TimeUnit::Hour && remainder * 4 >= unit_nanos && remainder * 2 < unit_nanos;
+        if remainder * 2 >= unit_nanos {
+            count += 1;
+            // 59.5 minutes is about an hour, not about 60 minutes
+            if let Some(&(next, next_size)) = idx.checked_sub(1).map(|i| &UNITS[i])
+                && next <= max_unit
+                && count * size == next_size
+            {
+                (count, unit) = (1, next);
+            }
+        }
+
+        let negative = self.negative && !self.duration.is_zero();
+        let sign = if negative { "-" } else { "" };
+        match style {
+            Style::Concise => {
+                let mark = if over { '>' } else { '~' };
+                write!(w, "{}{}{}{}", mark, sign, count, unit.symbol())
+            }
+            Style::Full => write!(
+                w,
+                "{}{}{} {}",
+                self.locale.approximately(over),
+                sign,
+                count,
+                self.locale.time_unit(unit, count == 1)
+            ),
+        }
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        const UNITS: [(TimeUnit, u64); 5] = [
+            (TimeUnit::Week, 604_800),
+            (TimeUnit::Day, 86_400),
+            (TimeUnit::Hour, 3_600),
+            (TimeUnit::Minute, 60),
+            (TimeUnit::Second, 1),
+        ];
+        // Nanoseconds in each unit below a second, and its symbol
+        const SUBSECOND: [(u32, &str); 3] = [(1_000_000, "ms"), (1_000, "µs"), (1, "ns")];
+
+        if self.approximate {
+            return self.write_approximate(w, style);
+        }
+        if self.coarse {
+            return self.write_coarse(w, style);
+        }
+
+        let secs = self.duration.as_secs();
+        let max_unit = self.max_unit.unwrap_or(TimeUnit::Day);
+        let mut rest = secs;
+        let [weeks, days, hours, minutes, seconds] = UNITS.map(|(unit, size)| {
+            if unit > max_unit {
+                return 0;
+            }
+            let count = rest / size;
+            rest %= size;
+            count
+        });
+
+        // Under a second, or with `precise`, the largest sub-second unit
+        let nanos = self.duration.subsec_nanos();
+        let subsecond = SUBSECOND
+            .iter()
+            .position(|&(size, _)| nanos >= size)
+            .filter(|_| secs == 0 || self.precise)
+            .map(|idx| (u64::from(nanos / SUBSECOND[idx].0), idx));
+
+        // Each non-zero unit, and the count as its symbol and its name
+        let mut parts: [Option<(u64, &str, &str)>; 6] = [None; 6];
+        let unit = |count: u64, unit: TimeUnit| {
+            Some((
+                count,
+                unit.symbol(),
+                self.locale.time_unit(unit, count == 1),
+            ))
+        };
+        if weeks > 0 {
+            parts[0] = unit(weeks, TimeUnit::Week);
+        }
+        if days > 0 {
+            parts[1] = unit(days, TimeUnit::Day);
+        }
+        if hours > 0 {
+            parts[2] = unit(hours, TimeUnit::Hour);
+        }
+        // Concise keeps a "0m" between hours and seconds ("1h 0m 5s")
+        if minutes > 0 || (hours > 0 && style == Style::Concise) {
+            parts[3] = unit(minutes, TimeUnit::Minute);
+        }
+        if seconds > 0 || (secs == 0 && subsecond.is_none()) {
+            parts[4] = unit(seconds, TimeUnit::Second);
+        }
+        if let Some((count, idx)) = subsecond {
+            let name = self.locale.subsecond_unit(idx, count == 1);
+            parts[5] = Some((count, SUBSECOND[idx].1, name));
+        }
+
+        if self.negative && !self.duration.is_zero() {
+            w.write_char('-')?;
+        }
+        let (separator, final_separator) = match style {
+            Style::Concise => (" ", " "),
+            Style::Full => {
+                let separator = self.separator.unwrap_or(" ");
+                (separator, self.final_separator.unwrap_or(separator))
+            }
+        };
+        let total = parts.iter().flatten().count();
+        for (i, &(count, symbol, name)) in parts.iter().flatten().enumerate() {
+            if i > 0 {
+                w.write_str(if i + 1 == total {
+                    final_separator
+                } else {
+                    separator
+                })?;
+            }
+            match style {
+                Style::Concise => write!(w, "{}{}", count, symbol)?,
+                Style::Full => write!(w, "{} {}", count, name)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for HumanTime {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        parse_duration(input).map(HumanTime::from)
+    }
+}
+
+/* -------------------- HumanPercent -------------------- */
+
+// What a `HumanPercent` counts in: hundredths, thousandths or ten-thousandths.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Scale {
+    Percent,
+    PerMille,
+    BasisPoints,
+}
+
+impl Scale {
+    // Powers of ten from a percentage to this scale
+    fn shift(self) -> i32 {
+        match self {
+            Scale::Percent => 0,
+            Scale::PerMille => 1,
+            Scale::BasisPoints => 2,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HumanPercent {
+    pub(crate) value: f64,
+    exact: Option<Exact>,
+    decimals: usize,
+    locale: Option<Locale>,
+    fallback: &'static str,
+    trim_zeros: bool,
+    clamp: bool,
+    signed: bool,
+    zero_sign: &'static str,
+    scale: Scale,
+    bar_fill: char,
+    bar_empty: char,
+    bare_bar: bool,
+}
+
+impl HumanPercent {
+    pub fn from(value: f64, decimals: usize) -> Self {
+        Self {
+            value,
+            exact: None,
+            // past 10^300 the rounding multiplier overflows
+            decimals: decimals.min(300),
+            locale: None,
+            fallback: "-",
+            trim_zeros: false,
+            clamp: false,
+            signed: false,
+            zero_sign: "",
+            scale: Scale::Percent,
+            bar_fill: '█',
+            bar_empty: '░',
+            bare_bar: false,
+        }
+    }
+
+    /// The percentage `numerator` is of `denominator`, rounded like
+    /// [`from`](HumanPercent::from). A zero denominator has no percentage and
+    /// renders as "-", or as the [`fallback`](HumanPercent::fallback) text.
+    ///
+    /// ```rust
+    /// use humanly::HumanPercent;
+    ///
+    /// assert_eq!(HumanPercent::from_ratio(3.0, 8.0, 1).concise(), "37.5%");
+    /// assert_eq!(HumanPercent::from_ratio(0.0, 0.0, 1).concise(), "-");
+    /// assert_eq!(HumanPercent::from_ratio(0.0, 0.0, 1).fallback("n/a").concise(), "n/a");
+    /// ```
+    pub fn from_ratio(numerator: f64, denominator: f64, decimals: usize) -> Self {
+        // NaN rather than the infinity `3.0 / 0.0` gives, which `clamp`
+        // would show as "100%"
+        let value = if denominator == 0.0 {
+            f64::NAN
+        } else {
+            numerator / denominator * 100.0
+        };
+        Self::from(value, decimals)
+    }
+
+    #[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+    pub(crate) fn from_exact(exact: Exact, decimals: usize) -> Self {
+        Self {
+            exact: Some(exact),
+            ..Self::from(exact.to_f64(), decimals)
+        }
+    }
+
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Text for a value with no percentage, such as a ratio over zero or NaN
+    /// (default "-"), in both styles.
+    pub fn fallback(mut self, text: &'static str) -> Self {
+        self.fallback = text;
+        self
+    }
+
+    /// Drop trailing zeros after rounding, so `from(12.30, 2)` reads "12.3%"
+    /// rather than "12.30%" and `from(100.0, 2)` reads "100%".
+    pub fn trim_zeros(mut self) -> Self {
+        self.trim_zeros = true;
+        self
+    }
+
+    /// Keep the value within 0–100% before rounding, for progress that
+    /// overshoots through float error: 100.4 is "100.0%" and -0.2 is "0.0%".
+    /// Infinities go to the nearer bound; NaN, and a
+    /// [`from_ratio`](HumanPercent::from_ratio) with a zero denominator, still
+    /// render the [`fallback`](HumanPercent::fallback).
+    ///
+    /// ```rust
+    /// use humanly::HumanPercent;
+    ///
+    /// assert_eq!(HumanPercent::from(100.4, 1).clamp().concise(), "100.0%");
+    /// assert_eq!(HumanPercent::from(-0.2, 0).clamp().concise(), "0%");
+    /// ```
+    pub fn clamp(mut self) -> Self {
+        self.clamp = true;
+        self
+    }
+
+    /// Write a "+" before values that round above zero, for changes:
+    /// "+2.5%", "-0.3%". A value that rounds to zero has no sign unless
+    /// [`zero_sign`](HumanPercent::zero_sign) gives one.
+    ///
+    /// ```rust
+    /// use humanly::HumanPercent;
+    ///
+    /// assert_eq!(HumanPercent::from(2.5, 1).signed().concise(), "+2.5%");
+    /// assert_eq!(HumanPercent::from(2.5, 1).signed().full(), "+2.5 percent");
+    /// assert_eq!(HumanPercent::from(-0.04, 1).signed().concise(), "0.0%");
+    /// ```
+    pub fn signed(mut self) -> Self {
+        self.signed = true;
+        self
+    }
+
+    /// Text before a [`signed`](HumanPercent::signed) value that rounds to
+    /// zero, such as "±" (default none).
+    pub fn zero_sign(mut self, sign: &'static str) -> Self {
+        self.zero_sign = sign;
+        self
+    }
+
+    /// Write the percentage in basis points, hundredths of a percent, for
+    /// small rates and spreads: 0.25% is "25 bps", "25 basis points" in full.
+    /// The value is rescaled before rounding to `decimals` places.
+    ///
+    /// ```rust
+    /// use humanly::HumanPercent;
+    ///
+    /// assert_eq!(HumanPercent::from(0.25, 0).basis_points().concise(), "25 bps");
+    /// assert_eq!(HumanPercent::from(0.255, 1).basis_points().full(), "25.5 basis points");
+    /// assert_eq!(HumanPercent::from(0.01, 0).basis_points().full(), "1 basis point");
+    /// ```
+    pub fn basis_points(mut self) -> Self {
+        self.scale = Scale::BasisPoints;
+        self
+    }
+
+    /// Write the percentage per mille, in tenths of a percent: 0.25% is
+    /// "2.5‰", "2.5 per mille" in full. The value is rescaled before rounding
+    /// to `decimals` places.
+    ///
+    /// ```rust
+    /// use humanly::HumanPercent;
+    ///
+    /// assert_eq!(HumanPercent::from(0.25, 1).per_mille().concise(), "2.5‰");
+    /// assert_eq!(HumanPercent::from(12.5, 0).per_mille().full(), "125 per mille");
+    /// ```
+    pub fn per_mille(mut self) -> Self {
+        self.scale = Scale::PerMille;
+        self
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    /// A progress bar `width` characters wide followed by the `concise()`
+    /// percentage: "██████░░░░ 60%". The bar is filled to the nearest whole
+    /// character, with values outside 0–100% drawn as an empty or full bar
+    /// while the percentage still shows the value itself. NaN draws an empty
+    /// bar and the [`fallback`](HumanPercent::fallback).
+    ///
+    /// ```rust
+    /// use humanly::HumanPercent;
+    ///
+    /// assert_eq!(HumanPercent::from(60.0, 0).bar(10), "██████░░░░ 60%");
+    /// assert_eq!(HumanPercent::from(125.0, 0).bar(4), "████ 125%");
+    /// assert_eq!(HumanPercent::from(25.0, 0).bar_chars('#', '-').bare_bar().bar(8), "##------");
+    /// ```
+    pub fn bar(&self, width: usize) -> String {
+        let share = if self.value.is_nan() {
+            0.0
+        } else {
+            self.value.clamp(0.0, 100.0) / 100.0
+        };
+        let filled = ((share * width as f64).round() as usize).min(width);
+
+        let mut bar = String::with_capacity(width * 3 + 8);
+        bar.extend(std::iter::repeat_n(self.bar_fill, filled));
+        bar.extend(std::iter::repeat_n(self.bar_empty, width - filled));
+        if !self.bare_bar {
+            bar.push(' ');
+            self.write_concise(&mut bar)
+                .expect("writing to a String cannot fail");
+        }
+        bar
+    }
+
+    /// The characters [`bar`](HumanPercent::bar) draws its filled and empty
+    /// cells with (default '█' and '░'). The bar counts one column per
+    /// character, so both should be one column wide: ASCII and the block
+    /// characters are, except in terminals set to draw East Asian ambiguous
+    /// width characters wide, while emoji and CJK characters take two.
+    pub fn bar_chars(mut self, fill: char, empty: char) -> Self {
+        self.bar_fill = fill;
+        self.bar_empty = empty;
+        self
+    }
+
+    /// Leave the percentage off [`bar`](HumanPercent::bar) output, for layouts
+    /// that place it themselves.
+    pub fn bare_bar(mut self) -> Self {
+        self.bare_bar = true;
+        self
+    }
+
+    /// The long form, as `Display` writes it.
+    ///
+    /// ```rust
+    /// use humanly::HumanPercent;
+    ///
+    /// assert_eq!(HumanPercent::from(12.34, 1).full(), "12.3 percent");
+    /// ```
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    /// The formatted value split into sign, number and unit; see [`Parts`].
+    pub fn into_parts(self, style: Style) -> Parts {
+        Parts::split(&collect(|out| self.write_into(out, style)), &[])
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    // The sign of signed output; a minus comes with the number itself
+    fn write_sign(&self, w: &mut impl Write, rounded: Ordering) -> fmt::Result {
+        match rounded {
+            _ if !self.signed => Ok(()),
+            Ordering::Greater => w.write_char('+'),
+            Ordering::Equal => w.write_str(self.zero_sign),
+            Ordering::Less => Ok(()),
+        }
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        let mark = decimal_mark(self.locale, style);
+        let value = if self.clamp {
+            self.value.clamp(0.0, 100.0)
+        } else {
+            self.value
+        };
+        let shift = self.scale.shift();
+        // an exact value out of range was clamped to a float bound
+        let exact = self
+            .exact
+            .filter(|_| value == self.value)
+            .and_then(|exact| {
+                let exponent = exact.exponent.checked_add(shift)?;
+                Exact { exponent, ..exact }.round_to(-(self.decimals as i32))
+            });
+        let one = if let Some(rounded) = exact {
+            self.write_sign(w, rounded.cmp(&0))?;
+            write_scaled(w, rounded, self.decimals as u32, mark, !self.trim_zeros)?;
+            10_u128
+                .checked_pow(self.decimals as u32)
+                .is_some_and(|one| rounded.unsigned_abs() == one)
+        } else {
+            let value = value * 10_f64.powi(shift);
+            let multiplier = 10_f64.powi(self.decimals as i32);
+            // a value too large to scale has no fraction left to round
+            let rounded = match value * multiplier {
+                scaled if scaled.is_finite() => scaled.round() / multiplier,
+                _ => value,
+            };
+
+            if !rounded.is_finite() {
+                return w.write_str(self.fallback);
+            }
+            // no "-0.0%" for a small negative
+            let rounded = if rounded == 0.0 { 0.0 } else { rounded };
+            self.write_sign(w, rounded.total_cmp(&0.0))?;
+            if self.trim_zeros {
+                write_localized(w, mark, format_args!("{}", rounded))?;
+            } else {
+                write_localized(w, mark, format_args!("{:.*}", self.decimals, rounded))?;
+            }
+            rounded.abs() == 1.0
+        };
+        // "1 basis point" but "1.0 basis points"
+        let singular = one && (self.trim_zeros || self.decimals == 0);
+        let locale = self.locale.unwrap_or_default();
+        match (style, self.scale) {
+            (Style::Concise, Scale::Percent) => w.write_char('%'),
+            (Style::Concise, Scale::PerMille) => w.write_char('‰'),
+            (Style::Concise, Scale::BasisPoints) => w.write_str(" bps"),
+            (Style::Full, Scale::Percent) => write!(w, " {}", locale.percent_word()),
+            (Style::Full, Scale::PerMille) => write!(w, " {}", locale.per_mille_word()),
+            (Style::Full, Scale::BasisPoints) => {
+                write!(w, " {}", locale.basis_point_word(singular))
+            }
         }
     }
 }
@@ -0,0 +1,177 @@
+use std::borrow::Cow;
+use std::fmt::{self, Write};
+
+use crate::core::{HumanFormat, Style, human_display, typeset, write_grouped};
+
+/* -------------------- HumanCount -------------------- */
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Count {
+    Integer { negative: bool, magnitude: u128 },
+    Float(f64),
+}
+
+/// An exact count with its digits grouped in threes: "1,234,567".
+///
+/// Integers of any width keep every digit. Floats keep their fractional part
+/// as written, ungrouped ("1,234.5678"). Non-finite values render as "-".
+/// Concise and full output are the same, except that full output adds the
+/// [`unit`](HumanCount::unit) when one is set: "1,234,567 downloads".
+///
+/// The separator and decimal mark default to "," and "." and can be changed
+/// for other conventions:
+///
+/// ```rust
+/// use humanly::HumanCount;
+///
+/// let count = HumanCount::from(1_234_567.89).separator('.').decimal_mark(',');
+/// assert_eq!(count.concise(), "1.234.567,89");
+/// assert_eq!(HumanCount::from(1_234_567).separator('\'').concise(), "1'234'567");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct HumanCount {
+    pub(crate) count: Count,
+    unit: Option<Cow<'static, str>>,
+    separator: char,
+    decimal_mark: char,
+}
+
+human_display!(HumanCount);
+
+macro_rules! count_from {
+    (signed: $($s:ty),*; unsigned: $($u:ty),*; float: $($f:ty),*) => {
+        $(
+            impl From<$s> for HumanCount {
+                fn from(count: $s) -> Self {
+                    Self::with(Count::Integer {
+                        negative: count < 0,
+                        magnitude: count.unsigned_abs() as u128,
+                    })
+                }
+            }
+        )*
+        $(
+            impl From<$u> for HumanCount {
+                fn from(count: $u) -> Self {
+                    Self::with(Count::Integer {
+                        negative: false,
+                        magnitude: count as u128,
+                    })
+                }
+            }
+        )*
+        $(
+            impl From<$f> for HumanCount {
+                fn from(count: $f) -> Self {
+                    Self::with(Count::Float(count.into()))
+                }
+            }
+        )*
+    };
+}
+
+count_from!(
+    signed: i8, i16, i32, i64, i128, isize;
+    unsigned: u8, u16, u32, u64, u128, usize;
+    float: f32, f64
+);
+
+impl HumanCount {
+    pub(crate) fn with(count: Count) -> Self {
+        Self {
+            count,
+            unit: None,
+            separator: ',',
+            decimal_mark: '.',
+        }
+    }
+
+    /// The character between groups of three digits, "," by default. Use
+    /// `'\u{2009}'` for thin spaces. Taking the decimal mark's character swaps
+    /// the two, so `.separator('.')` alone gives "1.234,5".
+    pub fn separator(mut self, separator: char) -> Self {
+        if separator == self.decimal_mark {
+            self.decimal_mark = self.separator;
+        }
+        self.separator = separator;
+        self
+    }
+
+    /// The character before the fractional digits, "." by default. Taking the
+    /// separator's character swaps the two, as with
+    /// [`separator`](HumanCount::separator).
+    pub fn decimal_mark(mut self, mark: char) -> Self {
+        if mark == self.separator {
+            self.separator = self.decimal_mark;
+        }
+        self.decimal_mark = mark;
+        self
+    }
+
+    /// A word written after the count in full output only.
+    pub fn unit(mut self, unit: impl Into<Cow<'static, str>>) -> Self {
+        self.unit = Some(unit.into());
+        self
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        match self.count {
+            Count::Integer {
+                negative,
+                magnitude,
+            } => {
+                if negative {
+                    w.write_char('-')?;
+                }
+                write_grouped(w, &magnitude.to_string(), self.separator)?;
+            }
+            Count::Float(count) if !count.is_finite() => return w.write_char('-'),
+            Count::Float(count) => {
+                // The sign goes first so it is never grouped with the digits,
+                // and -0.0 is written as "0"
+                if count < 0.0 {
+                    w.write_char('-')?;
+                }
+                let digits = count.abs().to_string();
+                write_grouped_decimal(w, &digits, self.separator, self.decimal_mark)?;
+            }
+        }
+
+        match (&self.unit, style) {
+            (Some(unit), Style::Full) => write!(w, " {}", unit),
+            _ => Ok(()),
+        }
+    }
+}
+
+// Writes unsigned decimal digits such as "1234.5678" with the integer part
+// grouped and the fraction after `mark` as it is: "1,234.5678".
+pub(crate) fn write_grouped_decimal(
+    w: &mut impl Write,
+    digits: &str,
+    separator: char,
+    mark: char,
+) -> fmt::Result {
+    let (integer, fraction) = match digits.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (digits, None),
+    };
+    write_grouped(w, integer, separator)?;
+    if let Some(fraction) = fraction {
+        w.write_char(mark)?;
+        w.write_str(fraction)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,208 @@
+use std::fmt::{self, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::core::{HumanFormat, Style, collect, human_display, typeset};
+
+/* -------------------- Civil dates -------------------- */
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// A proleptic Gregorian date and wall-clock time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct CivilDateTime {
+    pub(crate) year: i64,
+    pub(crate) month: u32,   // 1..=12
+    pub(crate) day: u32,     // 1..=31
+    pub(crate) weekday: u32, // 0 = Sunday
+    pub(crate) days: i64,    // since 1970-01-01
+    pub(crate) hour: u32,
+    pub(crate) minute: u32,
+    pub(crate) second: u32,
+}
+
+impl CivilDateTime {
+    /// Converts `time` to the calendar at `offset_minutes` east of UTC.
+    pub(crate) fn from_system_time(time: SystemTime, offset_minutes: i32) -> Self {
+        let secs = match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => after.as_secs() as i64,
+            // Round towards the past so 0.5 s before the epoch is still 1969
+            Err(err) => -(err.duration().as_secs_f64().ceil() as i64),
+        };
+        let local = secs + offset_minutes as i64 * 60;
+        let days = local.div_euclid(86_400);
+        let seconds_of_day = local.rem_euclid(86_400) as u32;
+        let (year, month, day) = civil_from_days(days);
+
+        Self {
+            days,
+            year,
+            month,
+            day,
+            weekday: (days + 4).rem_euclid(7) as u32, // 1970-01-01 was a Thursday
+            hour: seconds_of_day / 3600,
+            minute: seconds_of_day % 3600 / 60,
+            second: seconds_of_day % 60,
+        }
+    }
+
+    pub(crate) fn month_name(&self) -> &'static str {
+        MONTHS[self.month as usize - 1]
+    }
+
+    pub(crate) fn weekday_name(&self) -> &'static str {
+        WEEKDAYS[self.weekday as usize]
+    }
+}
+
+// Days since 1970-01-01 to (year, month, day), after Howard Hinnant's
+// `civil_from_days`: shift to eras of 400 years starting on 1 March so leap
+// days fall at the end of each year.
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/* -------------------- HumanDate -------------------- */
+
+/// Named layouts for [`HumanDate::format`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateStyle {
+    /// "2024-03-12"
+    Short,
+    /// "12 Mar 2024"
+    Medium,
+    /// "March 12, 2024"
+    Long,
+    /// "Tue, 12 Mar 2024 14:05", in the spirit of RFC 2822
+    Rfc,
+}
+
+/// Absolute dates: "12 Mar 2024" concise, "March 12, 2024" full.
+///
+/// Dates are computed in UTC unless a [`utc_offset`](HumanDate::utc_offset) is
+/// given. Any [`DateStyle`] can be rendered with [`format`](HumanDate::format).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HumanDate {
+    pub(crate) system_time: SystemTime,
+    offset_minutes: i32,
+    omit_current_year: bool,
+}
+
+human_display!(HumanDate);
+
+impl HumanDate {
+    pub fn from(system_time: SystemTime) -> Self {
+        Self {
+            system_time,
+            offset_minutes: 0,
+            omit_current_year: false,
+        }
+    }
+
+    /// Offset from UTC in minutes, e.g. `-300` for US Eastern Standard Time.
+    pub fn utc_offset(mut self, minutes: i32) -> Self {
+        self.offset_minutes = minutes;
+        self
+    }
+
+    /// Leave out the year in the medium and long styles when it is the current
+    /// year: "12 Mar", "March 12".
+    pub fn omit_current_year(mut self) -> Self {
+        self.omit_current_year = true;
+        self
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    pub fn format(&self, style: DateStyle) -> String {
+        collect(|out| self.write_format(out, style))
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        match style {
+            Style::Concise => self.write_layout(w, DateStyle::Medium),
+            Style::Full => self.write_layout(w, DateStyle::Long),
+        }
+    }
+
+    pub fn write_format(&self, w: &mut impl Write, style: DateStyle) -> fmt::Result {
+        typeset(w, |w| self.write_layout(w, style))
+    }
+
+    fn write_layout(&self, w: &mut impl Write, style: DateStyle) -> fmt::Result {
+        let date = CivilDateTime::from_system_time(self.system_time, self.offset_minutes);
+        let show_year = !self.omit_current_year
+            || CivilDateTime::from_system_time(SystemTime::now(), self.offset_minutes).year
+                != date.year;
+
+        match style {
+            DateStyle::Short => write!(w, "{:04}-{:02}-{:02}", date.year, date.month, date.day),
+            DateStyle::Medium => {
+                write!(w, "{} {}", date.day, &date.month_name()[..3])?;
+                if show_year {
+                    write!(w, " {}", date.year)?;
+                }
+                Ok(())
+            }
+            DateStyle::Long => {
+                write!(w, "{} {}", date.month_name(), date.day)?;
+                if show_year {
+                    write!(w, ", {}", date.year)?;
+                }
+                Ok(())
+            }
+            DateStyle::Rfc => write!(
+                w,
+                "{}, {:02} {} {} {:02}:{:02}",
+                &date.weekday_name()[..3],
+                date.day,
+                &date.month_name()[..3],
+                date.year,
+                date.hour,
+                date.minute
+            ),
+        }
+    }
+}
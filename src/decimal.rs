@@ -0,0 +1,95 @@
+use std::fmt::{self, Write};
+
+/* -------------------- Exact decimals -------------------- */
+
+// `mantissa × 10^exponent`, taken from an arbitrary-precision decimal so the
+// displayed digits are rounded without going through `f64`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Exact {
+    pub(crate) mantissa: i128,
+    pub(crate) exponent: i32,
+}
+
+impl Exact {
+    // The nearest `f64`, for everything that still works on floats.
+    #[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+    pub(crate) fn to_f64(self) -> f64 {
+        format!("{}e{}", self.mantissa, self.exponent)
+            .parse()
+            .unwrap_or(f64::NAN)
+    }
+
+    // The value in units of 10^`exponent`, rounded half away from zero, or
+    // `None` if that overflows.
+    pub(crate) fn round_to(self, exponent: i32) -> Option<i128> {
+        let shift = self.exponent.checked_sub(exponent)?;
+        if shift >= 0 {
+            return self
+                .mantissa
+                .checked_mul(10_i128.checked_pow(shift as u32)?);
+        }
+        // |mantissa| < 1.7e38, so anything finer rounds to zero
+        let Some(unit) = 10_i128.checked_pow(shift.unsigned_abs()) else {
+            return Some(0);
+        };
+        let (quotient, remainder) = (self.mantissa / unit, self.mantissa % unit);
+        if remainder.unsigned_abs() * 2 >= unit.unsigned_abs() {
+            Some(quotient + self.mantissa.signum())
+        } else {
+            Some(quotient)
+        }
+    }
+
+    // Like `core::magnitude` for a decimal ladder: the index of the power of
+    // 1000 to show the value in (up to `max_idx`) and the value in it, in
+    // units of 10^-`decimals`.
+    pub(crate) fn magnitude(self, max_idx: usize, decimals: usize) -> Option<(usize, i128)> {
+        let digits = self
+            .mantissa
+            .unsigned_abs()
+            .checked_ilog10()
+            .map_or(0, |d| d + 1);
+        let integer_digits = i64::from(digits) + i64::from(self.exponent);
+        let idx = ((integer_digits - 1).max(0) / 3).min(max_idx as i64) as usize;
+
+        let decimals = i32::try_from(decimals).ok()?;
+        let next_unit = 10_i128.checked_pow(decimals.checked_add(3)? as u32)?;
+        let scaled = self.round_to(3 * idx as i32 - decimals)?;
+        if idx < max_idx && scaled.unsigned_abs() >= next_unit.unsigned_abs() {
+            Some((idx + 1, self.round_to(3 * (idx as i32 + 1) - decimals)?))
+        } else {
+            Some((idx, scaled))
+        }
+    }
+}
+
+// Writes `value × 10^-decimals` without trailing zeros, "12.5", "-3", "0", or
+// with all of them when `keep_zeros` is set: "12.50", "-3.00".
+pub(crate) fn write_scaled(
+    w: &mut impl Write,
+    value: i128,
+    decimals: u32,
+    mark: char,
+    keep_zeros: bool,
+) -> fmt::Result {
+    let unit = 10_u128.pow(decimals);
+    let (integer, fraction) = (value.unsigned_abs() / unit, value.unsigned_abs() % unit);
+    if value < 0 {
+        w.write_char('-')?;
+    }
+    write!(w, "{}", integer)?;
+    if keep_zeros && decimals > 0 {
+        w.write_char(mark)?;
+        write!(w, "{:0width$}", fraction, width = decimals as usize)?;
+    } else if fraction != 0 {
+        // drop the trailing zeros from the digits and the width together
+        let (mut fraction, mut width) = (fraction, decimals as usize);
+        while fraction % 10 == 0 {
+            fraction /= 10;
+            width -= 1;
+        }
+        w.write_char(mark)?;
+        write!(w, "{:0width$}", fraction, width = width)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,32 @@
+//! [`defmt::Format`](https://docs.rs/defmt) implementations, enabled with the
+//! `defmt` feature, so logs show "1.4 MiB" rather than a raw byte count:
+//!
+//! ```rust,ignore
+//! defmt::info!("flashed {}", HumanSize::from(written));
+//! ```
+//!
+//! The concise form is formatted into a stack buffer of the type's
+//! `MAX_CONCISE_LEN`, so nothing is allocated.
+
+use ::defmt::{Format, Formatter, write};
+
+use crate::core::Style;
+use crate::{HumanNumber, HumanSize, HumanTime};
+
+macro_rules! defmt_concise {
+    ($t:ty) => {
+        impl Format for $t {
+            fn format(&self, f: Formatter<'_>) {
+                let mut buf = [0u8; <$t>::MAX_CONCISE_LEN];
+                match self.format_into(&mut buf, Style::Concise) {
+                    Ok(text) => write!(f, "{=str}", text),
+                    Err(_) => write!(f, "-"),
+                }
+            }
+        }
+    };
+}
+
+defmt_concise!(HumanNumber);
+defmt_concise!(HumanSize);
+defmt_concise!(HumanTime);
@@ -0,0 +1,198 @@
+use std::fmt;
+use std::time::{Duration, SystemTime};
+
+use crate::error::{ParseError, ParseErrorKind};
+use crate::parse::{offset, parse_duration, parse_human_number, parse_percent, parse_size};
+use crate::{HumanDuration, HumanNumber, HumanPercent, HumanSize, HumanTime};
+
+/* -------------------- dehumanize -------------------- */
+
+/// A value read back by [`dehumanize`], tagged with what kind of quantity the
+/// text described.
+///
+/// Its `Display` is the full form of the matching type ("5 bytes", "5
+/// billion"), which reads well when offering the candidates of an ambiguous
+/// input back to a user.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Dehumanized {
+    Bytes(u64),
+    Number(f64),
+    Percent(f64),
+    Duration(Duration),
+    Timestamp(SystemTime),
+}
+
+// The parsers never produce NaN, so equality is total for every value
+// `dehumanize` returns. Needed for `ParseError`, which is `Eq`.
+impl Eq for Dehumanized {}
+
+impl fmt::Display for Dehumanized {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Dehumanized::Bytes(bytes) => HumanSize::from(bytes).fmt(f),
+            Dehumanized::Number(number) => HumanNumber::from(number).fmt(f),
+            Dehumanized::Percent(value) => HumanPercent::from(value, 1).trim_zeros().fmt(f),
+            Dehumanized::Duration(duration) => HumanTime::from(duration).fmt(f),
+            Dehumanized::Timestamp(time) => HumanDuration::from(Some(time)).fmt(f),
+        }
+    }
+}
+
+/// Reads a humanized string back without being told what it is: "1.5 GiB",
+/// "2 hours ago", "45%", "3.4M", "1,234,567".
+///
+/// The first rule that applies decides:
+///
+/// 1. A trailing `%` is a [`Percent`](Dehumanized::Percent).
+/// 2. "now", "just now", "… ago", "in …" and "… from now" are a
+///    [`Timestamp`](Dehumanized::Timestamp) relative to the current time.
+/// 3. A number with no unit is a [`Number`](Dehumanized::Number); commas
+///    between groups of three digits are thousands separators.
+/// 4. Otherwise the unit decides. Size units (kB, MiB, GB…) are
+///    [`Bytes`](Dehumanized::Bytes), time units (s, min, h, d, and "1h 30m")
+///    a [`Duration`](Dehumanized::Duration). The single letters shared by more
+///    than one parser are settled as `HumanNumber` and `HumanTime` write
+///    them: a lowercase "m" is minutes, so "5m" is a duration, while "k", "M",
+///    "T" and "Q" are number suffixes, so "3.4M" is a number.
+///
+/// A bare "B" or "b" could be bytes or billions, so "5B" fails with
+/// [`ParseErrorKind::Ambiguous`] and [`ParseError::candidates`] lists both
+/// readings.
+///
+/// ```rust
+/// use humanly::{Dehumanized, dehumanize};
+/// use std::time::Duration;
+///
+/// assert_eq!(dehumanize("1.5 KiB"), Ok(Dehumanized::Bytes(1_536)));
+/// assert_eq!(dehumanize("5m"), Ok(Dehumanized::Duration(Duration::from_secs(300))));
+/// assert_eq!(dehumanize("3.4M"), Ok(Dehumanized::Number(3_400_000.0)));
+///
+/// let err = dehumanize("5B").unwrap_err();
+/// assert_eq!(err.candidates(), [Dehumanized::Bytes(5), Dehumanized::Number(5e9)]);
+/// ```
+pub fn dehumanize(input: &str) -> Result<Dehumanized, ParseError> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(ParseError::new(ParseErrorKind::Empty, 0, input));
+    }
+
+    if trimmed.ends_with('%') {
+        return parse_percent(input).map(Dehumanized::Percent);
+    }
+    if let Some(timestamp) = parse_relative(input, trimmed) {
+        return timestamp.map(Dehumanized::Timestamp);
+    }
+    if let Some(number) = parse_plain_number(trimmed) {
+        return Ok(Dehumanized::Number(number));
+    }
+
+    let unit = trimmed.trim_start_matches(|c: char| !c.is_ascii_alphabetic());
+    let preferred = match unit {
+        "m" => Some(ParsedAs::Duration),
+        "k" | "K" | "M" | "t" | "T" | "q" | "Q" | "qi" | "Qi" => Some(ParsedAs::Number),
+        _ => None,
+    };
+
+    let mut candidates = Vec::new();
+    let mut errors = Vec::new();
+    for kind in [ParsedAs::Bytes, ParsedAs::Number, ParsedAs::Duration] {
+        let parsed = match kind {
+            ParsedAs::Bytes => parse_size(input).map(Dehumanized::Bytes),
+            ParsedAs::Number => parse_human_number(input).map(Dehumanized::Number),
+            ParsedAs::Duration => parse_duration(input).map(Dehumanized::Duration),
+        };
+        match parsed {
+            Ok(value) => candidates.push((kind, value)),
+            Err(err) => errors.push(err),
+        }
+    }
+    if candidates.len() > 1
+        && let Some(preferred) = preferred
+    {
+        candidates.retain(|&(kind, _)| kind == preferred);
+    }
+
+    match candidates.as_slice() {
+        [(_, value)] => Ok(*value),
+        // The parser that read furthest before failing explains it best; on a
+        // tie, the first one tried.
+        [] => Err(errors
+            .into_iter()
+            .rev()
+            .max_by_key(|err| err.position())
+            .expect("every parser was tried")),
+        _ => Err(
+            ParseError::new(ParseErrorKind::Ambiguous, offset(input, trimmed), trimmed)
+                .with_candidates(candidates.into_iter().map(|(_, value)| value).collect()),
+        ),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ParsedAs {
+    Bytes,
+    Number,
+    Duration,
+}
+
+// "2 hours ago", "in 3 days", "3 days from now", "just now". `None` when the
+// text isn't relative at all.
+fn parse_relative(input: &str, trimmed: &str) -> Option<Result<SystemTime, ParseError>> {
+    let lower = trimmed.to_ascii_lowercase();
+    if lower == "now" || lower == "just now" {
+        return Some(Ok(SystemTime::now()));
+    }
+
+    let (past, duration) = if lower.ends_with(" ago") {
+        (true, &trimmed[..trimmed.len() - " ago".len()])
+    } else if lower.ends_with(" from now") {
+        (false, &trimmed[..trimmed.len() - " from now".len()])
+    } else if lower.starts_with("in ") {
+        (false, &trimmed["in ".len()..])
+    } else {
+        return None;
+    };
+
+    let result = parse_duration(duration)
+        .map_err(|err| err.shifted(offset(input, duration)))
+        .and_then(|duration| {
+            let now = SystemTime::now();
+            let time = if past {
+                now.checked_sub(duration)
+            } else {
+                now.checked_add(duration)
+            };
+            time.ok_or_else(|| {
+                ParseError::new(ParseErrorKind::OutOfRange, offset(input, trimmed), trimmed)
+            })
+        });
+    Some(result)
+}
+
+// "42", "-1.5", "1,234,567": a number with no unit, grouped or not.
+fn parse_plain_number(trimmed: &str) -> Option<f64> {
+    let (negative, unsigned) = match trimmed.strip_prefix(['-', '\u{2212}']) {
+        Some(unsigned) => (true, unsigned),
+        None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+    };
+    let (whole, fraction) = match unsigned.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (unsigned, None),
+    };
+
+    let digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    let mut groups = whole.split(',');
+    let first = groups.next()?;
+    if !digits(first) || (whole.contains(',') && first.len() > 3) {
+        return None;
+    }
+    if !groups.all(|group| group.len() == 3 && digits(group)) {
+        return None;
+    }
+    if fraction.is_some_and(|fraction| !digits(fraction)) {
+        return None;
+    }
+
+    let number: f64 = unsigned.replace(',', "").parse().ok()?;
+    Some(if negative { -number } else { number })
+}
@@ -0,0 +1,123 @@
+use std::fmt::{self, Write};
+
+use crate::core::{HumanFormat, Style, human_display, typeset};
+use crate::{HumanNumber, HumanPercent, HumanSize};
+
+/* -------------------- HumanDelta -------------------- */
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Change {
+    Number(f64, f64),
+    Size(u64, u64),
+}
+
+/// The change between two values, absolute and relative:
+/// "+1.2 MiB (+3.4%)", "−15k (−8%)".
+///
+/// The percentage is left out when the old value is zero. An unchanged value
+/// reads "±0 (0%)". Negative changes use the Unicode minus sign unless
+/// [`ascii`](HumanDelta::ascii) is set.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HumanDelta {
+    change: Change,
+    show_absolute: bool,
+    show_relative: bool,
+    ascii: bool,
+}
+
+human_display!(HumanDelta);
+
+impl HumanDelta {
+    fn new(change: Change) -> Self {
+        Self {
+            change,
+            show_absolute: true,
+            show_relative: true,
+            ascii: false,
+        }
+    }
+
+    pub fn numbers(old: impl Into<f64>, new: impl Into<f64>) -> Self {
+        Self::new(Change::Number(old.into(), new.into()))
+    }
+
+    pub fn sizes(old: u64, new: u64) -> Self {
+        Self::new(Change::Size(old, new))
+    }
+
+    /// Only the absolute change: "+1.2 MiB".
+    pub fn absolute_only(mut self) -> Self {
+        self.show_absolute = true;
+        self.show_relative = false;
+        self
+    }
+
+    /// Only the percentage: "+3.4%".
+    pub fn relative_only(mut self) -> Self {
+        self.show_absolute = false;
+        self.show_relative = true;
+        self
+    }
+
+    /// Use "-" instead of "−" for decreases.
+    pub fn ascii(mut self) -> Self {
+        self.ascii = true;
+        self
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    fn write_sign(&self, w: &mut impl Write, diff: f64) -> fmt::Result {
+        if diff > 0.0 {
+            w.write_char('+')
+        } else if diff < 0.0 {
+            w.write_str(if self.ascii { "-" } else { "−" })
+        } else {
+            w.write_char('±')
+        }
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        let (old, diff) = match self.change {
+            Change::Number(old, new) => (old, new - old),
+            Change::Size(old, new) => (old as f64, new as f64 - old as f64),
+        };
+
+        if self.show_absolute {
+            self.write_sign(w, diff)?;
+            match self.change {
+                Change::Number(..) => HumanNumber::from(diff.abs()).write_into(w, style)?,
+                Change::Size(old, new) => {
+                    HumanSize::from(old.abs_diff(new)).write_into(w, style)?
+                }
+            }
+        }
+
+        if self.show_relative && old != 0.0 {
+            let percent = diff / old.abs() * 100.0;
+            if self.show_absolute {
+                w.write_str(" (")?;
+            }
+            if diff != 0.0 {
+                self.write_sign(w, diff)?;
+            }
+            HumanPercent::from(percent.abs(), 1)
+                .trim_zeros()
+                .write_into(w, style)?;
+            if self.show_absolute {
+                w.write_char(')')?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use std::fmt::{self, Write};
+
+use crate::config::decimal_mark;
+use crate::core::{HumanFormat, Style, Unit, human_display, pick_unit, typeset, write_trimmed};
+
+/* -------------------- HumanDistance -------------------- */
+
+const METRIC: [Unit; 4] = [
+    Unit::new(0.001, 0.0, "mm", "millimeter", "millimeters"),
+    Unit::new(0.01, 1.0, "cm", "centimeter", "centimeters"),
+    Unit::new(1.0, 1.0, "m", "meter", "meters"),
+    Unit::new(1_000.0, 1.0, "km", "kilometer", "kilometers"),
+];
+
+const METRIC_BRITISH: [Unit; 4] = [
+    Unit::new(0.001, 0.0, "mm", "millimetre", "millimetres"),
+    Unit::new(0.01, 1.0, "cm", "centimetre", "centimetres"),
+    Unit::new(1.0, 1.0, "m", "metre", "metres"),
+    Unit::new(1_000.0, 1.0, "km", "kilometre", "kilometres"),
+];
+
+// Miles from a tenth of a mile, so 1.2 km reads "0.75 mi" rather than yards.
+const IMPERIAL: [Unit; 3] = [
+    Unit::new(0.3048, 0.0, "ft", "foot", "feet"),
+    Unit::new(0.9144, 1.0, "yd", "yard", "yards"),
+    Unit::new(1_609.344, 0.1, "mi", "mile", "miles"),
+];
+
+/// Distances: "1.2 km" concise, "1.2 kilometers" full.
+///
+/// Metric output moves between mm, cm, m and km; with
+/// [`imperial`](HumanDistance::imperial) it uses feet, yards and miles
+/// instead. Negative and non-finite distances render as "-".
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HumanDistance {
+    pub(crate) meters: f64,
+    imperial: bool,
+    british_spelling: bool,
+    precision: usize,
+}
+
+human_display!(HumanDistance);
+
+impl HumanDistance {
+    pub fn from_meters(meters: f64) -> Self {
+        Self {
+            meters,
+            imperial: false,
+            british_spelling: false,
+            precision: 1,
+        }
+    }
+
+    /// Feet, yards and miles instead of metric units.
+    pub fn imperial(mut self) -> Self {
+        self.imperial = true;
+        self
+    }
+
+    /// Metric units (the default).
+    pub fn metric(mut self) -> Self {
+        self.imperial = false;
+        self
+    }
+
+    /// "kilometres" instead of "kilometers" in full output.
+    pub fn british_spelling(mut self) -> Self {
+        self.british_spelling = true;
+        self
+    }
+
+    /// Maximum number of decimal places (default 1); trailing zeros are trimmed.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision.min(300);
+        self
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        if !self.meters.is_finite() || self.meters < 0.0 {
+            return w.write_str("-");
+        }
+
+        let units: &[Unit] = match (self.imperial, self.british_spelling) {
+            (true, _) => &IMPERIAL,
+            (false, false) => &METRIC,
+            (false, true) => &METRIC_BRITISH,
+        };
+        let base = if self.imperial { 0 } else { 2 };
+        let (rounded, unit) = pick_unit(self.meters, units, base, self.precision);
+        write_trimmed(w, rounded, self.precision, decimal_mark(None, style))?;
+
+        match style {
+            Style::Concise => write!(w, " {}", unit.symbol),
+            Style::Full if rounded == 1.0 => write!(w, " {}", unit.singular),
+            Style::Full => write!(w, " {}", unit.plural),
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use crate::{Dehumanized, HumanList};
+
+/* -------------------- ParseError -------------------- */
+
+/// What went wrong while parsing, see [`ParseError::kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input was empty or only whitespace.
+    Empty,
+    /// A number could not be read, e.g. "1.2.3" or a unit with no number.
+    InvalidNumber,
+    /// The unit suffix is not one the parser knows.
+    UnknownUnit,
+    /// The value is negative or too large for the target type.
+    OutOfRange,
+    /// Something follows an otherwise complete value, e.g. "5 MiB later".
+    TrailingInput,
+    /// The input reads as more than one kind of value, e.g. "5B" as bytes or
+    /// billions; see [`ParseError::candidates`].
+    Ambiguous,
+    /// A unit appears twice in a duration, e.g. "1h 2h".
+    DuplicateUnit,
+    /// A symbolic mode has the wrong length or a letter out of place, e.g.
+    /// "rwxr-x" or "rwxr-xr-q".
+    InvalidMode,
+}
+
+/// Error returned by every parser in this crate.
+///
+/// Besides the [`kind`](ParseError::kind), it records the byte offset of the
+/// problem within the original input and the offending substring, so callers
+/// can point at it:
+///
+/// ```text
+/// unknown unit 'MiBs' at position 3
+/// ```
+///
+/// It implements [`std::error::Error`], so it works with `?` in functions
+/// returning `Box<dyn Error>` or `anyhow::Result`, and converts into an
+/// [`io::Error`] of kind `InvalidInput`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    position: usize,
+    fragment: String,
+    expected: Option<&'static str>,
+    candidates: Vec<Dehumanized>,
+}
+
+impl ParseError {
+    pub(crate) fn new(kind: ParseErrorKind, position: usize, fragment: &str) -> Self {
+        Self {
+            kind,
+            position,
+            fragment: fragment.to_string(),
+            expected: None,
+            candidates: Vec::new(),
+        }
+    }
+
+    // Lists the accepted alternatives after the message.
+    pub(crate) fn expected(mut self, expected: &'static str) -> Self {
+        self.expected = Some(expected);
+        self
+    }
+
+    // Records the readings of an ambiguous input.
+    pub(crate) fn with_candidates(mut self, candidates: Vec<Dehumanized>) -> Self {
+        self.candidates = candidates;
+        self
+    }
+
+    // Moves the position along, for errors from parsing part of the input.
+    pub(crate) fn shifted(mut self, by: usize) -> Self {
+        self.position += by;
+        self
+    }
+
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Byte offset of the problem within the input that was parsed.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The part of the input the error refers to.
+    pub fn fragment(&self) -> &str {
+        &self.fragment
+    }
+
+    /// The possible readings of an [`Ambiguous`](ParseErrorKind::Ambiguous)
+    /// input, empty for every other kind.
+    pub fn candidates(&self) -> &[Dehumanized] {
+        &self.candidates
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::Empty => f.write_str("empty input")?,
+            ParseErrorKind::InvalidNumber => write!(f, "invalid number '{}'", self.fragment)?,
+            ParseErrorKind::UnknownUnit => write!(f, "unknown unit '{}'", self.fragment)?,
+            ParseErrorKind::OutOfRange => write!(f, "'{}' is out of range", self.fragment)?,
+            ParseErrorKind::TrailingInput => write!(f, "unexpected '{}'", self.fragment)?,
+            ParseErrorKind::Ambiguous => write!(f, "ambiguous '{}'", self.fragment)?,
+            ParseErrorKind::DuplicateUnit => write!(f, "duplicate unit '{}'", self.fragment)?,
+            ParseErrorKind::InvalidMode => write!(f, "invalid mode '{}'", self.fragment)?,
+        }
+        if self.kind != ParseErrorKind::Empty {
+            write!(f, " at position {}", self.position)?;
+        }
+        if let Some(expected) = self.expected {
+            write!(f, " (expected one of {})", expected)?;
+        }
+        if !self.candidates.is_empty() {
+            let candidates = HumanList::from(&self.candidates).or();
+            write!(f, " (could be {})", candidates)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseError> for io::Error {
+    fn from(err: ParseError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidInput, err)
+    }
+}
+
+/* -------------------- CapacityError -------------------- */
+
+/// Error returned by the `format_into` methods when the output doesn't fit
+/// the buffer. Nothing useful is left in the buffer; retry with at least
+/// [`required`](CapacityError::required) bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CapacityError {
+    required: usize,
+    capacity: usize,
+}
+
+impl CapacityError {
+    pub(crate) fn new(required: usize, capacity: usize) -> Self {
+        Self { required, capacity }
+    }
+
+    /// Bytes the formatted text needs.
+    pub fn required(&self) -> usize {
+        self.required
+    }
+
+    /// Bytes the buffer had.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "output needs {} bytes but the buffer holds {}",
+            self.required, self.capacity
+        )
+    }
+}
+
+impl Error for CapacityError {}
+
+/* -------------------- CoordinateError -------------------- */
+
+/// Error returned by [`HumanCoordinate::from_lat_lon`](crate::HumanCoordinate::from_lat_lon)
+/// for a latitude beyond ±90 or a longitude beyond ±180 (or NaN).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CoordinateError {
+    Latitude(f64),
+    Longitude(f64),
+}
+
+impl fmt::Display for CoordinateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoordinateError::Latitude(value) => {
+                write!(f, "latitude {} is outside -90 to 90", value)
+            }
+            CoordinateError::Longitude(value) => {
+                write!(f, "longitude {} is outside -180 to 180", value)
+            }
+        }
+    }
+}
+
+impl Error for CoordinateError {}
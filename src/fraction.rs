@@ -0,0 +1,147 @@
+use std::fmt::{self, Write};
+
+use crate::core::{HumanFormat, Style, human_display, typeset};
+use crate::words::{spell_cardinal, spell_ordinal};
+
+/* -------------------- HumanFraction -------------------- */
+
+/// Decimals as simple fractions: "3/4" concise, "three quarters" full.
+///
+/// The closest fraction with a denominator up to
+/// [`max_denominator`](HumanFraction::max_denominator) (default 16) is used.
+/// Values over 1 become mixed numbers ("1 1/2", "one and a half"), and when
+/// the fraction is off by more than the [`tolerance`](HumanFraction::tolerance)
+/// it is prefixed with "about". Non-finite values render as "-".
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HumanFraction {
+    pub(crate) value: f64,
+    max_denominator: u64,
+    tolerance: f64,
+}
+
+human_display!(HumanFraction);
+
+// Mixed number closest to a value: whole + numerator / denominator.
+struct Approximation {
+    negative: bool,
+    whole: u64,
+    numerator: u64,
+    denominator: u64,
+    exact: bool,
+}
+
+impl HumanFraction {
+    pub fn from(value: f64) -> Self {
+        Self {
+            value,
+            max_denominator: 16,
+            tolerance: 1e-9,
+        }
+    }
+
+    /// Largest denominator to consider (default 16, at least 1).
+    pub fn max_denominator(mut self, max_denominator: u64) -> Self {
+        self.max_denominator = max_denominator.max(1);
+        self
+    }
+
+    /// Largest error still written without "about" (default 1e-9, so only
+    /// fractions that are exact up to float rounding).
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance.abs();
+        self
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    fn approximate(&self) -> Approximation {
+        let magnitude = self.value.abs();
+        let mut whole = magnitude.trunc() as u64;
+        let fraction = magnitude.fract();
+
+        // Smallest denominator wins ties, so the result is already in lowest terms.
+        let (mut numerator, mut denominator, mut error) = (0, 1, fraction);
+        for d in 1..=self.max_denominator {
+            let n = (fraction * d as f64).round();
+            let e = (fraction - n / d as f64).abs();
+            if e < error {
+                (numerator, denominator, error) = (n as u64, d, e);
+            }
+        }
+        if numerator == denominator {
+            whole += 1;
+            (numerator, denominator) = (0, 1);
+        }
+
+        Approximation {
+            negative: self.value < 0.0 && (whole > 0 || numerator > 0),
+            whole,
+            numerator,
+            denominator,
+            exact: error <= self.tolerance,
+        }
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        if !self.value.is_finite() {
+            return w.write_str("-");
+        }
+
+        let a = self.approximate();
+        if !a.exact {
+            w.write_str("about ")?;
+        }
+
+        match style {
+            Style::Concise => {
+                if a.negative {
+                    w.write_char('-')?;
+                }
+                match (a.whole, a.numerator) {
+                    (whole, 0) => write!(w, "{}", whole),
+                    (0, numerator) => write!(w, "{}/{}", numerator, a.denominator),
+                    (whole, numerator) => write!(w, "{} {}/{}", whole, numerator, a.denominator),
+                }
+            }
+            Style::Full => {
+                if a.negative {
+                    w.write_str("negative ")?;
+                }
+                if a.numerator == 0 {
+                    return w.write_str(&spell_cardinal(a.whole));
+                }
+                if a.whole > 0 {
+                    write!(w, "{} and ", spell_cardinal(a.whole))?;
+                }
+                // "one and a half", but "one half" on its own
+                if a.numerator == 1 && a.whole > 0 {
+                    w.write_str("a")?;
+                } else {
+                    w.write_str(&spell_cardinal(a.numerator))?;
+                }
+                write!(w, " {}", denominator_word(a.denominator, a.numerator == 1))
+            }
+        }
+    }
+}
+
+fn denominator_word(denominator: u64, singular: bool) -> String {
+    match (denominator, singular) {
+        (2, true) => "half".to_string(),
+        (2, false) => "halves".to_string(),
+        (4, true) => "quarter".to_string(),
+        (4, false) => "quarters".to_string(),
+        (d, true) => spell_ordinal(d),
+        (d, false) => spell_ordinal(d) + "s",
+    }
+}
@@ -0,0 +1,101 @@
+use std::fmt::{self, Write};
+use std::time::Duration;
+
+use crate::config::decimal_mark;
+use crate::core::{HumanFormat, Style, human_display, scale_units, typeset, write_trimmed};
+
+/* -------------------- HumanFrequency -------------------- */
+
+/// Frequencies with SI prefixes: "3.6 GHz" concise, "3.6 gigahertz" full.
+///
+/// Values below 1 Hz render in millihertz ("500 mHz") unless
+/// [`as_period`](HumanFrequency::as_period) is set, in which case they read
+/// as the time between cycles ("every 2.5 s"). Negative and non-finite
+/// frequencies render as "-".
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HumanFrequency {
+    pub(crate) hz: f64,
+    precision: usize,
+    period_below_one_hz: bool,
+}
+
+human_display!(HumanFrequency);
+
+impl HumanFrequency {
+    pub fn from_hz(hz: f64) -> Self {
+        Self {
+            hz,
+            precision: 1,
+            period_below_one_hz: false,
+        }
+    }
+
+    /// The frequency of something that repeats every `period`.
+    pub fn from_period(period: Duration) -> Self {
+        let secs = period.as_secs_f64();
+        Self::from_hz(if secs > 0.0 { 1.0 / secs } else { f64::NAN })
+    }
+
+    /// Maximum number of decimal places (default 1); trailing zeros are trimmed.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision.min(300);
+        self
+    }
+
+    /// Render sub-hertz values as a period ("every 2.5 s") instead of millihertz.
+    pub fn as_period(mut self) -> Self {
+        self.period_below_one_hz = true;
+        self
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        if !self.hz.is_finite() || self.hz < 0.0 {
+            return w.write_str("-");
+        }
+
+        if self.hz > 0.0 && self.hz < 1.0 {
+            if self.period_below_one_hz {
+                w.write_str("every ")?;
+                write_trimmed(w, 1.0 / self.hz, self.precision, decimal_mark(None, style))?;
+                return match style {
+                    Style::Concise => w.write_str(" s"),
+                    Style::Full => w.write_str(" seconds"),
+                };
+            }
+
+            write_trimmed(
+                w,
+                self.hz * 1000.0,
+                self.precision,
+                decimal_mark(None, style),
+            )?;
+            return match style {
+                Style::Concise => w.write_str(" mHz"),
+                Style::Full => w.write_str(" millihertz"),
+            };
+        }
+
+        let units_short = ["Hz", "kHz", "MHz", "GHz", "THz"];
+        let units_full = ["hertz", "kilohertz", "megahertz", "gigahertz", "terahertz"];
+
+        let (rounded, idx) = scale_units(self.hz, 1000.0, units_short.len(), self.precision);
+        write_trimmed(w, rounded, self.precision, decimal_mark(None, style))?;
+
+        match style {
+            Style::Concise => write!(w, " {}", units_short[idx]),
+            Style::Full => write!(w, " {}", units_full[idx]),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Extension traits for converting std values into the `Human*` types.
+//!
+//! The methods carry a `human_` prefix so they don't collide with methods
+//! of the same name from other crates (`size()`, `time()` and `number()` are
+//! all common). The exception is [`HumanizeSystemTime::ago`], which reads
+//! naturally and has no widespread counterpart on `SystemTime`.
+
+use std::time::{Duration, SystemTime};
+
+use crate::{HumanDuration, HumanNumber, HumanSize, HumanTime};
+
+/* -------------------- HumanizeNumber -------------------- */
+
+/// `1_200.human_number()` instead of `HumanNumber::from(1_200)`.
+pub trait HumanizeNumber {
+    fn human_number(self) -> HumanNumber;
+}
+
+macro_rules! humanize_number {
+    ($($t:ty),*) => {
+        $(
+            impl HumanizeNumber for $t {
+                fn human_number(self) -> HumanNumber {
+                    HumanNumber::from(self)
+                }
+            }
+        )*
+    };
+}
+
+humanize_number!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+/* -------------------- HumanizeBytes -------------------- */
+
+/// `5_242_880u64.human_size()` instead of `HumanSize::from(5_242_880)`.
+pub trait HumanizeBytes {
+    fn human_size(self) -> HumanSize;
+}
+
+macro_rules! humanize_bytes {
+    ($($t:ty),*) => {
+        $(
+            impl HumanizeBytes for $t {
+                fn human_size(self) -> HumanSize {
+                    HumanSize::from(self as u64)
+                }
+            }
+        )*
+    };
+}
+
+humanize_bytes!(u8, u16, u32, u64, usize);
+
+/* -------------------- HumanizeDuration -------------------- */
+
+/// `elapsed.human_time()` instead of `HumanTime::from(elapsed)`.
+pub trait HumanizeDuration {
+    fn human_time(self) -> HumanTime;
+}
+
+impl HumanizeDuration for Duration {
+    fn human_time(self) -> HumanTime {
+        HumanTime::from(self)
+    }
+}
+
+/* -------------------- HumanizeSystemTime -------------------- */
+
+/// `modified.ago()` instead of `HumanDuration::from(Some(modified))`.
+pub trait HumanizeSystemTime {
+    fn ago(self) -> HumanDuration;
+}
+
+impl HumanizeSystemTime for SystemTime {
+    fn ago(self) -> HumanDuration {
+        HumanDuration::from(Some(self))
+    }
+}
+
+impl HumanizeSystemTime for Option<SystemTime> {
+    fn ago(self) -> HumanDuration {
+        HumanDuration::from(self)
+    }
+}
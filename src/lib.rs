@@ -4,11 +4,65 @@
 //! into human-readable formats.
 //!
 //! ## Quick Links
-//! - [`HumanNumber`]: Convert large numbers into k, M, B, T or thousand/million/billion/trillion
+//! - [`HumanNumber`]: Convert large numbers into k, M, B, T, Q, Qi or thousand/million/…/quintillion
 //! - [`HumanSize`]: Convert bytes into KiB, MiB, GiB, etc.
 //! - [`HumanDuration`]: Show elapsed time since a timestamp in human-readable format
-//! - [`HumanTime`]: Format a `Duration` as H:M:S
+//! - [`HumanTime`]: Format a `Duration` as days, hours, minutes and seconds
 //! - [`HumanPercent`]: Round and format floating-point numbers as percentages
+//! - [`HumanPermissions`]: Render Unix permission bits as `rwxr-xr-x` or a sentence
+//! - [`HumanOrdinal`]: Format rankings as 1st, 2nd, 3rd or first, second, third
+//! - [`HumanBitrate`]: Convert network throughput into bps, kbps, Mbps, etc.
+//! - [`HumanFrequency`]: Convert hertz into kHz, MHz, GHz, etc.
+//! - [`HumanList`]: Join items as "a, b, and c"
+//! - [`HumanMoney`]: Format currency amounts as $12,345.67 or 12,345.67 US dollars
+//! - [`HumanFraction`]: Approximate decimals as fractions like 3/4 or three quarters
+//! - [`HumanDate`]: Format timestamps as 12 Mar 2024 or March 12, 2024
+//! - [`HumanQuantity`]: Add SI prefixes to any unit, like 1.2 kW or 1.2 kilowatts
+//! - [`HumanPlural`]: Agree a noun with a count, like 1 file or 3 files
+//! - [`HumanRange`]: Format ranges like 1.5–2.0 GiB or 3–5 minutes
+//! - [`HumanDelta`]: Show a change as +1.2 MiB (+3.4%)
+//! - [`HumanDistance`]: Format distances like 750 m, 1.2 km or 0.75 mi
+//! - [`HumanMass`]: Format masses like 250 g, 1.2 kg or 2 lb 10 oz
+//! - [`HumanTemperature`]: Format temperatures like 23°C, 73°F or 296 K
+//! - [`HumanAge`]: Format ages like 34 years old or 6 months old
+//! - [`HumanTimeOfDay`]: Format clock times like quarter past three or 3:15 pm
+//! - [`HumanComparison`]: Compare a value with a baseline, like 2.3× larger or 12% smaller
+//! - [`HumanCoordinate`]: Format latitude and longitude as 48°51′24″N 2°21′08″E or 48.8567° N
+//! - [`HumanPace`]: Format running pace like 5:30 /km or 8:51 /mi
+//! - [`HumanUptime`]: Format uptime like uptime(1): up 3 days, 4:05
+//! - [`HumanCount`]: Group exact counts as 1,234,567
+//!
+//! ## One-liners
+//!
+//! When a concise string is all you need, the crate root has shorthand
+//! functions for the common types:
+//!
+//! ```rust
+//! use std::time::{Duration, SystemTime};
+//!
+//! assert_eq!(humanly::number(1_800_000), "1.8M");
+//! assert_eq!(humanly::size(5_242_880), "5 MiB");
+//! assert_eq!(humanly::time(Duration::from_secs(90)), "1m 30s");
+//! assert_eq!(humanly::ago(SystemTime::now() - Duration::from_secs(7_200)), "2h ago");
+//! assert_eq!(humanly::percent(12.34, 1), "12.3%");
+//! assert_eq!(humanly::compare(230.0, 100.0), "2.3×");
+//! ```
+//!
+//! ## Reading values back
+//!
+//! [`dehumanize`] reads a humanized string without being told its kind and
+//! tags the result with a [`Dehumanized`] variant. Input that fits more than
+//! one reading, like "5B" (bytes or billions), fails with
+//! [`ParseErrorKind::Ambiguous`] and lists the candidates:
+//!
+//! ```rust
+//! use humanly::{Dehumanized, dehumanize};
+//!
+//! assert_eq!(dehumanize("1.5 GiB"), Ok(Dehumanized::Bytes(1_610_612_736)));
+//! assert_eq!(dehumanize("45%"), Ok(Dehumanized::Percent(45.0)));
+//! assert!(matches!(dehumanize("2 hours ago"), Ok(Dehumanized::Timestamp(_))));
+//! assert_eq!(dehumanize("5B").unwrap_err().candidates().len(), 2);
+//! ```
 //!
 //! ## Output formats
 //!
@@ -24,6 +78,172 @@
 //! println!("{}", HumanNumber::from(1_800).full());
 //! ```
 //!
+//! `Display` uses the full form, and the alternate flag (`{:#}`) selects the concise one:
+//!
+//! ```rust
+//! use humanly::HumanSize;
+//!
+//! assert_eq!(format!("{}", HumanSize::from(1024)), "1 kibibyte");
+//! assert_eq!(format!("{:#}", HumanSize::from(1024)), "1 KiB");
+//! ```
+//!
+//! ## Extension traits
+//!
+//! The [`prelude`] brings every type into scope along with extension traits, so
+//! std values can be humanized directly:
+//!
+//! ```rust
+//! use humanly::prelude::*;
+//! use std::time::{Duration, SystemTime};
+//!
+//! assert_eq!(1_200.human_number().concise(), "1.2k");
+//! assert_eq!(5_242_880u64.human_size().concise(), "5 MiB");
+//! assert_eq!(Duration::from_secs(90).human_time().concise(), "1m 30s");
+//! assert_eq!(SystemTime::now().ago().concise(), "just now");
+//! ```
+//!
+//! ## Mixing types
+//!
+//! Every type implements [`HumanFormat`], an object-safe trait with `concise()`
+//! and `full()`, so one function or collection can take any of them:
+//!
+//! ```rust
+//! use humanly::{HumanFormat, HumanPercent, HumanSize, HumanTime};
+//! use std::time::Duration;
+//!
+//! fn cell(value: &dyn HumanFormat) -> String {
+//!     value.concise()
+//! }
+//!
+//! assert_eq!(cell(&HumanSize::from(5_242_880)), "5 MiB");
+//! assert_eq!(cell(&HumanTime::from(Duration::from_secs(90))), "1m 30s");
+//! assert_eq!(cell(&HumanPercent::from(12.3456, 1)), "12.3%");
+//! ```
+//!
+//! ## Writing without allocating
+//!
+//! `concise()` and `full()` return a new `String`. When formatting many values,
+//! `write_concise` and `write_full` write straight into any [`std::fmt::Write`]
+//! instead, so a single buffer can be reused; `write_into` takes the
+//! [`Style`] as an argument:
+//!
+//! ```rust
+//! use humanly::{HumanSize, Style};
+//!
+//! let mut cell = String::new();
+//! for bytes in [1_024, 5_242_880] {
+//!     cell.clear();
+//!     HumanSize::from(bytes).write_concise(&mut cell).unwrap();
+//! }
+//! assert_eq!(cell, "5 MiB");
+//!
+//! cell.clear();
+//! HumanSize::from(1_024).write_into(&mut cell, Style::Full).unwrap();
+//! assert_eq!(cell, "1 kibibyte");
+//! ```
+//!
+//! Where there is no allocator to grow a `String`, [`HumanNumber`],
+//! [`HumanSize`] and [`HumanTime`] also have `format_into`, which fills a byte
+//! buffer and returns the text as a `&str`. Each type documents the longest
+//! output it can produce, so the buffer can be sized at compile time:
+//!
+//! ```rust
+//! use humanly::{HumanSize, Style};
+//!
+//! let mut buf = [0u8; HumanSize::MAX_CONCISE_LEN];
+//! let text = HumanSize::from(1_500_000u64)
+//!     .format_into(&mut buf, Style::Concise)
+//!     .unwrap();
+//! assert_eq!(text, "1.4 MiB");
+//! ```
+//!
+//! ## Aligning columns
+//!
+//! [`align`] and [`align_decimal`] pad a column of values to a common width
+//! with the numbers right-aligned, so units and decimal points line up in tables:
+//!
+//! ```rust
+//! use humanly::{HumanSize, Style, align};
+//!
+//! let sizes = [HumanSize::from(1_000_000), HumanSize::from(5_242_880)];
+//! assert_eq!(align(&sizes, Style::Concise), ["976.6 KiB", "    5 MiB"]);
+//! ```
+//!
+//! ## Styling parts
+//!
+//! `into_parts` returns the pieces of a formatted value as a [`Parts`], for
+//! interfaces that style the number and the unit differently:
+//!
+//! ```rust
+//! use humanly::{HumanNumber, Style};
+//!
+//! let parts = HumanNumber::from(-1_500).into_parts(Style::Concise);
+//! assert_eq!((parts.sign.as_str(), parts.number.as_str(), parts.unit.as_str()), ("-", "1.5", "k"));
+//! ```
+//!
+//! ## Chart axes
+//!
+//! `HumanNumber::ticks` and `HumanSize::ticks` pick round steps between two
+//! bounds and label every [`Tick`] with the same unit and precision:
+//!
+//! ```rust
+//! use humanly::HumanNumber;
+//!
+//! let ticks = HumanNumber::ticks(0.0, 2_500_000.0, 6);
+//! let labels: Vec<&str> = ticks.iter().map(|tick| tick.label.as_str()).collect();
+//! assert_eq!(labels, ["0", "0.5M", "1.0M", "1.5M", "2.0M", "2.5M"]);
+//! ```
+//!
+//! `log_ticks` gives the powers of ten (or of 1024 for sizes) instead.
+//!
+//! ## Porting from Python
+//!
+//! [`compat::python`] has `intword`, `intcomma`, `naturalsize`, `naturaldelta`
+//! and `naturaltime` with the exact output of Python's `humanize` package.
+//!
+//! ## Locales
+//!
+//! `full()` output is English by default. Every type has a `.locale()` builder to
+//! switch the words, pluralization, word order and decimal mark:
+//!
+//! ```rust
+//! use humanly::{HumanNumber, HumanTime, Locale};
+//! use std::time::Duration;
+//!
+//! assert_eq!(HumanNumber::from(1_500_000).locale(Locale::Spanish).full(), "1,5 millones");
+//! assert_eq!(
+//!     HumanTime::from(Duration::from_secs(3661)).locale(Locale::Spanish).to_string(),
+//!     "1 hora 1 minuto 1 segundo"
+//! );
+//! ```
+//!
+//! ## Configuration
+//!
+//! [`Config`] sets application-wide defaults (decimal separator, suffix case,
+//! size unit system, [`Typography`]) for values that don't set the option themselves. Install
+//! it once with [`Config::set_global`], or for the current thread with
+//! [`Config::with`]:
+//!
+//! ```rust
+//! use humanly::{Config, HumanSize, UnitSystem};
+//!
+//! Config::with(Config::default().unit_system(UnitSystem::Decimal), |_| {
+//!     assert_eq!(HumanSize::from(5_000_000).concise(), "5 MB");
+//! });
+//! ```
+//!
+//! `Typography::Unicode` typesets output with a narrow no-break space between
+//! number and unit and a true minus sign; the parsers accept either form:
+//!
+//! ```rust
+//! use humanly::{Config, HumanNumber, HumanSize, Typography};
+//!
+//! Config::with(Config::default().typography(Typography::Unicode), |_| {
+//!     assert_eq!(HumanSize::from(5_242_880).concise(), "5\u{202F}MiB");
+//!     assert_eq!(HumanNumber::from(-1_500).concise(), "\u{2212}1.5k");
+//! });
+//! ```
+//!
 //! ## Examples
 //!
 //! ```rust
@@ -39,6 +259,7 @@
 //! assert_eq!(HumanNumber::from(2_500_000_000.0).full(), "2.5 billion");
 //! assert_eq!(HumanNumber::from(3_700_000_000_000.0).concise(), "3.7T");
 //! assert_eq!(HumanNumber::from(3_700_000_000_000.0).full(), "3.7 trillion");
+//! assert_eq!(HumanNumber::from(1_234_567).precision(2).concise(), "1.23M");
 //!
 //! // HumanSize
 //! // Binary (default, 1024-based)
@@ -69,34 +290,300 @@
 //! assert_eq!(HumanPercent::from(12.3456, 1).to_string(), "12.3 percent");
 //! ```
 //!
+//! ## Cargo features
+//!
+//! - `serde` — `Serialize`/`Deserialize` for every type, as the underlying numeric value.
+//! - `clap` — [`clap`] value parsers for size and duration arguments (`--max-size 512MiB`).
+//! - `cli` — a `humanly` binary (`humanly size 5242880`) for shell scripts.
+//! - `chrono` — `From` conversions for `chrono` date-times, dates and durations.
+//! - `time` — `From` conversions for `time` date-times, dates and durations.
+//! - `rust_decimal`, `bigdecimal` — `From` conversions into `HumanNumber` and
+//!   `HumanPercent` that round the decimal itself rather than an `f64`.
+//! - `wasm` — [`wasm`] bindings (`human_size`, `human_ago_unix_ms`…) for JavaScript.
+//! - `ffi` — [`ffi`] functions (`humanly_size`, `humanly_ago_unix_secs`…) for C,
+//!   with the header in `include/humanly.h`.
+//! - `defmt` — `defmt::Format` for `HumanNumber`, `HumanSize` and `HumanTime`,
+//!   logging the concise form without allocating.
+//!
 //! ## Crate modules
 //!
-//! - [`HumanNumber`] — Convert numbers to K/M/B/T notation (concise) or word format (full).
+//! - [`HumanNumber`] — Convert numbers to K/M/B/T/Q/Qi notation (concise) or word format (full).
 //! - [`HumanSize`] — Convert bytes to human-readable units (KiB, MiB…).
 //! - [`HumanDuration`] — Show how long ago a timestamp occurred in short or long format.
 //! - [`HumanTime`] — Convert `Duration` into H:M:S strings.
 //! - [`HumanPercent`] — Round floats and display as percentage string.
+//! - [`HumanPermissions`] — Convert Unix mode bits to symbolic or sentence form.
+//! - [`HumanOrdinal`] — Convert integers to ordinals (21st, twenty-first).
+//! - [`HumanBitrate`] — Convert bits or bytes per second to Mbps, Gbps… or Mibit/s.
+//! - [`HumanFrequency`] — Convert hertz or a period to Hz, kHz, MHz, GHz, THz.
+//! - [`HumanList`] — Join items in natural language, with "and"/"or" and truncation.
+//! - [`HumanMoney`] — Format integer minor units as currency ($12,345.67, 12,345.67 US dollars).
+//! - [`HumanFraction`] — Approximate decimals as simple fractions (3/4, one and a half).
+//! - [`HumanDate`] — Format a `SystemTime` as an absolute date (12 Mar 2024, March 12, 2024).
+//! - [`HumanQuantity`] — Scale any unit with SI prefixes from pico to tera (1.2 kW, 4.7 µF).
+//! - [`HumanPlural`] — Pair a count with a correctly pluralized noun (1 file, 3 files, 2 indices).
+//! - [`HumanRange`] — Format two numbers, sizes or durations as a range with a shared unit (1.5–2.0 GiB).
+//! - [`HumanDelta`] — Show the change between two numbers or sizes, absolute and relative (+1.2 MiB (+3.4%)).
+//! - [`HumanDistance`] — Convert meters to mm, cm, m, km or ft, yd, mi.
+//! - [`HumanMass`] — Convert grams to mg, g, kg, t or oz, lb.
+//! - [`HumanTemperature`] — Convert and format Celsius, Fahrenheit and kelvin temperatures.
+//! - [`HumanAge`] — Compute calendar-correct ages from birth dates.
+//! - [`HumanTimeOfDay`] — Fuzzy English and 12/24-hour clock times.
+//! - [`HumanComparison`] — Describe one number, size or duration relative to another (2.3× larger, 12% smaller, about the same).
+//! - [`HumanCoordinate`] — Format and parse latitude/longitude in degrees, minutes and seconds or decimal degrees.
+//! - [`HumanPace`] — Turn a distance and elapsed time into pace per kilometer or mile, with the speed as well.
+//! - [`HumanUptime`] — Format system uptime in the uptime(1) style (up 3 days, 4:05) or in words.
+//! - [`HumanCount`] — Write exact integers or decimals with thousands separators (1,234,567).
 //!
 //! [`HumanNumber`]: struct.HumanNumber.html
 //! [`HumanSize`]: struct.HumanSize.html
 //! [`HumanDuration`]: struct.HumanDuration.html
 //! [`HumanTime`]: struct.HumanTime.html
 //! [`HumanPercent`]: struct.HumanPercent.html
+//! [`HumanPermissions`]: struct.HumanPermissions.html
+//! [`HumanOrdinal`]: struct.HumanOrdinal.html
+//! [`HumanBitrate`]: struct.HumanBitrate.html
+//! [`HumanFrequency`]: struct.HumanFrequency.html
+//! [`HumanList`]: struct.HumanList.html
+//! [`HumanMoney`]: struct.HumanMoney.html
+//! [`HumanFraction`]: struct.HumanFraction.html
+//! [`HumanDate`]: struct.HumanDate.html
+//! [`HumanQuantity`]: struct.HumanQuantity.html
+//! [`HumanPlural`]: struct.HumanPlural.html
+//! [`HumanRange`]: struct.HumanRange.html
+//! [`HumanDelta`]: struct.HumanDelta.html
+//! [`HumanDistance`]: struct.HumanDistance.html
+//! [`HumanMass`]: struct.HumanMass.html
+//! [`HumanTemperature`]: struct.HumanTemperature.html
+//! [`HumanAge`]: struct.HumanAge.html
+//! [`HumanTimeOfDay`]: struct.HumanTimeOfDay.html
+//! [`HumanComparison`]: struct.HumanComparison.html
+//! [`HumanCoordinate`]: struct.HumanCoordinate.html
+//! [`HumanPace`]: struct.HumanPace.html
+//! [`HumanUptime`]: struct.HumanUptime.html
+//! [`HumanCount`]: struct.HumanCount.html
 
+mod age;
+mod align;
+mod bitrate;
+mod compare;
+mod config;
+mod coordinate;
 mod core;
+mod count;
+mod date;
+mod decimal;
+mod dehumanize;
+mod delta;
+mod distance;
+mod error;
+mod fraction;
+mod frequency;
+mod humanize;
+mod list;
+mod locale;
+mod mass;
+mod money;
+mod ordinal;
+mod pace;
+mod parse;
+mod parts;
+mod plural;
+mod quantity;
+mod range;
+mod temperature;
+mod ticks;
+mod time_of_day;
+mod uptime;
+mod words;
+pub use age::HumanAge;
+pub use align::{align, align_decimal};
+pub use bitrate::HumanBitrate;
+pub use compare::HumanComparison;
+pub use config::{Config, SuffixCase, Typography};
+pub use coordinate::HumanCoordinate;
 pub use core::HumanDuration;
+pub use core::HumanFormat;
 pub use core::HumanNumber;
 pub use core::HumanPercent;
 pub use core::HumanSize;
 pub use core::HumanTime;
+pub use core::IntoHumanNumber;
+pub use core::NumberSystem;
+pub use core::Rounding;
+pub use core::SizeUnit;
+pub use core::Style;
+pub use core::UnitSystem;
+pub use count::HumanCount;
+pub use date::{DateStyle, HumanDate};
+pub use dehumanize::{Dehumanized, dehumanize};
+pub use delta::HumanDelta;
+pub use distance::HumanDistance;
+pub use error::{CapacityError, CoordinateError, ParseError, ParseErrorKind};
+pub use fraction::HumanFraction;
+pub use frequency::HumanFrequency;
+pub use list::HumanList;
+pub use locale::{Locale, TimeUnit};
+pub use mass::HumanMass;
+pub use money::{Currency, HumanMoney};
+pub use ordinal::HumanOrdinal;
+pub use pace::HumanPace;
+pub use parts::Parts;
+pub use plural::HumanPlural;
+pub use quantity::HumanQuantity;
+pub use range::HumanRange;
+pub use temperature::HumanTemperature;
+pub use ticks::Tick;
+pub use time_of_day::HumanTimeOfDay;
+pub use uptime::HumanUptime;
+
+mod permissions;
+pub use permissions::HumanPermissions;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "clap")]
+pub mod clap;
+
+pub mod compat;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(feature = "defmt")]
+mod defmt;
+
+#[cfg(feature = "chrono")]
+mod chrono;
+
+#[cfg(feature = "time")]
+mod time;
+
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
 
-pub fn add(left: u64, right: u64) -> u64 {
-    left + right
+pub use humanize::{HumanizeBytes, HumanizeDuration, HumanizeNumber, HumanizeSystemTime};
+
+use std::time::{Duration, SystemTime};
+
+/// Everything needed for everyday use: the types and the extension traits.
+///
+/// ```rust
+/// use humanly::prelude::*;
+///
+/// assert_eq!(1_200.human_number().concise(), "1.2k");
+/// ```
+pub mod prelude {
+    pub use crate::{
+        Config, Currency, DateStyle, HumanAge, HumanBitrate, HumanComparison, HumanCoordinate,
+        HumanCount, HumanDate, HumanDelta, HumanDistance, HumanDuration, HumanFormat,
+        HumanFraction, HumanFrequency, HumanList, HumanMass, HumanMoney, HumanNumber, HumanOrdinal,
+        HumanPace, HumanPercent, HumanPermissions, HumanPlural, HumanQuantity, HumanRange,
+        HumanSize, HumanTemperature, HumanTime, HumanTimeOfDay, HumanUptime, HumanizeBytes,
+        HumanizeDuration, HumanizeNumber, HumanizeSystemTime, Locale, NumberSystem, Parts,
+        Rounding, SizeUnit, Style, SuffixCase, Tick, TimeUnit, Typography, UnitSystem,
+    };
+}
+
+/// Formats a number concisely: shorthand for `HumanNumber::from(n).concise()`.
+///
+/// ```rust
+/// assert_eq!(humanly::number(1_800_000), "1.8M");
+/// ```
+pub fn number(number: impl IntoHumanNumber) -> String {
+    HumanNumber::from(number).concise()
+}
+
+/// Formats a byte count concisely: shorthand for `HumanSize::from(bytes).concise()`.
+///
+/// ```rust
+/// assert_eq!(humanly::size(5_242_880), "5 MiB");
+/// ```
+pub fn size(bytes: u64) -> String {
+    HumanSize::from(bytes).concise()
+}
+
+/// Formats a duration concisely: shorthand for `HumanTime::from(duration).concise()`.
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// assert_eq!(humanly::time(Duration::from_secs(3_661)), "1h 1m 1s");
+/// ```
+pub fn time(duration: Duration) -> String {
+    HumanTime::from(duration).concise()
+}
+
+/// Formats how long ago `time` was, concisely: shorthand for
+/// `HumanDuration::from(Some(time)).concise()`.
+///
+/// ```rust
+/// use std::time::{Duration, SystemTime};
+///
+/// let time = SystemTime::now() - Duration::from_secs(300);
+/// assert_eq!(humanly::ago(time), "5m ago");
+/// ```
+pub fn ago(time: SystemTime) -> String {
+    HumanDuration::from(Some(time)).concise()
+}
+
+/// Formats a percentage concisely: shorthand for
+/// `HumanPercent::from(value, decimals).concise()`.
+///
+/// ```rust
+/// assert_eq!(humanly::percent(12.34, 1), "12.3%");
+/// ```
+pub fn percent(value: f64, decimals: usize) -> String {
+    HumanPercent::from(value, decimals).concise()
+}
+
+/// Compares `value` with `baseline` concisely: shorthand for
+/// `HumanComparison::numbers(value, baseline).concise()`. The ratio is
+/// `value / baseline`; see [`HumanComparison`] for the full sentence and the
+/// threshold.
+///
+/// ```rust
+/// assert_eq!(humanly::compare(230.0, 100.0), "2.3×");
+/// assert_eq!(humanly::compare(88.0, 100.0), "-12%");
+/// ```
+pub fn compare(value: f64, baseline: f64) -> String {
+    HumanComparison::numbers(value, baseline).concise()
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::age::HumanAge;
+    use crate::bitrate::HumanBitrate;
+    use crate::compare::HumanComparison;
+    use crate::coordinate::HumanCoordinate;
     use crate::core::{HumanDuration, HumanNumber, HumanPercent, HumanSize, HumanTime};
+    use crate::date::{DateStyle, HumanDate, civil_from_days};
+    use crate::delta::HumanDelta;
+    use crate::distance::HumanDistance;
+    use crate::fraction::HumanFraction;
+    use crate::frequency::HumanFrequency;
+    use crate::list::HumanList;
+    use crate::locale::{Locale, TimeUnit};
+    use crate::mass::HumanMass;
+    use crate::money::{Currency, HumanMoney};
+    use crate::ordinal::HumanOrdinal;
+    use crate::pace::HumanPace;
+    use crate::parts::Parts;
+    use crate::permissions::HumanPermissions;
+    use crate::plural::HumanPlural;
+    use crate::quantity::HumanQuantity;
+    use crate::range::HumanRange;
+    use crate::temperature::HumanTemperature;
+    use crate::ticks::Tick;
+    use crate::time_of_day::HumanTimeOfDay;
+    use crate::uptime::HumanUptime;
     use std::time::{Duration, SystemTime};
 
     #[test]
@@ -128,6 +615,35 @@ mod tests {
         assert_eq!(HumanNumber::from(1_500_000_000).concise(), "1.5B");
         assert_eq!(HumanNumber::from(1_000_000_000_000.0).concise(), "1T");
         assert_eq!(HumanNumber::from(2_500_000_000_000.0).concise(), "2.5T");
+        assert_eq!(HumanNumber::from(1e15).concise(), "1Q");
+        assert_eq!(HumanNumber::from(4.2e15).full(), "4.2 quadrillion");
+        assert_eq!(HumanNumber::from(1e18).full(), "1 quintillion");
+        assert_eq!(HumanNumber::from(u64::MAX as f64).concise(), "18.4Qi");
+        assert_eq!(HumanNumber::from(-3e17).concise(), "-300Q");
+        assert_eq!(
+            HumanNumber::from(2e18).locale(Locale::Spanish).full(),
+            "2 trillones"
+        );
+
+        // NaN and infinity have no suffix; values past i64 keep every digit
+        for n in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let number = HumanNumber::from(n);
+            assert_eq!(number.concise(), "-");
+            assert_eq!(number.full(), "-");
+            assert_eq!(number.system(crate::NumberSystem::Indian).concise(), "-");
+        }
+        let zeros = "0".repeat(282);
+        assert_eq!(HumanNumber::from(1e300).concise(), format!("1{}Qi", zeros));
+        assert_eq!(
+            HumanNumber::from(-1e300).full(),
+            format!("-1{} quintillion", zeros)
+        );
+        assert_eq!(
+            HumanNumber::from(1e40).concise(),
+            "10000000000000000000000Qi"
+        );
+        let indian = HumanNumber::from(1e26).system(crate::NumberSystem::Indian);
+        assert_eq!(indian.concise(), "10000000000000000000Cr");
 
         // Test Display trait (should use full format)
         assert_eq!(HumanNumber::from(1_500).to_string(), "1.5 thousand");
@@ -137,12 +653,14 @@ mod tests {
     #[test]
     fn test_human_size() {
         // Binary (default)
-        assert_eq!(HumanSize::from(0).concise(), "0");
-        assert_eq!(HumanSize::from(1).concise(), "1");
+        assert_eq!(HumanSize::from(0).concise(), "0 B");
+        assert_eq!(HumanSize::from(1).concise(), "1 B");
         assert_eq!(HumanSize::from(1).full(), "1 byte");
-        assert_eq!(HumanSize::from(500).concise(), "500");
+        assert_eq!(HumanSize::from(500).concise(), "500 B");
         assert_eq!(HumanSize::from(500).full(), "500 bytes");
-        assert_eq!(HumanSize::from(1023).concise(), "1023");
+        assert_eq!(HumanSize::from(1023).concise(), "1023 B");
+        assert_eq!(HumanSize::from(500).bare_bytes().concise(), "500");
+        assert_eq!(HumanSize::from(1024).bare_bytes().concise(), "1 KiB");
         assert_eq!(HumanSize::from(1024).concise(), "1 KiB");
         assert_eq!(HumanSize::from(1024).to_string(), "1 kibibyte");
         assert_eq!(HumanSize::from(1_048_576).to_string(), "1 mebibyte");
@@ -162,64 +680,5324 @@ mod tests {
 
     #[test]
     fn test_human_duration() {
-        let now = SystemTime::now();
+        // Measured against a fixed "now", so a slow run can't cross a boundary
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let ago = |secs: u64| HumanDuration::from(Some(now - Duration::from_secs(secs))).as_of(now);
+
+        assert_eq!(ago(0).to_string(), "just now");
+        assert_eq!(ago(45).concise(), "45s ago");
+        assert_eq!(ago(120).to_string(), "2 minutes ago");
+        assert_eq!(ago(7200).to_string(), "2 hours ago");
+        assert_eq!(ago(172_800).concise(), "2d ago");
+        assert_eq!(ago(1_209_600).concise(), "2w ago");
+        assert_eq!(ago(5_259_492).to_string(), "2 months ago");
+        assert_eq!(ago(63_113_904).concise(), "2y ago");
+        assert_eq!(ago(86_400).to_string(), "yesterday");
+
+        // Unit boundaries
+        assert_eq!(ago(1).concise(), "1s ago");
+        assert_eq!(ago(59).concise(), "59s ago");
+        assert_eq!(ago(60).concise(), "1m ago");
+        assert_eq!(ago(3_599).concise(), "59m ago");
+        assert_eq!(ago(3_600).to_string(), "1 hour ago");
+        assert_eq!(ago(86_399).concise(), "23h ago");
+        assert_eq!(ago(604_800).concise(), "1w ago");
 
+        // Anchored to another time, e.g. the end of a log
+        let then = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
         assert_eq!(
-            HumanDuration::from(Some(now - Duration::from_secs(0))).to_string(),
+            HumanDuration::from(Some(then))
+                .as_of(then + Duration::from_secs(300))
+                .concise(),
+            "5m ago"
+        );
+
+        // Without `as_of`, the current time is used
+        assert_eq!(
+            HumanDuration::from(Some(SystemTime::now())).concise(),
             "just now"
         );
+    }
+
+    #[test]
+    fn test_human_time() {
+        assert_eq!(HumanTime::from(Duration::from_secs(45)).concise(), "45s");
+        assert_eq!(HumanTime::from(Duration::from_secs(90)).concise(), "1m 30s");
+        assert_eq!(
+            HumanTime::from(Duration::from_secs(3672)).to_string(),
+            "1 hour 1 minute 12 seconds"
+        );
+
+        // Days, and weeks up to a chosen largest unit
+        let time = |secs: u64| HumanTime::from(Duration::from_secs(secs));
+        assert_eq!(time(200_000).concise(), "2d 7h 33m 20s");
         assert_eq!(
-            HumanDuration::from(Some(now - Duration::from_secs(45))).concise(),
-            "45s ago"
+            time(200_000).to_string(),
+            "2 days 7 hours 33 minutes 20 seconds"
         );
+        assert_eq!(time(172_800).concise(), "2d");
+        assert_eq!(time(172_805).concise(), "2d 5s");
+        assert_eq!(time(176_400).concise(), "2d 1h 0m");
+        assert_eq!(time(1_209_600).concise(), "14d");
+        assert_eq!(time(1_296_000).max_unit(TimeUnit::Week).concise(), "2w 1d");
         assert_eq!(
-            HumanDuration::from(Some(now - Duration::from_secs(120))).to_string(),
-            "2 minutes ago"
+            time(1_296_000).max_unit(TimeUnit::Year).to_string(),
+            "2 weeks 1 day"
+        );
+        assert_eq!(
+            time(200_000).max_unit(TimeUnit::Hour).concise(),
+            "55h 33m 20s"
+        );
+        assert_eq!(
+            time(200_000).max_unit(TimeUnit::Minute).concise(),
+            "3333m 20s"
+        );
+        assert_eq!(
+            time(200_000).max_unit(TimeUnit::Second).concise(),
+            "200000s"
+        );
+        assert_eq!(time(0).max_unit(TimeUnit::Week).concise(), "0s");
+    }
+
+    #[test]
+    fn test_human_percent() {
+        assert_eq!(HumanPercent::from(12.3456, 0).concise(), "12%");
+        assert_eq!(HumanPercent::from(12.3456, 1).concise(), "12.3%");
+        assert_eq!(HumanPercent::from(12.3456, 2).to_string(), "12.35 percent");
+        assert_eq!(
+            HumanPercent::from(0.1234 * 100.0, 1).to_string(),
+            "12.3 percent"
         );
+    }
+    #[test]
+    fn test_human_permissions() {
+        assert_eq!(HumanPermissions::from(0o755).concise(), "rwxr-xr-x");
+        assert_eq!(HumanPermissions::from(0o4755).concise(), "rwsr-xr-x");
+        assert_eq!(HumanPermissions::from(0o4644).concise(), "rwSr--r--");
+        assert_eq!(HumanPermissions::from(0o1777).concise(), "rwxrwxrwt");
+
         assert_eq!(
-            HumanDuration::from(Some(now - Duration::from_secs(7200))).to_string(),
-            "2 hours ago"
+            HumanPermissions::from(0o755).describe(),
+            "Owner can read, write and execute; group can read and execute; others can read and execute."
         );
         assert_eq!(
-            HumanDuration::from(Some(now - Duration::from_secs(172_800))).concise(),
-            "2d ago"
+            HumanPermissions::from(0o640).describe(),
+            "Owner can read and write; group can read; others cannot do anything."
         );
         assert_eq!(
-            HumanDuration::from(Some(now - Duration::from_secs(1_209_600))).concise(),
-            "2w ago"
+            HumanPermissions::from(0o000).describe(),
+            "Owner cannot do anything; group cannot do anything; others cannot do anything."
         );
         assert_eq!(
-            HumanDuration::from(Some(now - Duration::from_secs(5_259_492))).to_string(),
-            "2 months ago"
+            HumanPermissions::from(0o4755).describe(),
+            "Owner can read, write and execute; group can read and execute; others can read and execute. The setuid bit is set."
         );
         assert_eq!(
-            HumanDuration::from(Some(now - Duration::from_secs(63_113_904))).concise(),
-            "2y ago"
+            HumanPermissions::from(0o7000).describe(),
+            "Owner cannot do anything; group cannot do anything; others cannot do anything. The setuid, setgid and sticky bits are set."
         );
+    }
+    #[test]
+    fn test_human_ordinal() {
+        assert_eq!(HumanOrdinal::from(1).concise(), "1st");
+        assert_eq!(HumanOrdinal::from(2).concise(), "2nd");
+        assert_eq!(HumanOrdinal::from(3).concise(), "3rd");
+        assert_eq!(HumanOrdinal::from(4).concise(), "4th");
+        assert_eq!(HumanOrdinal::from(11).concise(), "11th");
+        assert_eq!(HumanOrdinal::from(12).concise(), "12th");
+        assert_eq!(HumanOrdinal::from(13).concise(), "13th");
+        assert_eq!(HumanOrdinal::from(21).concise(), "21st");
+        assert_eq!(HumanOrdinal::from(112).concise(), "112th");
+        assert_eq!(HumanOrdinal::from(1_001).concise(), "1001st");
+        assert_eq!(HumanOrdinal::from(0).concise(), "0th");
+        assert_eq!(HumanOrdinal::from(-3).concise(), "-3rd");
+
+        assert_eq!(HumanOrdinal::from(1).full(), "first");
+        assert_eq!(HumanOrdinal::from(2).full(), "second");
+        assert_eq!(HumanOrdinal::from(3).full(), "third");
+        assert_eq!(HumanOrdinal::from(5).full(), "fifth");
+        assert_eq!(HumanOrdinal::from(8).full(), "eighth");
+        assert_eq!(HumanOrdinal::from(9).full(), "ninth");
+        assert_eq!(HumanOrdinal::from(11).full(), "eleventh");
+        assert_eq!(HumanOrdinal::from(12).full(), "twelfth");
+        assert_eq!(HumanOrdinal::from(20).full(), "twentieth");
+        assert_eq!(HumanOrdinal::from(21).full(), "twenty-first");
+        assert_eq!(HumanOrdinal::from(100).full(), "one hundredth");
+        assert_eq!(HumanOrdinal::from(103).full(), "one hundred third");
+        assert_eq!(HumanOrdinal::from(1_000_000).full(), "one millionth");
+        assert_eq!(HumanOrdinal::from(0).full(), "zeroth");
+        assert_eq!(HumanOrdinal::from(-3).full(), "negative third");
         assert_eq!(
-            HumanDuration::from(Some(now - Duration::from_secs(86_400))).to_string(),
-            "yesterday"
+            HumanOrdinal::from(i64::MIN).concise(),
+            "-9223372036854775808th"
         );
+
+        assert_eq!(HumanOrdinal::from(42).to_string(), "forty-second");
+        assert_eq!(format!("{:#}", HumanOrdinal::from(42)), "42nd");
     }
 
     #[test]
-    fn test_human_time() {
-        assert_eq!(HumanTime::from(Duration::from_secs(45)).concise(), "45s");
-        assert_eq!(HumanTime::from(Duration::from_secs(90)).concise(), "1m 30s");
+    fn test_human_bitrate() {
         assert_eq!(
-            HumanTime::from(Duration::from_secs(3672)).to_string(),
-            "1 hour 1 minute 12 seconds"
+            HumanBitrate::from_bits_per_sec(2_400_000).concise(),
+            "2.4 Mbps"
+        );
+        assert_eq!(
+            HumanBitrate::from_bits_per_sec(2_400_000).full(),
+            "2.4 megabits per second"
+        );
+        assert_eq!(
+            HumanBitrate::from_bits_per_sec(2_400_000)
+                .binary()
+                .concise(),
+            "2.3 Mibit/s"
+        );
+        assert_eq!(
+            HumanBitrate::from_bits_per_sec(2_400_000).binary().full(),
+            "2.3 mebibits per second"
+        );
+        assert_eq!(HumanBitrate::from_bits_per_sec(0).concise(), "0 bps");
+        assert_eq!(
+            HumanBitrate::from_bits_per_sec(1).full(),
+            "1 bit per second"
+        );
+        assert_eq!(HumanBitrate::from_bits_per_sec(999).concise(), "999 bps");
+        assert_eq!(HumanBitrate::from_bits_per_sec(1_000).concise(), "1 kbps");
+        assert_eq!(
+            HumanBitrate::from_bits_per_sec(1_000).full(),
+            "1 kilobit per second"
+        );
+        assert_eq!(HumanBitrate::from_bits_per_sec(999_960).concise(), "1 Mbps");
+        assert_eq!(
+            HumanBitrate::from_bits_per_sec(1_048_576)
+                .binary()
+                .decimal()
+                .concise(),
+            "1 Mbps"
+        );
+
+        assert_eq!(
+            HumanBitrate::from_bytes_per_sec(125_000).concise(),
+            "1 Mbps"
+        );
+        assert_eq!(
+            HumanBitrate::from_bytes_per_sec(131_072).binary().concise(),
+            "1 Mibit/s"
+        );
+
+        // 10 MB over 8 seconds
+        assert_eq!(
+            HumanBitrate::from_transfer(10_000_000, Duration::from_secs(8)).concise(),
+            "10 Mbps"
+        );
+        assert_eq!(
+            HumanBitrate::from_transfer(10_000_000, Duration::ZERO).concise(),
+            "-"
+        );
+        assert_eq!(
+            HumanBitrate::from_transfer(10_000_000, Duration::ZERO).full(),
+            "-"
+        );
+
+        assert_eq!(
+            HumanBitrate::from_bits_per_sec(1_500_000_000).to_string(),
+            "1.5 gigabits per second"
         );
     }
 
     #[test]
-    fn test_human_percent() {
-        assert_eq!(HumanPercent::from(12.3456, 0).concise(), "12%");
-        assert_eq!(HumanPercent::from(12.3456, 1).concise(), "12.3%");
-        assert_eq!(HumanPercent::from(12.3456, 2).to_string(), "12.35 percent");
+    fn test_human_frequency() {
+        assert_eq!(HumanFrequency::from_hz(144.0).concise(), "144 Hz");
+        assert_eq!(HumanFrequency::from_hz(144.0).full(), "144 hertz");
+        assert_eq!(HumanFrequency::from_hz(44_100.0).concise(), "44.1 kHz");
+        assert_eq!(HumanFrequency::from_hz(3.6e9).concise(), "3.6 GHz");
+        assert_eq!(HumanFrequency::from_hz(3.6e9).full(), "3.6 gigahertz");
+        assert_eq!(HumanFrequency::from_hz(2.4e12).concise(), "2.4 THz");
+        assert_eq!(HumanFrequency::from_hz(5e15).concise(), "5000 THz");
+        assert_eq!(HumanFrequency::from_hz(999_960.0).concise(), "1 MHz");
+        assert_eq!(HumanFrequency::from_hz(0.0).concise(), "0 Hz");
+
+        // Precision
         assert_eq!(
-            HumanPercent::from(0.1234 * 100.0, 1).to_string(),
-            "12.3 percent"
+            HumanFrequency::from_hz(3_591_234_567.0)
+                .precision(3)
+                .concise(),
+            "3.591 GHz"
+        );
+        assert_eq!(
+            HumanFrequency::from_hz(44_100.0).precision(0).concise(),
+            "44 kHz"
+        );
+
+        // From a period
+        assert_eq!(
+            HumanFrequency::from_period(Duration::from_millis(7)).concise(),
+            "142.9 Hz"
+        );
+        assert_eq!(
+            HumanFrequency::from_period(Duration::from_nanos(400)).full(),
+            "2.5 megahertz"
+        );
+        assert_eq!(HumanFrequency::from_period(Duration::ZERO).concise(), "-");
+
+        // Sub-hertz
+        assert_eq!(HumanFrequency::from_hz(0.5).concise(), "500 mHz");
+        assert_eq!(HumanFrequency::from_hz(0.5).full(), "500 millihertz");
+        assert_eq!(
+            HumanFrequency::from_period(Duration::from_millis(2_500))
+                .as_period()
+                .concise(),
+            "every 2.5 s"
+        );
+        assert_eq!(
+            HumanFrequency::from_hz(0.4).as_period().full(),
+            "every 2.5 seconds"
+        );
+        assert_eq!(HumanFrequency::from_hz(4.0).as_period().concise(), "4 Hz");
+
+        // Placeholders
+        assert_eq!(HumanFrequency::from_hz(-1.0).concise(), "-");
+        assert_eq!(HumanFrequency::from_hz(f64::INFINITY).full(), "-");
+        assert_eq!(HumanFrequency::from_hz(f64::NAN).to_string(), "-");
+
+        // Huge precisions are capped instead of overflowing
+        let frequency = HumanFrequency::from_hz(1_234.5);
+        assert_eq!(frequency.precision(400), frequency.precision(300));
+        assert_eq!(
+            HumanFrequency::from_hz(1e20)
+                .precision(usize::MAX)
+                .concise(),
+            "100000000 THz"
+        );
+    }
+
+    #[test]
+    fn test_human_list() {
+        let fruit = ["apples", "pears", "plums"];
+        assert_eq!(HumanList::from(&fruit).full(), "apples, pears, and plums");
+        assert_eq!(
+            HumanList::from(&fruit).no_oxford_comma().full(),
+            "apples, pears and plums"
+        );
+        assert_eq!(
+            HumanList::from(&fruit).or().full(),
+            "apples, pears, or plums"
+        );
+        assert_eq!(HumanList::from(&fruit).concise(), "apples, pears, plums");
+
+        assert_eq!(HumanList::from(&["Alice", "Bob"]).full(), "Alice and Bob");
+        assert_eq!(HumanList::from(&["Alice"]).full(), "Alice");
+        assert_eq!(HumanList::<&str>::from(&[]).full(), "");
+        assert_eq!(HumanList::<&str>::from(&[]).concise(), "");
+
+        // Truncation
+        let people = ["Alice", "Bob", "Carol", "Dave", "Erin"];
+        assert_eq!(
+            HumanList::from(&people).limit(2).full(),
+            "Alice, Bob, and 3 others"
+        );
+        assert_eq!(
+            HumanList::from(&people).limit(2).no_oxford_comma().full(),
+            "Alice, Bob and 3 others"
+        );
+        assert_eq!(
+            HumanList::from(&people).limit(4).full(),
+            "Alice, Bob, Carol, Dave, and 1 other"
+        );
+        assert_eq!(
+            HumanList::from(&people).limit(1).full(),
+            "Alice and 4 others"
+        );
+        assert_eq!(
+            HumanList::from(&people).limit(0).full(),
+            "Alice and 4 others"
+        );
+        assert_eq!(
+            HumanList::from(&people).limit(5).full(),
+            "Alice, Bob, Carol, Dave, and Erin"
+        );
+        assert_eq!(
+            HumanList::from(&people).limit(2).concise(),
+            "Alice, Bob, +3"
+        );
+
+        // Any Display item
+        let numbers = vec![1, 2, 3];
+        assert_eq!(HumanList::from(&numbers).or().to_string(), "1, 2, or 3");
+        let owned = vec![String::from("x"), String::from("y")];
+        assert_eq!(format!("{:#}", HumanList::from(&owned)), "x, y");
+    }
+
+    #[test]
+    fn test_human_money() {
+        let price = HumanMoney::new(1_234_567, Currency::USD);
+        assert_eq!(price.concise(), "$12,345.67");
+        assert_eq!(price.full(), "12,345.67 US dollars");
+        assert_eq!(price.to_string(), "12,345.67 US dollars");
+        assert_eq!(format!("{:#}", price), "$12,345.67");
+        assert_eq!(price.code().concise(), "USD 12,345.67");
+        assert_eq!(price.code().symbol().concise(), "$12,345.67");
+
+        // Per-currency exponent
+        assert_eq!(
+            HumanMoney::new(1_234_567, Currency::JPY).concise(),
+            "¥1,234,567"
+        );
+        assert_eq!(
+            HumanMoney::new(1_234_567, Currency::JPY).full(),
+            "1,234,567 Japanese yen"
+        );
+        assert_eq!(HumanMoney::new(5, Currency::EUR).concise(), "€0.05");
+        assert_eq!(
+            HumanMoney::new(100, Currency::GBP).full(),
+            "1.00 British pound"
+        );
+        assert_eq!(
+            HumanMoney::new(150, Currency::GBP).full(),
+            "1.50 British pounds"
+        );
+        assert_eq!(HumanMoney::new(0, Currency::USD).concise(), "$0.00");
+
+        // Negative amounts
+        let refund = HumanMoney::new(-1_234, Currency::USD);
+        assert_eq!(refund.concise(), "-$12.34");
+        assert_eq!(refund.parentheses().concise(), "($12.34)");
+        assert_eq!(refund.parentheses().full(), "(12.34 US dollars)");
+        assert_eq!(refund.code().concise(), "-USD 12.34");
+        assert_eq!(
+            HumanMoney::new(i64::MIN, Currency::JPY).concise(),
+            "-¥9,223,372,036,854,775,808"
         );
+
+        // Abbreviated, through HumanNumber
+        let revenue = HumanMoney::new(1_234_567_890, Currency::USD).abbreviated();
+        assert_eq!(revenue.concise(), "$12.3M");
+        assert_eq!(revenue.full(), "12.3 million US dollars");
+        assert_eq!(revenue.parentheses().code().concise(), "USD 12.3M");
+        assert_eq!(
+            HumanMoney::new(-1_234_567_890, Currency::USD)
+                .abbreviated()
+                .parentheses()
+                .concise(),
+            "($12.3M)"
+        );
+        assert_eq!(
+            HumanMoney::new(99_999, Currency::USD)
+                .abbreviated()
+                .concise(),
+            "$999.99"
+        );
+    }
+
+    #[test]
+    fn test_human_fraction() {
+        assert_eq!(HumanFraction::from(0.75).concise(), "3/4");
+        assert_eq!(HumanFraction::from(0.75).full(), "three quarters");
+        assert_eq!(HumanFraction::from(0.5).full(), "one half");
+        assert_eq!(HumanFraction::from(0.25).full(), "one quarter");
+        assert_eq!(HumanFraction::from(0.625).concise(), "5/8");
+        assert_eq!(HumanFraction::from(0.625).full(), "five eighths");
+
+        // Exact thirds are detected despite float rounding
+        assert_eq!(HumanFraction::from(1.0 / 3.0).concise(), "1/3");
+        assert_eq!(HumanFraction::from(2.0 / 3.0).full(), "two thirds");
+        assert_eq!(HumanFraction::from(0.333).concise(), "about 1/3");
+        assert_eq!(HumanFraction::from(0.333).full(), "about one third");
+
+        // Mixed numbers
+        assert_eq!(HumanFraction::from(1.5).concise(), "1 1/2");
+        assert_eq!(HumanFraction::from(1.5).full(), "one and a half");
+        assert_eq!(HumanFraction::from(2.75).full(), "two and three quarters");
+        assert_eq!(HumanFraction::from(3.2).full(), "three and a fifth");
+
+        // Whole numbers, rounding up, zero and negatives
+        assert_eq!(HumanFraction::from(2.0).concise(), "2");
+        assert_eq!(HumanFraction::from(2.0).full(), "two");
+        assert_eq!(HumanFraction::from(0.999).concise(), "about 1");
+        assert_eq!(HumanFraction::from(0.0).full(), "zero");
+        assert_eq!(HumanFraction::from(-0.01).concise(), "about 0");
+        assert_eq!(HumanFraction::from(-1.25).concise(), "-1 1/4");
+        assert_eq!(HumanFraction::from(-0.5).full(), "negative one half");
+        assert_eq!(HumanFraction::from(f64::NAN).concise(), "-");
+
+        // Denominator and tolerance
+        assert_eq!(HumanFraction::from(0.1).concise(), "1/10");
+        assert_eq!(
+            HumanFraction::from(0.1).max_denominator(4).concise(),
+            "about 0"
+        );
+        assert_eq!(
+            HumanFraction::from(0.2).max_denominator(4).concise(),
+            "about 1/4"
+        );
+        assert_eq!(HumanFraction::from(0.333).tolerance(0.001).concise(), "1/3");
+        assert_eq!(HumanFraction::from(0.03125).concise(), "about 0");
+        assert_eq!(
+            HumanFraction::from(0.03125).max_denominator(32).full(),
+            "one thirty-second"
+        );
+        assert_eq!(HumanFraction::from(0.75).to_string(), "three quarters");
+        assert_eq!(format!("{:#}", HumanFraction::from(0.75)), "3/4");
+    }
+
+    #[test]
+    fn test_human_date() {
+        use std::time::UNIX_EPOCH;
+
+        // Tuesday 2024-03-12 14:05 UTC
+        let date = HumanDate::from(UNIX_EPOCH + Duration::from_secs(1_710_252_300));
+        assert_eq!(date.concise(), "12 Mar 2024");
+        assert_eq!(date.full(), "March 12, 2024");
+        assert_eq!(date.to_string(), "March 12, 2024");
+        assert_eq!(format!("{:#}", date), "12 Mar 2024");
+        assert_eq!(date.format(DateStyle::Short), "2024-03-12");
+        assert_eq!(date.format(DateStyle::Medium), "12 Mar 2024");
+        assert_eq!(date.format(DateStyle::Long), "March 12, 2024");
+        assert_eq!(date.format(DateStyle::Rfc), "Tue, 12 Mar 2024 14:05");
+
+        // UTC offsets can cross midnight
+        assert_eq!(
+            date.utc_offset(-15 * 60).format(DateStyle::Rfc),
+            "Mon, 11 Mar 2024 23:05"
+        );
+        assert_eq!(
+            date.utc_offset(10 * 60).format(DateStyle::Rfc),
+            "Wed, 13 Mar 2024 00:05"
+        );
+
+        // Leap days, century rules and dates before the epoch
+        let at = |secs: i64| {
+            let time = if secs < 0 {
+                UNIX_EPOCH - Duration::from_secs(secs.unsigned_abs())
+            } else {
+                UNIX_EPOCH + Duration::from_secs(secs as u64)
+            };
+            HumanDate::from(time).format(DateStyle::Short)
+        };
+        assert_eq!(at(0), "1970-01-01");
+        assert_eq!(at(1_709_164_800), "2024-02-29");
+        assert_eq!(at(951_782_400), "2000-02-29");
+        assert_eq!(at(4_107_542_400), "2100-03-01");
+        assert_eq!(at(-1), "1969-12-31");
+        assert_eq!(at(-86_400), "1969-12-31");
+        assert_eq!(at(-2_208_988_800), "1900-01-01");
+        assert_eq!(
+            HumanDate::from(UNIX_EPOCH - Duration::from_millis(500)).format(DateStyle::Rfc),
+            "Wed, 31 Dec 1969 23:59"
+        );
+
+        // Walking day by day never skips or repeats a date
+        let mut previous = civil_from_days(-800_000);
+        for days in -799_999..800_000 {
+            let (year, month, day) = civil_from_days(days);
+            let (p_year, p_month, p_day) = previous;
+            let next_day = (year, month, day) == (p_year, p_month, p_day + 1);
+            let next_month = day == 1 && (year, month) == (p_year, p_month + 1);
+            let next_year = (month, day) == (1, 1) && year == p_year + 1 && p_month == 12;
+            assert!(
+                next_day || next_month || next_year,
+                "{} -> {:?}",
+                days,
+                (year, month, day)
+            );
+            previous = (year, month, day);
+        }
+
+        // The year is only omitted for the current year
+        let today = HumanDate::from(SystemTime::now()).omit_current_year();
+        assert_eq!(today.concise().split(' ').count(), 2);
+        assert!(!today.full().contains(','));
+        assert_eq!(date.omit_current_year().full(), "March 12, 2024");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        use crate::parse::{parse_duration, parse_size};
+        use crate::{ParseError, ParseErrorKind};
+
+        let err = parse_size("12 MiBs").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::UnknownUnit);
+        assert_eq!(err.position(), 3);
+        assert_eq!(err.fragment(), "MiBs");
+        assert!(
+            err.to_string()
+                .starts_with("unknown unit 'MiBs' at position 3 (expected one of B, kB, KiB")
+        );
+
+        let err = parse_size("  ").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::Empty);
+        assert_eq!(err.to_string(), "empty input");
+
+        let err = parse_size(" 1.2.3 GB").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::InvalidNumber);
+        assert_eq!(err.to_string(), "invalid number '1.2.3' at position 1");
+
+        let err = parse_size("MiB").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.position(), err.fragment()),
+            (ParseErrorKind::InvalidNumber, 0, "")
+        );
+
+        let err = parse_size("5 MiB later").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::TrailingInput);
+        assert_eq!(err.to_string(), "unexpected ' later' at position 5");
+
+        let err = parse_size("100 EiB").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::OutOfRange);
+        assert_eq!(err.to_string(), "'100 EiB' is out of range at position 0");
+
+        let err = parse_duration("1h 30x").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::UnknownUnit);
+        assert_eq!(err.position(), 5);
+        assert_eq!(
+            err.to_string(),
+            "unknown unit 'x' at position 5 (expected one of w, d, h, m, s)"
+        );
+
+        let err = parse_duration("1h -5m").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.position()),
+            (ParseErrorKind::InvalidNumber, 3)
+        );
+
+        let err = parse_duration("1e300").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::OutOfRange);
+
+        // Plays well with std error handling
+        let io_err: std::io::Error = parse_size("x").unwrap_err().into();
+        assert_eq!(io_err.kind(), std::io::ErrorKind::InvalidInput);
+        let boxed: Box<dyn std::error::Error + Send + Sync> =
+            parse_size("1 XB").unwrap_err().into();
+        assert!(boxed.downcast_ref::<ParseError>().is_some());
+    }
+
+    #[test]
+    fn test_human_quantity() {
+        assert_eq!(HumanQuantity::new(1_234.0, "W").concise(), "1.2 kW");
+        assert_eq!(HumanQuantity::new(1_234.0, "W").full(), "1.2 kilowatts");
+        assert_eq!(HumanQuantity::new(1_000.0, "W").full(), "1 kilowatt");
+        assert_eq!(HumanQuantity::new(230.0, "V").full(), "230 volts");
+        assert_eq!(HumanQuantity::new(0.0, "V").concise(), "0 V");
+        assert_eq!(
+            HumanQuantity::new(4.2e12, "Wh").full(),
+            "4.2 terawatt-hours"
+        );
+        assert_eq!(HumanQuantity::new(5e15, "W").concise(), "5000 TW");
+        assert_eq!(HumanQuantity::new(999.96, "W").concise(), "1 kW");
+        assert_eq!(HumanQuantity::new(-2_500.0, "J").concise(), "-2.5 kJ");
+
+        // Below one
+        assert_eq!(HumanQuantity::new(0.25, "L").concise(), "250 mL");
+        assert_eq!(HumanQuantity::new(0.25, "L").full(), "250 milliliters");
+        assert_eq!(HumanQuantity::new(4.7e-6, "F").concise(), "4.7 µF");
+        assert_eq!(HumanQuantity::new(3.3e-9, "s").full(), "3.3 nanoseconds");
+        assert_eq!(HumanQuantity::new(1e-12, "F").full(), "1 picofarad");
+        assert_eq!(HumanQuantity::new(0.99996, "A").concise(), "1 A");
+        assert_eq!(HumanQuantity::new(1e-15, "F").concise(), "0 pF");
+
+        // Precision
+        assert_eq!(
+            HumanQuantity::new(1_234.0, "W").precision(3).concise(),
+            "1.234 kW"
+        );
+        assert_eq!(
+            HumanQuantity::new(1_250.0, "W").precision(0).concise(),
+            "1 kW"
+        );
+
+        // Names and plurals
+        assert_eq!(HumanQuantity::new(2.5, "Hz").full(), "2.5 hertz");
+        assert_eq!(HumanQuantity::new(3_000.0, "lx").full(), "3 kilolx");
+        assert_eq!(
+            HumanQuantity::new(3_000.0, "lx")
+                .name("lux")
+                .plural("lux")
+                .full(),
+            "3 kilolux"
+        );
+        assert_eq!(
+            HumanQuantity::new(800.0, "lm").name("lumen").full(),
+            "800 lumens"
+        );
+        assert_eq!(
+            HumanQuantity::new(1.0, "lm").name("lumen").full(),
+            "1 lumen"
+        );
+        let unit = String::from("cd");
+        assert_eq!(HumanQuantity::new(12.0, unit).concise(), "12 cd");
+
+        assert_eq!(HumanQuantity::new(f64::NAN, "W").concise(), "-");
+        assert_eq!(
+            HumanQuantity::new(1_234.0, "W").to_string(),
+            "1.2 kilowatts"
+        );
+        assert_eq!(format!("{:#}", HumanQuantity::new(1_234.0, "W")), "1.2 kW");
+
+        // Huge precisions are capped instead of overflowing
+        assert_eq!(
+            HumanQuantity::new(1.5, "W").precision(400).concise(),
+            "1.5 W"
+        );
+        assert_eq!(
+            HumanQuantity::new(1e30, "W")
+                .precision(usize::MAX)
+                .concise(),
+            "1000000000000000000 TW"
+        );
+    }
+
+    #[test]
+    fn test_human_plural() {
+        assert_eq!(HumanPlural::from(3, "file").concise(), "3 files");
+        assert_eq!(HumanPlural::from(1, "file").concise(), "1 file");
+        assert_eq!(HumanPlural::from(0, "file").concise(), "0 files");
+        assert_eq!(HumanPlural::from(-1, "degree").concise(), "-1 degree");
+        assert_eq!(HumanPlural::from(1_200, "file").concise(), "1200 files");
+        assert_eq!(
+            HumanPlural::from(1_200, "file").full(),
+            "1.2 thousand files"
+        );
+        assert_eq!(HumanPlural::from(1_000, "file").full(), "1 thousand files");
+        assert_eq!(HumanPlural::from(1, "file").full(), "1 file");
+
+        // Regular spelling rules
+        assert_eq!(HumanPlural::from(2, "box").concise(), "2 boxes");
+        assert_eq!(HumanPlural::from(2, "match").concise(), "2 matches");
+        assert_eq!(HumanPlural::from(2, "bus").concise(), "2 buses");
+        assert_eq!(HumanPlural::from(2, "city").concise(), "2 cities");
+        assert_eq!(HumanPlural::from(2, "day").concise(), "2 days");
+        assert_eq!(HumanPlural::from(2, "Key").concise(), "2 Keys");
+
+        // Irregular plurals and noun-only output
+        assert_eq!(
+            HumanPlural::from_pair(2, "index", "indices").concise(),
+            "2 indices"
+        );
+        assert_eq!(
+            HumanPlural::from_pair(1, "index", "indices").concise(),
+            "1 index"
+        );
+        assert_eq!(
+            HumanPlural::from_pair(5, "child", "children")
+                .without_count()
+                .concise(),
+            "children"
+        );
+        assert_eq!(HumanPlural::from(1, "file").without_count().full(), "file");
+        let noun = String::from("commit");
+        assert_eq!(HumanPlural::from(4, noun).to_string(), "4 commits");
+        assert_eq!(
+            format!("{:#}", HumanPlural::from(4_500, "row")),
+            "4500 rows"
+        );
+    }
+
+    #[test]
+    fn test_config() {
+        use crate::{Config, SuffixCase, UnitSystem};
+
+        // The default configuration changes nothing
+        assert_eq!(Config::current(), Config::default());
+        Config::with(Config::default(), |_| {
+            assert_eq!(HumanNumber::from(1_500).concise(), "1.5k");
+            assert_eq!(HumanSize::from(1_000_000).concise(), "976.6 KiB");
+            assert_eq!(HumanPercent::from(12.345, 1).to_string(), "12.3 percent");
+        });
+        // Installing the default globally is indistinguishable from not doing so
+        assert!(Config::set_global(Config::default()).is_ok());
+        assert!(Config::set_global(Config::default()).is_err());
+
+        let config = Config::default()
+            .decimal_separator(',')
+            .suffix_case(SuffixCase::Upper)
+            .unit_system(UnitSystem::Decimal);
+        Config::with(config, |cfg| {
+            assert_eq!(*cfg, config);
+            assert_eq!(HumanNumber::from(1_500).concise(), "1,5K");
+            assert_eq!(HumanNumber::from(1_500_000).full(), "1,5 million");
+            assert_eq!(HumanSize::from(1_500_000).concise(), "1,5 MB");
+            assert_eq!(HumanPercent::from(12.345, 1).concise(), "12,3%");
+            assert_eq!(
+                HumanBitrate::from_bits_per_sec(2_400_000).concise(),
+                "2,4 Mbps"
+            );
+
+            // Per-value options win over the configuration
+            assert_eq!(HumanSize::from(1_000_000).binary().concise(), "976,6 KiB");
+            assert_eq!(
+                HumanNumber::from(1_500).locale(Locale::English).concise(),
+                "1.5K"
+            );
+            assert_eq!(
+                HumanNumber::from(1_500).locale(Locale::English).full(),
+                "1.5 thousand"
+            );
+
+            // Scopes nest and restore the outer configuration
+            Config::with(Config::default().suffix_case(SuffixCase::Lower), |_| {
+                assert_eq!(HumanNumber::from(2_000_000).concise(), "2m");
+            });
+            assert_eq!(HumanNumber::from(2_000_000).concise(), "2M");
+        });
+        assert_eq!(HumanNumber::from(1_500).concise(), "1.5k");
+
+        // Restored even if the closure panics
+        let result = std::panic::catch_unwind(|| {
+            Config::with(config, |_| panic!("boom"));
+        });
+        assert!(result.is_err());
+        assert_eq!(Config::current(), Config::default());
+    }
+
+    #[test]
+    fn test_derives() {
+        use crate::{HumanDate, HumanQuantity, ParseErrorKind};
+        use std::time::UNIX_EPOCH;
+
+        // Plain data: can be stored, cloned, compared and debugged
+        #[derive(Clone, Debug, PartialEq, Default)]
+        struct Row {
+            size: HumanSize,
+            elapsed: HumanTime,
+            seen: HumanDuration,
+            count: HumanNumber,
+            mode: HumanPermissions,
+        }
+
+        let row = Row {
+            size: HumanSize::from(1_024).decimal(),
+            elapsed: HumanTime::from(Duration::from_secs(90)),
+            ..Row::default()
+        };
+        let copy = row.clone();
+        assert_eq!(row, copy);
+        // Sizes compare by byte count, whatever their unit system
+        assert_eq!(row.size, HumanSize::from(1_024));
+        assert_ne!(row.size, HumanSize::from(1_000).decimal());
+        assert_eq!(
+            HumanSize::from(1_024).binary(),
+            HumanSize::from(1_024).binary()
+        );
+
+        // Defaults
+        assert_eq!(HumanNumber::default().concise(), "0");
+        assert_eq!(HumanSize::default().concise(), "0 B");
+        assert_eq!(HumanTime::default().concise(), "0s");
+        assert_eq!(HumanDuration::default().concise(), "-");
+        assert_eq!(HumanPermissions::default().concise(), "---------");
+
+        // Debug shows the value and its settings, not the rendered text
+        assert_eq!(
+            format!("{:?}", HumanSize::from(1_024).decimal()),
+            "HumanSize { bytes: 1024, negative: false, system: Some(Decimal), unit: None, locale: None, bits: false, precision: 1, keep_trailing_zeros: false, compact_spacing: false, bare_bytes: false }"
+        );
+        assert_eq!(
+            format!("{:?}", HumanPercent::from(12.5, 1)),
+            "HumanPercent { value: 12.5, exact: None, decimals: 1, locale: None, fallback: \"-\", trim_zeros: false, clamp: false, signed: false, zero_sign: \"\", scale: Percent, bar_fill: '█', bar_empty: '░', bare_bar: false }"
+        );
+        assert_eq!(
+            format!("{:?}", HumanOrdinal::from(3)),
+            "HumanOrdinal { number: 3 }"
+        );
+        assert_eq!(
+            format!("{:?}", HumanList::from(&["a", "b"])),
+            r#"HumanList { items: ["a", "b"], conjunction: "and", oxford_comma: true, limit: None }"#
+        );
+
+        // Every public type is Clone + Debug + PartialEq
+        fn plain_data<T: Clone + std::fmt::Debug + PartialEq>(value: T) -> T {
+            let copy = value.clone();
+            assert_eq!(copy, value);
+            copy
+        }
+        plain_data(HumanPercent::from(1.0, 1));
+        plain_data(HumanBitrate::from_bits_per_sec(1));
+        plain_data(HumanFrequency::from_hz(1.0));
+        plain_data(HumanFraction::from(0.5));
+        plain_data(HumanDate::from(UNIX_EPOCH));
+        plain_data(HumanMoney::new(1, Currency::USD));
+        plain_data(HumanPlural::from(1, "file"));
+        plain_data(HumanQuantity::new(1.0, "W"));
+        plain_data(HumanList::from(&[1, 2]));
+        plain_data(ParseErrorKind::Empty);
+    }
+
+    #[test]
+    fn test_human_range() {
+        // Sizes share the larger endpoint's unit
+        assert_eq!(
+            HumanRange::sizes(1_610_612_736, 2_147_483_648).concise(),
+            "1.5–2.0 GiB"
+        );
+        assert_eq!(
+            HumanRange::sizes(1_610_612_736, 2_147_483_648).full(),
+            "1.5–2.0 gibibytes"
+        );
+        assert_eq!(
+            HumanRange::sizes(512 * 1024, 2 * 1024 * 1024).concise(),
+            "0.5–2.0 MiB"
+        );
+        assert_eq!(
+            HumanRange::sizes(1_000_000, 3_000_000).decimal().concise(),
+            "1–3 MB"
+        );
+        assert_eq!(HumanRange::sizes(100, 512).concise(), "100–512 B");
+        assert_eq!(HumanRange::sizes(100, 512).full(), "100–512 bytes");
+        assert_eq!(
+            HumanRange::sizes(1023, 1_048_000).concise(),
+            "1.0–1023.4 KiB"
+        );
+
+        // Durations
+        let mins = |m: u64| Duration::from_secs(m * 60);
+        assert_eq!(HumanRange::times(mins(3), mins(5)).full(), "3–5 minutes");
+        assert_eq!(HumanRange::times(mins(3), mins(5)).concise(), "3–5m");
+        assert_eq!(
+            HumanRange::times(mins(30), mins(90)).full(),
+            "0.5–1.5 hours"
+        );
+        assert_eq!(
+            HumanRange::times(Duration::from_secs(10), Duration::from_secs(45)).concise(),
+            "10–45s"
+        );
+        assert_eq!(HumanRange::times(mins(60), mins(60)).full(), "1 hour");
+
+        // Numbers
+        assert_eq!(HumanRange::numbers(10_000, 20_000).concise(), "10k–20k");
+        assert_eq!(HumanRange::numbers(10_000, 20_000).full(), "10–20 thousand");
+        assert_eq!(
+            HumanRange::numbers(500, 1_500_000).full(),
+            "0.0–1.5 million"
+        );
+        assert_eq!(HumanRange::numbers(3, 5).full(), "3–5");
+
+        // Order, collapsing and separators
+        assert_eq!(HumanRange::numbers(20_000, 10_000).concise(), "10k–20k");
+        assert_eq!(HumanRange::numbers(7, 7).concise(), "7");
+        assert_eq!(HumanRange::sizes(2048, 2048).concise(), "2 KiB");
+        assert_eq!(
+            HumanRange::times(mins(3), mins(5)).ascii().concise(),
+            "3-5m"
+        );
+        assert_eq!(
+            HumanRange::numbers(10_000, 20_000).ascii().concise(),
+            "10k-20k"
+        );
+        assert_eq!(
+            HumanRange::times(mins(3), mins(5)).to_string(),
+            "3–5 minutes"
+        );
+        assert_eq!(format!("{:#}", HumanRange::numbers(1, 2)), "1–2");
+    }
+
+    #[test]
+    fn test_human_delta() {
+        assert_eq!(
+            HumanDelta::sizes(36_700_160, 37_958_451).concise(),
+            "+1.2 MiB (+3.4%)"
+        );
+        assert_eq!(
+            HumanDelta::sizes(36_700_160, 37_958_451).full(),
+            "+1.2 mebibytes (+3.4 percent)"
+        );
+        assert_eq!(
+            HumanDelta::numbers(187_500, 172_500).concise(),
+            "−15k (−8%)"
+        );
+        assert_eq!(
+            HumanDelta::numbers(187_500, 172_500).ascii().concise(),
+            "-15k (-8%)"
+        );
+        assert_eq!(HumanDelta::numbers(-50, 25).concise(), "+75 (+150%)");
+
+        // Old value of zero and no change
+        assert_eq!(HumanDelta::numbers(0, 1_200).concise(), "+1.2k");
+        assert_eq!(HumanDelta::sizes(0, 0).concise(), "±0 B");
+        assert_eq!(HumanDelta::numbers(42, 42).concise(), "±0 (0%)");
+        assert_eq!(HumanDelta::sizes(5_000, 1_000).concise(), "−3.9 KiB (−80%)");
+
+        // Hiding either half
+        let delta = HumanDelta::numbers(200, 250);
+        assert_eq!(delta.absolute_only().concise(), "+50");
+        assert_eq!(delta.relative_only().concise(), "+25%");
+        assert_eq!(delta.relative_only().full(), "+25 percent");
+        assert_eq!(HumanDelta::numbers(0, 5).relative_only().concise(), "");
+
+        assert_eq!(delta.to_string(), "+50 (+25 percent)");
+        assert_eq!(format!("{:#}", delta), "+50 (+25%)");
+    }
+
+    #[test]
+    fn test_align() {
+        use crate::{Config, HumanQuantity, Style, align, align_decimal};
+
+        let sizes = [
+            HumanSize::from(1_000_000),
+            HumanSize::from(5_242_880),
+            HumanSize::from(512),
+        ];
+        assert_eq!(
+            align(&sizes, Style::Concise),
+            ["976.6 KiB", "    5 MiB", "  512 B  "]
+        );
+        assert_eq!(
+            align_decimal(&sizes, Style::Concise),
+            ["976.6 KiB", "  5   MiB", "512   B  "]
+        );
+        assert_eq!(
+            align(&sizes, Style::Full),
+            ["976.6 kibibytes", "    5 mebibytes", "  512 bytes    "]
+        );
+
+        // Signs, prefixes and values without a number
+        let moods = [
+            HumanDelta::numbers(100, 90).ascii(),
+            HumanDelta::numbers(100, 1_300).ascii(),
+        ];
+        assert_eq!(
+            align(&moods, Style::Concise),
+            [" -10 (-10%)   ", "+1.2k (+1200%)"]
+        );
+        let prices = [
+            HumanMoney::new(1_234_567, Currency::USD),
+            HumanMoney::new(99, Currency::USD),
+        ];
+        assert_eq!(align(&prices, Style::Concise), ["$12,345.67", "     $0.99"]);
+        let times = [
+            HumanDuration::from(None),
+            HumanDuration::from(Some(SystemTime::now())),
+        ];
+        assert_eq!(align(&times, Style::Concise), ["       -", "just now"]);
+
+        // Every row has the same width, even with multi-byte units
+        let rows = align(
+            &[
+                HumanQuantity::new(4.7e-6, "F"),
+                HumanQuantity::new(220.0, "F"),
+            ],
+            Style::Concise,
+        );
+        assert_eq!(rows, ["4.7 µF", "220 F "]);
+        assert!(align::<HumanSize>(&[], Style::Concise).is_empty());
+
+        Config::with(Config::default().decimal_separator(','), |_| {
+            let numbers = [HumanNumber::from(1_500), HumanNumber::from(25_000)];
+            assert_eq!(align_decimal(&numbers, Style::Concise), [" 1,5k", "25  k"]);
+        });
+    }
+
+    #[test]
+    fn test_human_distance() {
+        assert_eq!(HumanDistance::from_meters(750.0).concise(), "750 m");
+        assert_eq!(HumanDistance::from_meters(1_200.0).concise(), "1.2 km");
+        assert_eq!(HumanDistance::from_meters(1_200.0).full(), "1.2 kilometers");
+        assert_eq!(
+            HumanDistance::from_meters(1_200.0)
+                .british_spelling()
+                .full(),
+            "1.2 kilometres"
+        );
+        assert_eq!(HumanDistance::from_meters(1.0).full(), "1 meter");
+        assert_eq!(HumanDistance::from_meters(0.25).concise(), "25 cm");
+        assert_eq!(HumanDistance::from_meters(0.004).concise(), "4 mm");
+        assert_eq!(HumanDistance::from_meters(0.0).concise(), "0 m");
+
+        // Rounding happens before the unit is picked
+        assert_eq!(HumanDistance::from_meters(999.96).concise(), "1 km");
+        assert_eq!(HumanDistance::from_meters(960.0).concise(), "960 m");
+        assert_eq!(HumanDistance::from_meters(0.99996).concise(), "1 m");
+        assert_eq!(HumanDistance::from_meters(0.0094).concise(), "9.4 mm");
+        assert_eq!(HumanDistance::from_meters(0.00999).concise(), "1 cm");
+
+        // Imperial
+        assert_eq!(
+            HumanDistance::from_meters(1_200.0).imperial().concise(),
+            "0.7 mi"
+        );
+        assert_eq!(
+            HumanDistance::from_meters(1_200.0)
+                .imperial()
+                .precision(2)
+                .concise(),
+            "0.75 mi"
+        );
+        assert_eq!(
+            HumanDistance::from_meters(1_609.344).imperial().full(),
+            "1 mile"
+        );
+        assert_eq!(
+            HumanDistance::from_meters(42_195.0).imperial().full(),
+            "26.2 miles"
+        );
+        assert_eq!(
+            HumanDistance::from_meters(100.0).imperial().concise(),
+            "109.4 yd"
+        );
+        assert_eq!(
+            HumanDistance::from_meters(0.3048).imperial().full(),
+            "1 foot"
+        );
+        assert_eq!(HumanDistance::from_meters(0.6).imperial().full(), "2 feet");
+        assert_eq!(HumanDistance::from_meters(0.0).imperial().concise(), "0 ft");
+        assert_eq!(
+            HumanDistance::from_meters(160.0).imperial().concise(),
+            "175 yd"
+        );
+        assert_eq!(
+            HumanDistance::from_meters(161.0).imperial().concise(),
+            "0.1 mi"
+        );
+        assert_eq!(
+            HumanDistance::from_meters(1_200.0)
+                .imperial()
+                .metric()
+                .concise(),
+            "1.2 km"
+        );
+
+        // Precision and placeholders
+        assert_eq!(
+            HumanDistance::from_meters(1_234.0).precision(3).concise(),
+            "1.234 km"
+        );
+        assert_eq!(HumanDistance::from_meters(-5.0).concise(), "-");
+        assert_eq!(HumanDistance::from_meters(f64::INFINITY).full(), "-");
+        assert_eq!(
+            HumanDistance::from_meters(1_200.0).to_string(),
+            "1.2 kilometers"
+        );
+        assert_eq!(
+            format!("{:#}", HumanDistance::from_meters(1_200.0)),
+            "1.2 km"
+        );
+
+        // Huge precisions are capped instead of overflowing
+        let distance = HumanDistance::from_meters(1_234.5);
+        assert_eq!(distance.precision(400), distance.precision(300));
+        assert_eq!(
+            HumanDistance::from_meters(1e15)
+                .precision(usize::MAX)
+                .concise(),
+            "1000000000000 km"
+        );
+    }
+
+    #[test]
+    fn test_human_mass() {
+        assert_eq!(HumanMass::from_grams(250.0).concise(), "250 g");
+        assert_eq!(HumanMass::from_grams(1_200.0).concise(), "1.2 kg");
+        assert_eq!(HumanMass::from_grams(1_200.0).full(), "1.2 kilograms");
+        assert_eq!(HumanMass::from_grams(3_400_000.0).concise(), "3.4 t");
+        assert_eq!(HumanMass::from_grams(1_000_000.0).full(), "1 tonne");
+        assert_eq!(HumanMass::from_grams(1.0).full(), "1 gram");
+        assert_eq!(HumanMass::from_grams(0.25).concise(), "250 mg");
+        assert_eq!(HumanMass::from_grams(0.0).concise(), "0 g");
+
+        // Metric boundaries, after rounding
+        assert_eq!(HumanMass::from_grams(999.94).concise(), "999.9 g");
+        assert_eq!(HumanMass::from_grams(999.96).concise(), "1 kg");
+        assert_eq!(HumanMass::from_grams(960.0).concise(), "960 g");
+        assert_eq!(HumanMass::from_grams(0.99949).concise(), "999.5 mg");
+        assert_eq!(HumanMass::from_grams(0.99996).concise(), "1 g");
+        assert_eq!(HumanMass::from_grams(999_960.0).concise(), "1 t");
+
+        // Imperial
+        assert_eq!(HumanMass::from_grams(250.0).imperial().concise(), "8.8 oz");
+        assert_eq!(
+            HumanMass::from_grams(1_200.0).imperial().concise(),
+            "2.6 lb"
+        );
+        assert_eq!(
+            HumanMass::from_grams(28.349_523_125).imperial().full(),
+            "1 ounce"
+        );
+        assert_eq!(
+            HumanMass::from_grams(453.592_37).imperial().full(),
+            "1 pound"
+        );
+        assert_eq!(
+            HumanMass::from_grams(1_000.0).imperial().full(),
+            "2.2 pounds"
+        );
+        assert_eq!(HumanMass::from_grams(452.0).imperial().concise(), "15.9 oz");
+        assert_eq!(HumanMass::from_grams(453.0).imperial().concise(), "1 lb");
+        assert_eq!(HumanMass::from_grams(0.0).imperial().concise(), "0 oz");
+        assert_eq!(
+            HumanMass::from_grams(1_200.0).imperial().metric().concise(),
+            "1.2 kg"
+        );
+
+        // Pounds and ounces
+        let split = |grams: f64| {
+            HumanMass::from_grams(grams)
+                .pounds_and_ounces()
+                .precision(0)
+        };
+        assert_eq!(split(1_190.0).concise(), "2 lb 10 oz");
+        assert_eq!(split(1_190.0).full(), "2 pounds 10 ounces");
+        assert_eq!(split(481.0).full(), "1 pound 1 ounce");
+        assert_eq!(split(907.0).concise(), "2 lb");
+        assert_eq!(split(453.0).concise(), "1 lb");
+        assert_eq!(split(250.0).concise(), "9 oz");
+        assert_eq!(
+            HumanMass::from_grams(1_190.0).pounds_and_ounces().concise(),
+            "2 lb 10 oz"
+        );
+        assert_eq!(
+            HumanMass::from_grams(1_200.0).pounds_and_ounces().concise(),
+            "2 lb 10.3 oz"
+        );
+
+        // Precision and placeholders
+        assert_eq!(
+            HumanMass::from_grams(1_234.0).precision(3).concise(),
+            "1.234 kg"
+        );
+        assert_eq!(HumanMass::from_grams(-5.0).concise(), "-");
+        assert_eq!(HumanMass::from_grams(f64::NAN).full(), "-");
+        assert_eq!(HumanMass::from_grams(1_200.0).to_string(), "1.2 kilograms");
+        assert_eq!(format!("{:#}", HumanMass::from_grams(1_200.0)), "1.2 kg");
+
+        // Huge precisions are capped instead of overflowing
+        let mass = HumanMass::from_grams(1_234.5);
+        assert_eq!(mass.precision(400), mass.precision(300));
+        assert_eq!(
+            HumanMass::from_grams(1e15).precision(usize::MAX).concise(),
+            "1000000000 t"
+        );
+        assert_eq!(
+            HumanMass::from_grams(1e15)
+                .pounds_and_ounces()
+                .precision(usize::MAX)
+                .concise(),
+            "2204622621848 lb 12.4140625 oz"
+        );
+    }
+
+    #[test]
+    fn test_human_temperature() {
+        assert_eq!(HumanTemperature::from_celsius(23.0).concise(), "23°C");
+        assert_eq!(
+            HumanTemperature::from_celsius(23.0).full(),
+            "23 degrees Celsius"
+        );
+        assert_eq!(
+            HumanTemperature::from_celsius(1.0).full(),
+            "1 degree Celsius"
+        );
+        assert_eq!(HumanTemperature::from_fahrenheit(73.0).concise(), "73°F");
+        assert_eq!(HumanTemperature::from_kelvin(296.0).concise(), "296 K");
+        assert_eq!(HumanTemperature::from_kelvin(296.0).full(), "296 kelvins");
+        assert_eq!(HumanTemperature::from_kelvin(1.0).full(), "1 kelvin");
+
+        // Anchor points convert exactly
+        assert_eq!(
+            HumanTemperature::from_celsius(0.0).fahrenheit().concise(),
+            "32°F"
+        );
+        assert_eq!(
+            HumanTemperature::from_fahrenheit(32.0).celsius().concise(),
+            "0°C"
+        );
+        assert_eq!(
+            HumanTemperature::from_celsius(100.0).fahrenheit().concise(),
+            "212°F"
+        );
+        assert_eq!(
+            HumanTemperature::from_fahrenheit(212.0).celsius().concise(),
+            "100°C"
+        );
+        assert_eq!(
+            HumanTemperature::from_celsius(-40.0).fahrenheit().concise(),
+            "-40°F"
+        );
+        assert_eq!(
+            HumanTemperature::from_fahrenheit(-40.0).celsius().concise(),
+            "-40°C"
+        );
+        assert_eq!(
+            HumanTemperature::from_celsius(0.0).kelvin().concise(),
+            "273.2 K"
+        );
+        assert_eq!(
+            HumanTemperature::from_celsius(0.0)
+                .kelvin()
+                .precision(2)
+                .concise(),
+            "273.15 K"
+        );
+        assert_eq!(
+            HumanTemperature::from_kelvin(273.15).celsius().concise(),
+            "0°C"
+        );
+        assert_eq!(
+            HumanTemperature::from_kelvin(0.0)
+                .fahrenheit()
+                .precision(2)
+                .concise(),
+            "-459.67°F"
+        );
+        assert_eq!(
+            HumanTemperature::from_celsius(23.0).fahrenheit().concise(),
+            "73.4°F"
+        );
+
+        // Negative values
+        assert_eq!(HumanTemperature::from_celsius(-5.5).concise(), "-5.5°C");
+        assert_eq!(
+            HumanTemperature::from_celsius(-1.0).full(),
+            "-1 degree Celsius"
+        );
+        assert_eq!(HumanTemperature::from_celsius(-0.04).concise(), "0°C");
+        assert_eq!(HumanTemperature::from_celsius(-300.0).concise(), "-");
+        assert_eq!(HumanTemperature::from_kelvin(-1.0).concise(), "-");
+        assert_eq!(HumanTemperature::from_celsius(f64::NAN).concise(), "-");
+
+        // ASCII fallback and precision
+        assert_eq!(
+            HumanTemperature::from_celsius(23.0).ascii().concise(),
+            "23 C"
+        );
+        assert_eq!(
+            HumanTemperature::from_fahrenheit(73.0).ascii().concise(),
+            "73 F"
+        );
+        assert_eq!(
+            HumanTemperature::from_kelvin(296.0).ascii().concise(),
+            "296 K"
+        );
+
+        // Huge precisions are capped instead of overflowing
+        let celsius = HumanTemperature::from_celsius(23.5);
+        assert_eq!(celsius.precision(400), celsius.precision(300));
+        assert_eq!(
+            HumanTemperature::from_celsius(1e10)
+                .precision(usize::MAX)
+                .concise(),
+            "10000000000°C"
+        );
+        assert_eq!(HumanTemperature::from_celsius(23.46).concise(), "23.5°C");
+        assert_eq!(
+            HumanTemperature::from_celsius(23.46).precision(0).concise(),
+            "23°C"
+        );
+        assert_eq!(
+            HumanTemperature::from_celsius(23.0).to_string(),
+            "23 degrees Celsius"
+        );
+        assert_eq!(
+            format!("{:#}", HumanTemperature::from_celsius(23.0)),
+            "23°C"
+        );
+    }
+
+    #[test]
+    fn test_human_age() {
+        use std::time::UNIX_EPOCH;
+
+        let day = |days: u64| UNIX_EPOCH + Duration::from_secs(days * 86_400);
+        let age = |birth: u64, now: u64| HumanAge::from(day(birth)).as_of(day(now));
+
+        // Born 1990-06-15; the age goes up on the birthday itself
+        assert_eq!(age(7_470, 19_888).full(), "33 years old"); // 2024-06-14
+        assert_eq!(age(7_470, 19_889).full(), "34 years old"); // 2024-06-15
+        assert_eq!(age(7_470, 19_889).concise(), "34y");
+        assert_eq!(age(7_470, 19_889).to_string(), "34 years old");
+        assert_eq!(format!("{:#}", age(7_470, 19_889)), "34y");
+
+        // Born 2024-02-29: a year older on 1 March in common years
+        assert_eq!(age(19_782, 20_147).full(), "11 months old"); // 2025-02-28
+        assert_eq!(age(19_782, 20_148).full(), "12 months old"); // 2025-03-01
+        assert_eq!(age(19_782, 20_512).full(), "23 months old"); // 2026-02-28
+        assert_eq!(age(19_782, 20_513).full(), "2 years old"); // 2026-03-01
+        assert_eq!(age(19_782, 21_243).concise(), "4y"); // 2028-02-29
+
+        // Born 2024-01-31: months are counted on the calendar
+        assert_eq!(age(19_753, 19_813).full(), "2 months old"); // 2024-03-31
+        assert_eq!(age(19_753, 19_813).concise(), "2mo");
+        assert_eq!(age(19_753, 19_783).full(), "4 weeks old"); // 2024-03-01
+
+        // Born 2024-04-01
+        assert_eq!(age(19_814, 19_833).full(), "2 weeks old"); // 2024-04-20
+        assert_eq!(age(19_814, 19_833).concise(), "2w");
+        assert_eq!(age(19_814, 19_824).full(), "10 days old"); // 2024-04-11
+        assert_eq!(age(19_814, 19_815).full(), "1 day old");
+        assert_eq!(age(19_814, 19_814).concise(), "0d");
+
+        // Future birth dates
+        assert_eq!(age(19_840, 19_833).full(), "not yet born");
+        assert_eq!(age(19_840, 19_833).concise(), "-");
+
+        // Birthdays follow the given calendar: 00:30 UTC is still the day
+        // before an hour west of UTC
+        let birth = day(19_814) + Duration::from_secs(30 * 60);
+        let now = day(19_814) + Duration::from_secs(12 * 3_600);
+        assert_eq!(HumanAge::from(birth).as_of(now).full(), "0 days old");
+        assert_eq!(
+            HumanAge::from(birth).as_of(now).utc_offset(-60).full(),
+            "1 day old"
+        );
+
+        // Without `as_of`, the current time is used
+        assert_eq!(
+            HumanAge::from(day(7_470)).concise().chars().last(),
+            Some('y')
+        );
+    }
+
+    #[test]
+    fn test_human_time_of_day() {
+        let at = HumanTimeOfDay::from_hms;
+
+        assert_eq!(at(15, 15, 0).full(), "quarter past three");
+        assert_eq!(at(15, 15, 0).concise(), "3:15 pm");
+        assert_eq!(at(15, 15, 0).twenty_four_hour().concise(), "15:15");
+        assert_eq!(at(15, 15, 0).to_string(), "quarter past three");
+        assert_eq!(format!("{:#}", at(15, 15, 0)), "3:15 pm");
+
+        // Rounded to the nearest five minutes
+        assert_eq!(at(15, 17, 0).full(), "quarter past three");
+        assert_eq!(at(15, 17, 0).concise(), "3:17 pm");
+        assert_eq!(at(15, 17, 40).full(), "twenty past three");
+        assert_eq!(at(15, 2, 29).full(), "three o'clock");
+        assert_eq!(at(15, 5, 0).full(), "five past three");
+        assert_eq!(at(15, 25, 0).full(), "twenty-five past three");
+
+        // "past" flips to "to" after half past
+        assert_eq!(at(9, 30, 0).full(), "half past nine");
+        assert_eq!(at(9, 35, 0).full(), "twenty-five to ten");
+        assert_eq!(at(9, 45, 0).full(), "quarter to ten");
+        assert_eq!(at(9, 58, 0).full(), "ten o'clock");
+
+        // Midnight and noon
+        assert_eq!(at(0, 0, 0).full(), "midnight");
+        assert_eq!(at(12, 0, 0).full(), "noon");
+        assert_eq!(at(23, 58, 0).full(), "midnight");
+        assert_eq!(at(11, 45, 0).full(), "quarter to noon");
+        assert_eq!(at(0, 10, 0).full(), "ten past midnight");
+        assert_eq!(at(0, 0, 0).concise(), "12:00 am");
+        assert_eq!(at(12, 0, 0).concise(), "12:00 pm");
+        assert_eq!(at(0, 5, 0).twenty_four_hour().concise(), "00:05");
+
+        // Exact minutes
+        assert_eq!(at(15, 17, 0).exact().full(), "seventeen minutes past three");
+        assert_eq!(at(15, 59, 0).exact().full(), "one minute to four");
+        assert_eq!(at(15, 1, 0).exact().full(), "one minute past three");
+        assert_eq!(at(15, 20, 0).exact().full(), "twenty past three");
+
+        // Parts of the day
+        assert_eq!(
+            at(21, 30, 0).day_period().full(),
+            "half past nine in the evening"
+        );
+        assert_eq!(
+            at(9, 30, 0).day_period().full(),
+            "half past nine in the morning"
+        );
+        assert_eq!(
+            at(15, 0, 0).day_period().full(),
+            "three o'clock in the afternoon"
+        );
+        assert_eq!(at(2, 0, 0).day_period().full(), "two o'clock at night");
+        assert_eq!(at(12, 0, 0).day_period().full(), "noon");
+
+        // From a SystemTime: 2024-03-12 14:05 UTC
+        let time = std::time::UNIX_EPOCH + Duration::from_secs(1_710_252_300);
+        assert_eq!(
+            HumanTimeOfDay::from_system_time(time, 0).concise(),
+            "2:05 pm"
+        );
+        assert_eq!(
+            HumanTimeOfDay::from_system_time(time, -5 * 60).full(),
+            "five past nine"
+        );
+
+        // Out of range
+        assert_eq!(at(24, 0, 0).full(), "-");
+        assert_eq!(at(12, 60, 0).concise(), "-");
+    }
+
+    #[test]
+    fn test_parts() {
+        use crate::core::Style;
+
+        let parts = HumanNumber::from(-1_500).into_parts(Style::Concise);
+        assert_eq!(
+            parts,
+            Parts {
+                prefix: String::new(),
+                sign: "-".to_string(),
+                number: "1.5".to_string(),
+                unit: "k".to_string(),
+                qualifier: String::new(),
+            }
+        );
+        assert_eq!(
+            HumanNumber::from(2_000_000).into_parts(Style::Full).unit,
+            " million"
+        );
+        assert_eq!(
+            HumanSize::from(1_000_000).into_parts(Style::Concise).unit,
+            " KiB"
+        );
+        assert_eq!(
+            HumanPercent::from(12.5, 1).into_parts(Style::Concise).unit,
+            "%"
+        );
+        assert_eq!(
+            HumanNumber::from(1_500_000)
+                .locale(Locale::Spanish)
+                .into_parts(Style::Full)
+                .number,
+            "1,5"
+        );
+
+        let ago = SystemTime::now() - Duration::from_secs(300);
+        let parts = HumanDuration::from(Some(ago)).into_parts(Style::Full);
+        assert_eq!(
+            (parts.number.as_str(), parts.unit.as_str()),
+            ("5", " minutes")
+        );
+        assert_eq!(parts.qualifier, " ago");
+        let parts = HumanDuration::from(Some(ago)).into_parts(Style::Concise);
+        assert_eq!(
+            (parts.unit.as_str(), parts.qualifier.as_str()),
+            ("m", " ago")
+        );
+        let parts = HumanDuration::from(Some(ago))
+            .locale(Locale::Spanish)
+            .into_parts(Style::Full);
+        assert_eq!(
+            (parts.prefix.as_str(), parts.unit.as_str()),
+            ("hace ", " minutos")
+        );
+        let parts = HumanDuration::from(None).into_parts(Style::Full);
+        assert_eq!((parts.number.as_str(), parts.qualifier.as_str()), ("", "-"));
+
+        let parts = HumanTime::from(Duration::from_secs(3_605)).into_parts(Style::Concise);
+        let units: Vec<_> = parts.iter().map(|p| p.unit.as_str()).collect();
+        assert_eq!(units, ["h", "m", "s"]);
+        assert_eq!(parts[1].prefix, " ");
+
+        // Parts always join back into the formatted string
+        let now = SystemTime::now();
+        for style in [Style::Concise, Style::Full] {
+            for locale in [Locale::English, Locale::Spanish] {
+                for n in [0.0, -0.5, 999.0, -1_500.0, 2_000_000.0, 1e19] {
+                    let number = HumanNumber::from(n).locale(locale);
+                    let mut expected = String::new();
+                    number.write_into(&mut expected, style).unwrap();
+                    assert_eq!(number.into_parts(style).to_string(), expected);
+
+                    let percent = HumanPercent::from(n, 2).locale(locale);
+                    let mut expected = String::new();
+                    percent.write_into(&mut expected, style).unwrap();
+                    assert_eq!(percent.into_parts(style).to_string(), expected);
+                }
+                for bytes in [0, 1, 1_023, 1_536, 5_242_880, u64::MAX] {
+                    let size = HumanSize::from(bytes).locale(locale);
+                    let mut expected = String::new();
+                    size.write_into(&mut expected, style).unwrap();
+                    assert_eq!(size.into_parts(style).to_string(), expected);
+                }
+                for secs in [0, 1, 59, 3_600, 3_661, 90_000] {
+                    let time = HumanTime::from(Duration::from_secs(secs)).locale(locale);
+                    let mut expected = String::new();
+                    time.write_into(&mut expected, style).unwrap();
+                    let joined: String = time
+                        .into_parts(style)
+                        .iter()
+                        .map(|p| p.to_string())
+                        .collect();
+                    assert_eq!(joined, expected);
+
+                    for time in [
+                        now - Duration::from_secs(secs * 7),
+                        now + Duration::from_secs(secs * 7 + 30),
+                    ] {
+                        let duration = HumanDuration::from(Some(time)).locale(locale);
+                        let parts = duration.into_parts(style);
+                        let mut expected = String::new();
+                        duration.write_into(&mut expected, style).unwrap();
+                        assert_eq!(parts.to_string(), expected);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_python_compat() {
+        use crate::compat::python::{intcomma, intword, naturaldelta, naturalsize, naturaltime};
+
+        // Expected strings are python-humanize 4.x output for the same input
+        for (value, ndigits, expected) in [
+            (100.0, None, "100"),
+            (1_000.0, None, "1,000"),
+            (1_000_000.0, None, "1,000,000"),
+            (-1_234_567.0, None, "-1,234,567"),
+            (1_234_567.25, None, "1,234,567.25"),
+            (1_234.545_454_5, Some(2), "1,234.55"),
+            (14_308.40, Some(1), "14,308.4"),
+            (0.5, None, "0.5"),
+            (f64::NAN, None, "NaN"),
+        ] {
+            assert_eq!(intcomma(value, ndigits), expected, "intcomma({value})");
+        }
+
+        for (value, expected) in [
+            (0.0, "0"),
+            (100.0, "100"),
+            (999.0, "999"),
+            (12_400.0, "12.4 thousand"),
+            (1_000_000.0, "1.0 million"),
+            (1_200_000.0, "1.2 million"),
+            (-1_200_000.0, "-1.2 million"),
+            (1_200_000_000.0, "1.2 billion"),
+            (999_999.0, "1.0 million"),
+            (999_949_999.0, "999.9 million"),
+            (8.1e33, "8.1 decillion"),
+            (f64::INFINITY, "+Inf"),
+            (f64::NEG_INFINITY, "-Inf"),
+        ] {
+            assert_eq!(intword(value), expected, "intword({value})");
+        }
+
+        for (value, binary, gnu, expected) in [
+            (0.0, false, false, "0 Bytes"),
+            (1.0, false, false, "1 Byte"),
+            (-1.0, false, false, "-1 Byte"),
+            (300.0, false, false, "300 Bytes"),
+            (3_000.0, false, false, "3.0 kB"),
+            (3_000_000.0, false, false, "3.0 MB"),
+            (1e28, false, false, "10.0 RB"),
+            (3e34, false, false, "30000.0 QB"),
+            (3_000.0, true, false, "2.9 KiB"),
+            (-4_096.0, true, false, "-4.0 KiB"),
+            (1_024.0, true, false, "1.0 KiB"),
+            (300.0, false, true, "300B"),
+            (1.0, false, true, "1B"),
+            (3_000.0, false, true, "2.9K"),
+            (3_000_000.0, false, true, "2.9M"),
+        ] {
+            assert_eq!(
+                naturalsize(value, binary, gnu),
+                expected,
+                "naturalsize({value}, {binary}, {gnu})"
+            );
+        }
+
+        const DAY: u64 = 86_400;
+        for (secs, expected) in [
+            (0, "a moment"),
+            (1, "a second"),
+            (30, "30 seconds"),
+            (60, "a minute"),
+            (119, "a minute"),
+            (120, "2 minutes"),
+            (1_001, "16 minutes"),
+            (3_600, "an hour"),
+            (7_200, "2 hours"),
+            (DAY - 1, "23 hours"),
+            (DAY, "a day"),
+            (7 * DAY, "7 days"),
+            (30 * DAY, "30 days"),
+            (31 * DAY, "a month"),
+            (62 * DAY, "2 months"),
+            (365 * DAY, "a year"),
+            (366 * DAY, "1 year, 1 day"),
+            (380 * DAY, "1 year, 15 days"),
+            (400 * DAY, "1 year, 1 month"),
+            (500 * DAY, "1 year, 4 months"),
+            (730 * DAY, "2 years"),
+            (365_000 * DAY, "1,000 years"),
+        ] {
+            assert_eq!(
+                naturaldelta(Duration::from_secs(secs)),
+                expected,
+                "naturaldelta({secs})"
+            );
+        }
+
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        for (offset, expected) in [
+            (0, "now"),
+            (-1, "a second ago"),
+            (-30, "30 seconds ago"),
+            (-60, "a minute ago"),
+            (-3_600, "an hour ago"),
+            (-(DAY as i64), "a day ago"),
+            (3 * 3_600, "3 hours from now"),
+            (60, "a minute from now"),
+        ] {
+            let value = if offset < 0 {
+                now - Duration::from_secs(offset.unsigned_abs())
+            } else {
+                now + Duration::from_secs(offset as u64)
+            };
+            assert_eq!(
+                naturaltime(value, Some(now)),
+                expected,
+                "naturaltime({offset})"
+            );
+        }
+    }
+
+    #[test]
+    fn test_crate_functions() {
+        assert_eq!(crate::number(1_800_000), "1.8M");
+        assert_eq!(crate::number(-42.5), "-42.5");
+        assert_eq!(crate::size(1_000_000), "976.6 KiB");
+        assert_eq!(crate::time(Duration::from_secs(3_661)), "1h 1m 1s");
+        assert_eq!(
+            crate::ago(SystemTime::now() - Duration::from_secs(120)),
+            "2m ago"
+        );
+        assert_eq!(crate::percent(12.3456, 2), "12.35%");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_conversions() {
+        use ::chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+
+        let time: HumanTime = TimeDelta::seconds(3_661).into();
+        assert_eq!(time.concise(), "1h 1m 1s");
+        let time: HumanTime = TimeDelta::seconds(-3_661).into();
+        assert_eq!(time.concise(), "-1h 1m 1s");
+        assert_eq!(time.to_string(), "-1 hour 1 minute 1 second");
+        let time: HumanTime = TimeDelta::zero().into();
+        assert_eq!(time.concise(), "0s");
+
+        let ago: HumanDuration =
+            (DateTime::<Utc>::from(SystemTime::now()) - TimeDelta::minutes(5)).into();
+        assert_eq!(ago.concise(), "5m ago");
+        // 1969-07-20, before the epoch
+        let pre_epoch = DateTime::from_timestamp(-14_182_940, 0).unwrap();
+        let ago: HumanDuration = pre_epoch.into();
+        assert!(ago.concise().ends_with("y ago"));
+        let date: HumanDate = pre_epoch.into();
+        assert_eq!(date.full(), "July 20, 1969");
+
+        let date: HumanDate = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap().into();
+        assert_eq!(date.concise(), "29 Feb 2024");
+        let date: HumanDate = NaiveDate::from_ymd_opt(1900, 1, 1).unwrap().into();
+        assert_eq!(date.format(DateStyle::Short), "1900-01-01");
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time_conversions() {
+        use ::time::{Date, Month, OffsetDateTime};
+
+        let time: HumanTime = ::time::Duration::seconds(3_661).into();
+        assert_eq!(time.concise(), "1h 1m 1s");
+        let time: HumanTime = ::time::Duration::seconds(-90).into();
+        assert_eq!(time.concise(), "-1m 30s");
+        let time: HumanTime = ::time::Duration::milliseconds(-500).into();
+        assert_eq!(time.concise(), "-500ms");
+
+        let ago: HumanDuration = (OffsetDateTime::now_utc() - ::time::Duration::hours(2)).into();
+        assert_eq!(ago.concise(), "2h ago");
+        // 1969-07-20, before the epoch
+        let pre_epoch = OffsetDateTime::from_unix_timestamp(-14_182_940).unwrap();
+        let date: HumanDate = pre_epoch.into();
+        assert_eq!(date.full(), "July 20, 1969");
+
+        let date: HumanDate = Date::from_calendar_date(2024, Month::February, 29)
+            .unwrap()
+            .into();
+        assert_eq!(date.concise(), "29 Feb 2024");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_field_adapters() {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Settings {
+            #[serde(with = "crate::serde::size")]
+            max_cache: u64,
+            #[serde(with = "crate::serde::duration_secs")]
+            timeout: Duration,
+            #[serde(with = "crate::serde::number")]
+            requests: f64,
+            #[serde(with = "crate::serde::percent")]
+            sample_rate: f64,
+        }
+
+        let settings = Settings {
+            max_cache: 536_870_912,
+            timeout: Duration::from_secs(5_400),
+            requests: 1_800_000.0,
+            sample_rate: 12.5,
+        };
+
+        // JSON round trip
+        let json = serde_json::to_string(&settings).unwrap();
+        assert_eq!(
+            json,
+            r#"{"max_cache":"512 MiB","timeout":"1h 30m","requests":"1.8M","sample_rate":"12.5%"}"#
+        );
+        assert_eq!(serde_json::from_str::<Settings>(&json).unwrap(), settings);
+
+        // YAML round trip
+        let yaml = serde_yaml::to_string(&settings).unwrap();
+        assert_eq!(
+            yaml,
+            "max_cache: 512 MiB\ntimeout: 1h 30m\nrequests: 1.8M\nsample_rate: 12.5%\n"
+        );
+        assert_eq!(serde_yaml::from_str::<Settings>(&yaml).unwrap(), settings);
+
+        // raw numbers are accepted too
+        let raw: Settings = serde_json::from_str(
+            r#"{"max_cache":536870912,"timeout":5400,"requests":1800000,"sample_rate":12.5}"#,
+        )
+        .unwrap();
+        assert_eq!(raw, settings);
+        let raw: Settings =
+            serde_yaml::from_str("max_cache: 1024\ntimeout: 1.5\nrequests: -3\nsample_rate: 50\n")
+                .unwrap();
+        assert_eq!(raw.max_cache, 1_024);
+        assert_eq!(raw.timeout, Duration::from_millis(1_500));
+        assert_eq!(raw.requests, -3.0);
+        assert_eq!(raw.sample_rate, 50.0);
+
+        // values the text would round are written raw
+        let inexact = Settings {
+            max_cache: 1_000_000,
+            timeout: Duration::from_millis(1_500),
+            requests: 1_234_567.0,
+            sample_rate: 33.333,
+        };
+        let json = serde_json::to_string(&inexact).unwrap();
+        assert_eq!(
+            json,
+            r#"{"max_cache":1000000,"timeout":1.5,"requests":1234567.0,"sample_rate":33.333}"#
+        );
+        assert_eq!(serde_json::from_str::<Settings>(&json).unwrap(), inexact);
+
+        // invalid text and negative sizes are errors
+        assert!(
+            serde_json::from_str::<Settings>(
+                r#"{"max_cache":"lots","timeout":1,"requests":1,"sample_rate":1}"#
+            )
+            .is_err()
+        );
+        assert!(
+            serde_json::from_str::<Settings>(
+                r#"{"max_cache":-1,"timeout":1,"requests":1,"sample_rate":1}"#
+            )
+            .is_err()
+        );
+
+        // binary formats always use the raw numbers
+        let bytes = bincode::serialize(&settings).unwrap();
+        assert_eq!(bytes.len(), 4 * 8);
+        assert_eq!(bincode::deserialize::<Settings>(&bytes).unwrap(), settings);
+
+        // the text ignores the display settings
+        let custom = crate::Config::default().decimal_separator(',');
+        let json = crate::Config::with(custom, |_| serde_json::to_string(&settings).unwrap());
+        assert!(json.contains(r#""1.8M""#), "{}", json);
+    }
+
+    #[test]
+    fn test_magnitude_selection() {
+        use crate::core::{UnitSystem, write_trimmed};
+        use std::fmt::Write;
+
+        // The float divide loops `magnitude` replaced, kept as a reference
+        fn old_size(bytes: u64, system: UnitSystem) -> String {
+            if bytes < 1024 {
+                return format!("{} B", bytes);
+            }
+            let (units_short, _, step) = system.units();
+            let mut size = bytes as f64 / step;
+            let mut idx = 0;
+            while size >= step && idx < units_short.len() - 1 {
+                size /= step;
+                idx += 1;
+            }
+            let rounded = (size * 10.0).round() / 10.0;
+            if rounded.fract() == 0.0 {
+                format!("{} {}", rounded as u64, units_short[idx])
+            } else {
+                format!("{:.1} {}", rounded, units_short[idx])
+            }
+        }
+        fn old_number(number: f64) -> String {
+            let (divisor, suffix) = [
+                (1e18, "Qi"),
+                (1e15, "Q"),
+                (1e12, "T"),
+                (1e9, "B"),
+                (1e6, "M"),
+                (1e3, "k"),
+            ]
+            .into_iter()
+            .find(|(divisor, _)| number.abs() >= *divisor)
+            .unwrap_or((1.0, ""));
+            let value = number / divisor;
+            crate::core::collect(|out| {
+                if value.fract() == 0.0 {
+                    write!(out, "{}", value as i64)?;
+                } else {
+                    write_trimmed(out, value, 1, '.')?;
+                }
+                out.write_str(suffix)
+            })
+        }
+
+        // Where the old code differs it showed a whole next unit in the one
+        // below ("1024 KiB", "1000k"); the new code moves up ("1 MiB", "1M").
+        fn check(old: String, new: String, step: &str, units: &[&str]) {
+            if old == new {
+                return;
+            }
+            let (mantissa, unit) = old.split_at(step.len());
+            let unit = unit.trim_start();
+            let next = units.iter().position(|u| *u == unit).map(|i| units[i + 1]);
+            assert_eq!(mantissa, step, "{} became {}", old, new);
+            assert_eq!(
+                Some(
+                    new.trim_start_matches('-')
+                        .trim_start_matches('1')
+                        .trim_start()
+                ),
+                next,
+                "{} became {}",
+                old,
+                new
+            );
+        }
+
+        let mut values: Vec<u64> = vec![0, 1, u64::MAX, u64::MAX - 1, 999_950, 999_949];
+        for shift in 0..64 {
+            for delta in [-2_i64, -1, 0, 1, 2] {
+                values.push((1_u64 << shift).wrapping_add_signed(delta));
+            }
+        }
+        for exp in 0..20 {
+            let power = 10_u64.pow(exp);
+            for delta in [-2_i64, -1, 0, 1, 2] {
+                values.push(power.wrapping_add_signed(delta));
+            }
+            // half-tenths either side of the next unit: 999.95, 1023.95
+            for tenths in [9_995_u64, 9_996, 10_235, 10_236] {
+                if let Some(value) = (power / 10).checked_mul(tenths).filter(|&v| v > 0) {
+                    values.extend([value - 1, value, value + 1]);
+                }
+            }
+        }
+        // a fixed pseudo-random sweep over every magnitude
+        let mut state = 0x2545_F491_4F6C_DD1D_u64;
+        for n in 0..100_000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            values.push(state >> (n % 64));
+        }
+
+        let binary = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB", "ZiB", "YiB"];
+        let decimal = ["B", "kB", "MB", "GB", "TB", "PB", "EB", "ZB", "YB"];
+        let numbers = ["", "k", "M", "B", "T", "Q", "Qi"];
+        let mut fixed = 0;
+        for &value in &values {
+            let new = HumanSize::from(value).binary().concise();
+            fixed += usize::from(old_size(value, UnitSystem::Binary) != new);
+            check(old_size(value, UnitSystem::Binary), new, "1024", &binary);
+
+            let new = HumanSize::from(value).decimal().concise();
+            fixed += usize::from(old_size(value, UnitSystem::Decimal) != new);
+            check(old_size(value, UnitSystem::Decimal), new, "1000", &decimal);
+
+            for number in [value as f64, value as f64 / 7.0, -(value as f64)] {
+                let new = HumanNumber::from(number).concise();
+                let old = old_number(number);
+                fixed += usize::from(old != new);
+                let step = if number < 0.0 { "-1000" } else { "1000" };
+                check(old, new, step, &numbers);
+            }
+        }
+        // the sweep does reach the old boundary errors
+        assert!(fixed > 0);
+
+        assert_eq!(HumanNumber::from(999_950).concise(), "1M");
+        assert_eq!(HumanNumber::from(999_949).concise(), "999.9k");
+        assert_eq!(HumanNumber::from(999_949_999).concise(), "999.9M");
+        assert_eq!(HumanNumber::from(999_950_000).concise(), "1B");
+        assert_eq!(HumanNumber::from(999_949_999_999_i64).concise(), "999.9B");
+        assert_eq!(HumanNumber::from(999_950_000_000_i64).concise(), "1T");
+        assert_eq!(HumanNumber::from(999_950_000_000_i64).full(), "1 trillion");
+        assert_eq!(HumanNumber::from(999_950_000_000_000_i64).concise(), "1Q");
+        assert_eq!(HumanNumber::from(999.96).concise(), "1k");
+        assert_eq!(HumanNumber::from(-999_950).full(), "-1 million");
+        assert_eq!(HumanSize::from(1_048_575).concise(), "1 MiB");
+        assert_eq!(HumanSize::from(999_999).decimal().concise(), "1 MB");
+        // just below and at 1023.95 / 999.95 of each unit up to TiB
+        for (power, unit, next) in [(1, "KiB", "MiB"), (2, "MiB", "GiB"), (3, "GiB", "TiB")] {
+            let base = 1024_u64.pow(power);
+            let edge = base * 1024 - base / 20;
+            let below = HumanSize::from(edge - 1).concise();
+            assert_eq!(below, format!("1023.9 {}", unit));
+            assert_eq!(HumanSize::from(edge).concise(), format!("1 {}", next));
+        }
+        for (power, unit, next) in [(1, "kB", "MB"), (2, "MB", "GB"), (3, "GB", "TB")] {
+            let base = 1000_u64.pow(power);
+            let edge = base * 1000 - base / 20;
+            let below = HumanSize::from(edge - 1).decimal().concise();
+            assert_eq!(below, format!("999.9 {}", unit));
+            let at = HumanSize::from(edge).decimal().concise();
+            assert_eq!(at, format!("1 {}", next));
+        }
+        assert_eq!(HumanSize::from(1_073_689_395).concise(), "1023.9 MiB");
+        assert_eq!(HumanSize::from(1_073_689_396).concise(), "1 GiB");
+        assert_eq!(HumanSize::from(u64::MAX).concise(), "16 EiB");
+        assert_eq!(HumanSize::from(u64::MAX).decimal().concise(), "18.4 EB");
+        assert_eq!(HumanNumber::from(u64::MAX as f64).concise(), "18.4Qi");
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_rust_decimal_conversions() {
+        use ::rust_decimal::Decimal;
+
+        let number = |text: &str| -> HumanNumber { text.parse::<Decimal>().unwrap().into() };
+        let percent = |text: &str| {
+            let percent: HumanPercent = text.parse::<Decimal>().unwrap().into();
+            percent.concise()
+        };
+
+        // f64 rounds this to 999_950, which would show as "1M"
+        assert_eq!(number("999949.99999999999999999").concise(), "999.9k");
+        assert_eq!(number("999949.99999999999999999").full(), "999.9 thousand");
+        assert_eq!(number("999950").concise(), "1M");
+        assert_eq!(number("1234.5").concise(), "1.2k");
+        assert_eq!(number("1250").concise(), "1.3k");
+        assert_eq!(number("-2500000.05").concise(), "-2.5M");
+        assert_eq!(number("1000").full(), "1 thousand");
+        assert_eq!(number("0.04").concise(), "0");
+        assert_eq!(number("12.34").concise(), "12.3");
+        assert_eq!(number("1250").precision(0).concise(), "1k");
+        assert_eq!(number("999999.5").precision(3).concise(), "1M");
+        assert_eq!(
+            number("1200000")
+                .precision(2)
+                .keep_trailing_zeros()
+                .concise(),
+            "1.20M"
+        );
+        assert_eq!(
+            number("79228162514264337593543950335").concise(),
+            "79228162514.3Qi"
+        );
+        assert_eq!(number("0.0000000000000000000000000001").concise(), "0");
+        assert_eq!(number("1234.5").locale(Locale::Spanish).full(), "1,2 mil");
+
+        // f64 has 0.05 slightly above a half, so it rounds to "0.1%"
+        assert_eq!(percent("0.04999999999999999999"), "0.0%");
+        assert_eq!(percent("12.25"), "12.3%");
+        assert_eq!(percent("-3.00"), "-3.0%");
+        assert_eq!(percent("99.95"), "100.0%");
+        let signed = |text: &str| {
+            let percent: HumanPercent = text.parse::<Decimal>().unwrap().into();
+            percent.signed().concise()
+        };
+        assert_eq!(signed("0.05"), "+0.1%");
+        assert_eq!(signed("-0.04999999999999999999"), "0.0%");
+        let rescaled = |text: &str| {
+            let percent: HumanPercent = text.parse::<Decimal>().unwrap().into();
+            (percent.basis_points().concise(), percent.per_mille().full())
+        };
+        assert_eq!(
+            rescaled("0.125"),
+            ("12.5 bps".into(), "1.3 per mille".into())
+        );
+        assert_eq!(
+            rescaled("-0.0015"),
+            ("-0.2 bps".into(), "0.0 per mille".into())
+        );
+
+        // Clamped values keep their exact rounding inside the range
+        let clamped = |text: &str| {
+            let percent: HumanPercent = text.parse::<Decimal>().unwrap().into();
+            percent.clamp().concise()
+        };
+        assert_eq!(clamped("0.04999999999999999999"), "0.0%");
+        assert_eq!(clamped("100.4"), "100.0%");
+        assert_eq!(clamped("-0.2"), "0.0%");
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn test_bigdecimal_conversions() {
+        use ::bigdecimal::BigDecimal;
+
+        let number = |text: &str| -> HumanNumber { text.parse::<BigDecimal>().unwrap().into() };
+
+        // more digits than an i128 holds, and than f64 keeps
+        let long = "999949.999999999999999999999999999999999999999999999999";
+        assert_eq!(number(long).concise(), "999.9k");
+        assert_eq!(
+            number("999950.000000000000000000000000000000000000000001").concise(),
+            "1M"
+        );
+        assert_eq!(
+            number("-2449999.99999999999999999999999999999999").concise(),
+            "-2.4M"
+        );
+        assert_eq!(number("1.5e20").concise(), "150Qi");
+        assert_eq!(number("1e-40").concise(), "0");
+        assert_eq!(number("1e40").concise(), "10000000000000000000000Qi");
+
+        let percent: HumanPercent = "0.04999999999999999999999"
+            .parse::<BigDecimal>()
+            .unwrap()
+            .into();
+        assert_eq!(percent.concise(), "0.0%");
+        let percent: HumanPercent = (&"12.25".parse::<BigDecimal>().unwrap()).into();
+        assert_eq!(format!("{}", percent), "12.3 percent");
+    }
+
+    #[test]
+    fn test_ticks() {
+        use crate::core::UnitSystem;
+
+        fn labels<T>(ticks: Vec<Tick<T>>) -> Vec<String> {
+            ticks.into_iter().map(|tick| tick.label).collect()
+        }
+
+        assert_eq!(
+            labels(HumanNumber::ticks(0.0, 3_400_000.0, 4)),
+            ["0", "1M", "2M", "3M"]
+        );
+        // one precision across the axis
+        assert_eq!(
+            labels(HumanNumber::ticks(0.0, 2_500_000.0, 6)),
+            ["0", "0.5M", "1.0M", "1.5M", "2.0M", "2.5M"]
+        );
+        assert_eq!(
+            labels(HumanNumber::ticks(0.0, 10.0, 5)),
+            ["0", "2.5", "5.0", "7.5", "10.0"]
+        );
+        assert_eq!(
+            labels(HumanNumber::ticks(-1_000.0, 1_000.0, 5)),
+            ["-1.0k", "-0.5k", "0", "0.5k", "1.0k"]
+        );
+        assert_eq!(
+            labels(HumanNumber::ticks(0.1, 0.35, 3)),
+            ["0.1", "0.2", "0.3"]
+        );
+        // bounds in either order, off the steps
+        let ticks = HumanNumber::ticks(9_900.0, 120.0, 3);
+        assert_eq!(
+            ticks.iter().map(|tick| tick.value).collect::<Vec<_>>(),
+            [2_500.0, 5_000.0, 7_500.0]
+        );
+        assert_eq!(labels(ticks), ["2.5k", "5.0k", "7.5k"]);
+        assert_eq!(labels(HumanNumber::ticks(5.0, 5.0, 3)), ["5"]);
+        assert!(HumanNumber::ticks(0.0, 1.0, 0).is_empty());
+        assert!(HumanNumber::ticks(0.0, f64::NAN, 3).is_empty());
+        // a span past f64::MAX still gets finite ticks
+        let ticks = HumanNumber::ticks(-f64::MAX, f64::MAX, 5);
+        assert_eq!(ticks.len(), 3);
+        assert!(ticks.iter().all(|tick| tick.value.is_finite()));
+        let huge = labels(ticks);
+        assert_eq!(huge[1], "0");
+        assert!(huge.iter().all(|label| !label.contains("NaN")));
+        assert_eq!(HumanNumber::ticks(0.0, 3_400_000.0, 4)[2].to_string(), "2M");
+        assert_eq!(format!("{:>4}", HumanNumber::ticks(0.0, 3.0, 4)[1]), "   1");
+
+        // the configured decimal separator
+        let comma = crate::Config::default().decimal_separator(',');
+        crate::Config::with(comma, |_| {
+            assert_eq!(labels(HumanNumber::ticks(0.0, 1.0, 3)), ["0", "0,5", "1,0"]);
+        });
+
+        assert_eq!(
+            labels(HumanNumber::log_ticks(1.0, 20_000.0)),
+            ["1", "10", "100", "1k", "10k"]
+        );
+        assert_eq!(labels(HumanNumber::log_ticks(0.005, 0.5)), ["0.01", "0.1"]);
+        assert!(HumanNumber::log_ticks(0.0, 100.0).is_empty());
+
+        // sizes
+        assert_eq!(
+            labels(HumanSize::ticks(0, 1 << 30, 5, UnitSystem::Binary)),
+            ["0 B", "256 MiB", "512 MiB", "768 MiB", "1024 MiB"]
+        );
+        assert_eq!(
+            labels(HumanSize::ticks(0, 10_000_000, 5, UnitSystem::Decimal)),
+            ["0 B", "2.5 MB", "5.0 MB", "7.5 MB", "10.0 MB"]
+        );
+        assert_eq!(
+            labels(HumanSize::ticks(0, 1_000, 3, UnitSystem::Decimal)),
+            ["0 B", "500 B", "1000 B"]
+        );
+        assert_eq!(
+            labels(HumanSize::ticks(100, 700, 4, UnitSystem::Binary)),
+            ["256 B", "512 B"]
+        );
+        let ticks = HumanSize::ticks(0, u64::MAX, 3, UnitSystem::Binary);
+        assert_eq!(
+            ticks.iter().map(|tick| tick.value).collect::<Vec<_>>(),
+            [0, 1 << 63]
+        );
+        assert_eq!(labels(ticks), ["0 B", "8 EiB"]);
+        assert!(HumanSize::ticks(0, 1, 0, UnitSystem::Binary).is_empty());
+
+        assert_eq!(
+            labels(HumanSize::log_ticks(0, 5 << 30, UnitSystem::Binary)),
+            ["1 B", "1 KiB", "1 MiB", "1 GiB"]
+        );
+        assert_eq!(
+            labels(HumanSize::log_ticks(1_000, u64::MAX, UnitSystem::Decimal)),
+            ["1 kB", "1 MB", "1 GB", "1 TB", "1 PB", "1 EB"]
+        );
+    }
+
+    #[test]
+    fn test_typography() {
+        use crate::Config;
+        use crate::config::Typography;
+        use crate::core::Style;
+        use std::time::UNIX_EPOCH;
+
+        let unicode = Config::default().typography(Typography::Unicode);
+        Config::with(unicode, |_| {
+            assert_eq!(HumanSize::from(5_242_880).concise(), "5\u{202F}MiB");
+            assert_eq!(HumanSize::from(1_572_864).full(), "1.5\u{202F}mebibytes");
+            assert_eq!(HumanNumber::from(-1_500).concise(), "\u{2212}1.5k");
+            assert_eq!(
+                HumanNumber::from(-1_500).full(),
+                "\u{2212}1.5\u{202F}thousand"
+            );
+            assert_eq!(HumanPercent::from(-12.5, 1).concise(), "\u{2212}12.5%");
+            assert_eq!(
+                HumanTime::from(Duration::from_secs(5_400)).concise(),
+                "1h 30m"
+            );
+            assert_eq!(
+                HumanTime::from(Duration::from_secs(5_400)).to_string(),
+                "1\u{202F}hour 30\u{202F}minutes"
+            );
+            assert_eq!(
+                HumanTemperature::from_celsius(-5.0).concise(),
+                "\u{2212}5°C"
+            );
+            assert_eq!(
+                HumanDelta::sizes(1_048_576, 0).concise(),
+                "\u{2212}1\u{202F}MiB (\u{2212}100%)"
+            );
+            // hyphens that aren't minus signs stay
+            assert_eq!(HumanPercent::from(f64::NAN, 1).concise(), "-");
+            assert_eq!(
+                HumanRange::numbers(-20_000.0, -10_000.0).ascii().concise(),
+                "\u{2212}20k-\u{2212}10k"
+            );
+            let date = UNIX_EPOCH + Duration::from_secs(1_710_252_300);
+            assert_eq!(HumanDate::from(date).format(DateStyle::Short), "2024-03-12");
+            // `{}` goes through the same path
+            assert_eq!(format!("{:#}", HumanSize::from(2_048)), "2\u{202F}KiB");
+            let mut buf = String::new();
+            HumanNumber::from(-3)
+                .write_into(&mut buf, Style::Concise)
+                .unwrap();
+            assert_eq!(buf, "\u{2212}3");
+        });
+
+        // ASCII is the default
+        assert_eq!(HumanNumber::from(-1_500).concise(), "-1.5k");
+        assert_eq!(HumanSize::from(5_242_880).concise(), "5 MiB");
+
+        // parsers take both forms whatever the setting
+        use crate::parse::{parse_duration, parse_human_number, parse_percent, parse_size};
+        assert_eq!(parse_size("5\u{202F}MiB"), Ok(5_242_880));
+        assert_eq!(parse_size("5 MiB"), Ok(5_242_880));
+        assert_eq!(parse_human_number("\u{2212}1.5k"), Ok(-1_500.0));
+        assert_eq!(parse_human_number("-1.5k"), Ok(-1_500.0));
+        assert_eq!(parse_percent("\u{2212}12.5%"), Ok(-12.5));
+        assert_eq!(
+            parse_duration("1\u{202F}hour 30\u{202F}minutes"),
+            Ok(Duration::from_secs(5_400))
+        );
+    }
+
+    #[test]
+    fn test_human_format_trait() {
+        use crate::{HumanFormat, HumanList, HumanPermissions};
+
+        let ship = ["Alice", "Bob"];
+        let values: Vec<Box<dyn HumanFormat + '_>> = vec![
+            Box::new(HumanNumber::from(1_200)),
+            Box::new(HumanSize::from(5_242_880)),
+            Box::new(HumanTime::from(Duration::from_secs(90))),
+            Box::new(HumanPercent::from(12.3456, 1)),
+            Box::new(HumanList::from(&ship)),
+            Box::new(HumanPermissions::from(0o755)),
+        ];
+
+        let concise: Vec<String> = values.iter().map(|v| v.concise()).collect();
+        assert_eq!(
+            concise,
+            [
+                "1.2k",
+                "5 MiB",
+                "1m 30s",
+                "12.3%",
+                "Alice, Bob",
+                "rwxr-xr-x"
+            ]
+        );
+
+        let full: Vec<String> = values.iter().map(|v| v.full()).collect();
+        assert_eq!(full[0], "1.2 thousand");
+        assert_eq!(full[1], "5 mebibytes");
+        assert_eq!(full[2], "1 minute 30 seconds");
+        assert_eq!(full[3], "12.3 percent");
+        assert_eq!(full[4], "Alice and Bob");
+        assert_eq!(full[5], HumanPermissions::from(0o755).describe());
+
+        // The inherent methods and `Display` agree with the trait.
+        fn both(value: &(impl HumanFormat + std::fmt::Display)) -> [String; 4] {
+            [
+                value.concise(),
+                format!("{:#}", value),
+                value.full(),
+                value.to_string(),
+            ]
+        }
+        for value in [
+            both(&HumanNumber::from(-3_456_789)),
+            both(&HumanSize::from(1_000_000u64).decimal()),
+            both(&HumanTime::from(Duration::from_secs(86_400))),
+        ] {
+            assert_eq!(value[0], value[1]);
+            assert_eq!(value[2], value[3]);
+        }
+        assert_eq!(
+            HumanNumber::from(1_800_000).concise(),
+            HumanFormat::concise(&HumanNumber::from(1_800_000))
+        );
+    }
+
+    #[test]
+    fn test_comparison() {
+        let cases = [
+            (230.0, 100.0, "2.3×", "2.3× larger"),
+            (88.0, 100.0, "-12%", "12% smaller"),
+            (112.5, 100.0, "+12.5%", "12.5% larger"),
+            (199.0, 100.0, "+99%", "99% larger"),
+            (199.96, 100.0, "+100%", "100% larger"),
+            (200.0, 100.0, "2×", "2× larger"),
+            (40.0, 100.0, "0.4×", "2.5× smaller"),
+            (50.0, 100.0, "0.5×", "2× smaller"),
+            (0.12, 100.0, "0.0012×", "833.3× smaller"),
+            (100.5, 100.0, "≈1×", "about the same"),
+            (0.0, 0.0, "≈1×", "about the same"),
+            (5.0, 0.0, "+∞", "infinitely larger"),
+            (0.0, 5.0, "-100%", "100% smaller"),
+            (-230.0, -100.0, "2.3×", "2.3× larger"),
+            (-5.0, 5.0, "-", "-"),
+            (f64::NAN, 1.0, "-", "-"),
+        ];
+        for (value, baseline, concise, full) in cases {
+            let comparison = HumanComparison::numbers(value, baseline);
+            assert_eq!(comparison.concise(), concise, "{} vs {}", value, baseline);
+            assert_eq!(comparison.full(), full, "{} vs {}", value, baseline);
+        }
+
+        // The ratio reads value / baseline.
+        assert_eq!(HumanComparison::numbers(3, 2).ratio(), Some(1.5));
+        assert_eq!(HumanComparison::numbers(1, 0).ratio(), Some(f64::INFINITY));
+        assert_eq!(HumanComparison::numbers(-1, 2).ratio(), None);
+
+        let comparison = HumanComparison::numbers(150, 100);
+        assert_eq!(comparison.threshold(1.5).full(), "1.5× larger");
+        assert_eq!(comparison.threshold(0.5).concise(), "1.5×");
+        assert_eq!(comparison.same_within(60.0).full(), "about the same");
+        assert_eq!(
+            HumanComparison::numbers(100.5, 100).same_within(0.0).full(),
+            "0.5% larger"
+        );
+        assert_eq!(
+            HumanComparison::times(Duration::from_millis(20), Duration::from_millis(50))
+                .words("faster", "slower")
+                .full(),
+            "2.5× slower"
+        );
+
+        assert_eq!(HumanComparison::sizes(1_500, 1_000).full(), "50% larger");
+        assert_eq!(
+            HumanSize::from(2_300)
+                .compare(&HumanSize::from(1_000))
+                .full(),
+            "2.3× larger"
+        );
+        assert_eq!(
+            HumanTime::from(Duration::from_secs(3))
+                .compare(&HumanTime::from(Duration::from_secs(2)))
+                .full(),
+            "50% longer"
+        );
+        assert_eq!(
+            HumanComparison::times(Duration::from_secs(1), Duration::from_secs(4)).full(),
+            "4× shorter"
+        );
+
+        assert_eq!(crate::compare(230.0, 100.0), "2.3×");
+        assert_eq!(
+            format!("{}", HumanComparison::numbers(88, 100)),
+            "12% smaller"
+        );
+        assert_eq!(format!("{:#}", HumanComparison::numbers(88, 100)), "-12%");
+    }
+
+    #[test]
+    fn test_format_into() {
+        use crate::core::Style;
+        use crate::{CapacityError, Config, Locale, SuffixCase, Typography, UnitSystem};
+
+        let mut buf = [0u8; 16];
+        assert_eq!(
+            HumanSize::from(1_500_000u64).format_into(&mut buf, Style::Concise),
+            Ok("1.4 MiB")
+        );
+        assert_eq!(
+            HumanNumber::from(-1_800_000).format_into(&mut buf, Style::Full),
+            Ok("-1.8 million")
+        );
+        assert_eq!(
+            HumanTime::from(Duration::from_secs(5_400)).format_into(&mut buf, Style::Concise),
+            Ok("1h 30m")
+        );
+
+        let mut small = [0u8; 4];
+        let err = HumanSize::from(1_500_000u64)
+            .format_into(&mut small, Style::Concise)
+            .unwrap_err();
+        assert_eq!(err, CapacityError::new(7, 4));
+        assert_eq!((err.required(), err.capacity()), (7, 4));
+        assert_eq!(
+            err.to_string(),
+            "output needs 7 bytes but the buffer holds 4"
+        );
+        assert_eq!(
+            HumanNumber::from(5).format_into(&mut [], Style::Concise),
+            Err(CapacityError::new(1, 0))
+        );
+
+        // The documented maximum lengths hold at the extremes of every type,
+        // under every setting that lengthens the output.
+        let mut numbers = vec![f64::MAX, f64::MIN_POSITIVE, f64::NAN, f64::INFINITY, 0.0];
+        let mut x = 1e-3;
+        while x < 1e300 {
+            numbers.extend([x, x * 1.23456, x * 9.95, x * 9.949]);
+            x *= 10.0;
+        }
+        // 1,048,473 is "1023.9 KiB", the most digits before a binary unit
+        let mut sizes = vec![0, 1023, 1_048_473, u64::MAX];
+        for shift in 0..64 {
+            let power = 1u64 << shift;
+            sizes.extend([power - 1, (power as f64 * 0.99996) as u64]);
+            sizes.push(power.saturating_mul(1000).saturating_sub(1));
+        }
+        let mut times = vec![
+            Duration::MAX,
+            Duration::from_secs(u64::MAX - 1),
+            Duration::ZERO,
+        ];
+        // the most whole units with every smaller one at its largest
+        for unit in [60, 3_600, 86_400, 604_800] {
+            times.push(Duration::from_secs(u64::MAX / unit * unit - 1));
+        }
+        // and the longest sub-second remainders
+        for i in 0..times.len() {
+            let secs = times[i].as_secs();
+            times.extend([999_999_999, 999_999, 999].map(|nanos| Duration::new(secs, nanos)));
+        }
+        let max_units = [
+            None,
+            Some(TimeUnit::Second),
+            Some(TimeUnit::Minute),
+            Some(TimeUnit::Hour),
+            Some(TimeUnit::Week),
+        ];
+
+        for typography in [Typography::Ascii, Typography::Unicode] {
+            for separator in [None, Some('𝄞')] {
+                for system in [UnitSystem::Binary, UnitSystem::Decimal, UnitSystem::Jedec] {
+                    let mut config = Config::default()
+                        .typography(typography)
+                        .suffix_case(SuffixCase::Upper)
+                        .unit_system(system);
+                    if let Some(separator) = separator {
+                        config = config.decimal_separator(separator);
+                    }
+                    Config::with(config, |_| {
+                        for locale in [None, Some(Locale::English), Some(Locale::Spanish)] {
+                            let within = |text: String, max: usize| {
+                                assert!(text.len() <= max, "{:?} is over {}", text, max);
+                            };
+                            for &n in &numbers {
+                                for n in [n, -n] {
+                                    let mut number = HumanNumber::from(n);
+                                    if let Some(locale) = locale {
+                                        number = number.locale(locale);
+                                    }
+                                    within(number.concise(), HumanNumber::MAX_CONCISE_LEN);
+                                    within(number.full(), HumanNumber::MAX_FULL_LEN);
+                                    let indian = number.system(crate::NumberSystem::Indian);
+                                    within(indian.concise(), HumanNumber::MAX_CONCISE_LEN);
+                                    within(indian.full(), HumanNumber::MAX_FULL_LEN);
+                                }
+                            }
+                            for &bytes in &sizes {
+                                let mut size = HumanSize::from(bytes);
+                                if let Some(locale) = locale {
+                                    size = size.locale(locale);
+                                }
+                                within(size.concise(), HumanSize::MAX_CONCISE_LEN);
+                                within(size.full(), HumanSize::MAX_FULL_LEN);
+                                if let Ok(bytes) = i64::try_from(bytes) {
+                                    let mut size = HumanSize::from_signed(-bytes);
+                                    if let Some(locale) = locale {
+                                        size = size.locale(locale);
+                                    }
+                                    for size in [size, size.bits()] {
+                                        within(size.concise(), HumanSize::MAX_CONCISE_LEN);
+                                        within(size.full(), HumanSize::MAX_FULL_LEN);
+                                    }
+                                }
+                            }
+                            for (&duration, &max_unit) in times.iter().flat_map(|duration| {
+                                max_units.iter().map(move |max_unit| (duration, max_unit))
+                            }) {
+                                let mut time = HumanTime::from_signed(duration, true);
+                                if let Some(locale) = locale {
+                                    time = time.locale(locale);
+                                }
+                                if let Some(max_unit) = max_unit {
+                                    time = time.max_unit(max_unit);
+                                }
+                                for time in [time, time.precise()] {
+                                    within(time.concise(), HumanTime::MAX_CONCISE_LEN);
+                                    within(time.to_string(), HumanTime::MAX_FULL_LEN);
+                                }
+                            }
+                        }
+                    });
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_coordinate() {
+        use crate::{CoordinateError, ParseErrorKind};
+
+        let paris = HumanCoordinate::from_lat_lon(48.8567, 2.3522).unwrap();
+        assert_eq!(paris.concise(), "48°51′24″N 2°21′08″E");
+        assert_eq!(paris.full(), "48.8567° N, 2.3522° E");
+        assert_eq!(paris.ascii().concise(), "48°51'24\"N 2°21'08\"E");
+        assert_eq!(format!("{:#}", paris), paris.concise());
+
+        let sydney = HumanCoordinate::from_lat_lon(-33.8688, 151.2093).unwrap();
+        assert_eq!(sydney.concise(), "33°52′08″S 151°12′33″E");
+        assert_eq!(sydney.full(), "33.8688° S, 151.2093° E");
+
+        // Seconds carry into minutes and degrees; rounding to zero is north/east.
+        let carry = HumanCoordinate::from_lat_lon(10.999_99, -0.000_01).unwrap();
+        assert_eq!(carry.concise(), "11°00′00″N 0°00′00″E");
+        assert_eq!(carry.full(), "11.0000° N, 0.0000° E");
+        let corner = HumanCoordinate::from_lat_lon(-90.0, -180.0).unwrap();
+        assert_eq!(corner.concise(), "90°00′00″S 180°00′00″W");
+
+        assert_eq!(
+            HumanCoordinate::from_lat_lon(90.5, 0.0),
+            Err(CoordinateError::Latitude(90.5))
+        );
+        assert_eq!(
+            HumanCoordinate::from_lat_lon(0.0, -181.0)
+                .unwrap_err()
+                .to_string(),
+            "longitude -181 is outside -180 to 180"
+        );
+        assert!(HumanCoordinate::from_lat_lon(f64::NAN, 0.0).is_err());
+
+        // Both forms round-trip, in either alphabet and either axis order.
+        for coordinate in [paris, sydney, corner] {
+            for text in [
+                coordinate.concise(),
+                coordinate.ascii().concise(),
+                coordinate.full(),
+            ] {
+                let parsed: HumanCoordinate = text.parse().unwrap();
+                assert_eq!(parsed.concise(), coordinate.concise(), "{}", text);
+            }
+        }
+        let swapped: HumanCoordinate = "2°21′08″E 48°51′24″N".parse().unwrap();
+        assert_eq!(swapped.concise(), paris.concise());
+        let signed: HumanCoordinate = "-33.8688, 151.2093".parse().unwrap();
+        assert_eq!(
+            (signed.latitude(), signed.longitude()),
+            (-33.8688, 151.2093)
+        );
+        let lowercase: HumanCoordinate = "48.5n 2.25w".parse().unwrap();
+        assert_eq!(lowercase.concise(), "48°30′00″N 2°15′00″W");
+
+        let err = |input: &str| input.parse::<HumanCoordinate>().unwrap_err();
+        assert_eq!(err("").kind(), ParseErrorKind::Empty);
+        assert_eq!(err("48.5").kind(), ParseErrorKind::InvalidNumber);
+        assert_eq!(err("91°N 0°E").kind(), ParseErrorKind::OutOfRange);
+        assert_eq!(err("91°N 0°E").fragment(), "91°N");
+        assert_eq!(err("48°61′N 0°E").kind(), ParseErrorKind::OutOfRange);
+        assert_eq!(err("48°N 2°N").kind(), ParseErrorKind::UnknownUnit);
+        assert_eq!(err("48°N 2°N").position(), 4);
+        assert_eq!(err("-48°S 2°E").kind(), ParseErrorKind::UnknownUnit);
+        assert_eq!(err("48°N 2°E x").kind(), ParseErrorKind::TrailingInput);
+    }
+
+    #[test]
+    fn test_pace() {
+        let run = HumanPace::new(10_000.0, Duration::from_secs(55 * 60));
+        assert_eq!(run.concise(), "5:30 /km");
+        assert_eq!(run.full(), "5 minutes 30 seconds per kilometer");
+        assert_eq!(run.per_mile().concise(), "8:51 /mi");
+        assert_eq!(run.per_mile().full(), "8 minutes 51 seconds per mile");
+        assert_eq!(run.per_mile().per_km(), run);
+        assert_eq!(run.seconds_per_unit(), Some(330.0));
+        assert!((run.speed_kmh().unwrap() - 10.909).abs() < 1e-3);
+        assert!((run.speed_mph().unwrap() - 6.779).abs() < 1e-3);
+
+        let check = |meters: f64, secs: u64, concise: &str, full: &str| {
+            let pace = HumanPace::new(meters, Duration::from_secs(secs));
+            assert_eq!(pace.concise(), concise, "{} m in {} s", meters, secs);
+            assert_eq!(pace.full(), full, "{} m in {} s", meters, secs);
+        };
+        check(1_000.0, 300, "5:00 /km", "5 minutes per kilometer");
+        check(1_000.0, 61, "1:01 /km", "1 minute 1 second per kilometer");
+        check(1_000.0, 9, "0:09 /km", "9 seconds per kilometer");
+        check(
+            500.0,
+            1_965,
+            "1:05:30 /km",
+            "1 hour 5 minutes 30 seconds per kilometer",
+        );
+        check(1_000.0, 7_200, "2:00:00 /km", "2 hours per kilometer");
+        check(1_000.0, 0, "0:00 /km", "0 seconds per kilometer");
+
+        // No distance, no pace: a placeholder rather than a division by zero.
+        for meters in [0.0, -5.0, f64::NAN, f64::INFINITY] {
+            let pace = HumanPace::new(meters, Duration::from_secs(60));
+            assert_eq!(pace.concise(), "-");
+            assert_eq!(pace.full(), "-");
+            assert_eq!(pace.speed_kmh(), None);
+        }
+        assert_eq!(HumanPace::new(1_000.0, Duration::ZERO).speed_kmh(), None);
+        assert_eq!(format!("{:#}", run), "5:30 /km");
+    }
+
+    #[test]
+    fn test_uptime() {
+        let uptime = |secs: u64| HumanUptime::from(Duration::from_secs(secs));
+
+        // Fixtures from procps `uptime`, with the hour column unpadded.
+        for (secs, concise) in [
+            (3 * 86_400 + 4 * 3_600 + 5 * 60, "up 3 days, 4:05"),
+            (27 * 60 + 59, "up 27 min"),
+            (3_600, "up 1:00"),
+            (23 * 3_600 + 59 * 60, "up 23:59"),
+            (86_400, "up 1 day, 0 min"),
+            (86_400 + 5 * 60, "up 1 day, 5 min"),
+            (86_400 + 3_600 + 60, "up 1 day, 1:01"),
+            (412 * 86_400 + 13 * 3_600 + 7 * 60, "up 412 days, 13:07"),
+            (60, "up 1 min"),
+            (42, "up 42 sec"),
+            (0, "up 0 sec"),
+        ] {
+            assert_eq!(uptime(secs).concise(), concise, "{} s", secs);
+        }
+
+        assert_eq!(
+            uptime(3 * 86_400 + 4 * 3_600 + 5 * 60).full(),
+            "up 3 days, 4 hours and 5 minutes"
+        );
+        assert_eq!(uptime(86_400 + 60).full(), "up 1 day and 1 minute");
+        assert_eq!(uptime(2 * 3_600).full(), "up 2 hours");
+        assert_eq!(uptime(42).full(), "up 42 seconds");
+        assert_eq!(uptime(1).full(), "up 1 second");
+        assert_eq!(format!("{:#}", uptime(27 * 60)), "up 27 min");
+
+        let booted = SystemTime::now() - Duration::from_secs(27 * 60 + 5);
+        assert_eq!(HumanUptime::from_boot_time(booted).concise(), "up 27 min");
+        let future = SystemTime::now() + Duration::from_secs(60);
+        assert_eq!(HumanUptime::from_boot_time(future).concise(), "up 0 sec");
+    }
+
+    #[test]
+    fn test_dehumanize() {
+        use crate::{Dehumanized, ParseErrorKind, dehumanize};
+
+        let parsed = |input: &str| dehumanize(input).unwrap();
+        assert_eq!(parsed("1.5 GiB"), Dehumanized::Bytes(1_610_612_736));
+        assert_eq!(parsed("5 MB"), Dehumanized::Bytes(5_000_000));
+        assert_eq!(parsed("45%"), Dehumanized::Percent(45.0));
+        assert_eq!(parsed("−12.5 %"), Dehumanized::Percent(-12.5));
+        assert_eq!(parsed("3.4M"), Dehumanized::Number(3_400_000.0));
+        assert_eq!(parsed("15k"), Dehumanized::Number(15_000.0));
+        assert_eq!(parsed("1,234,567"), Dehumanized::Number(1_234_567.0));
+        assert_eq!(parsed("-1,234.5"), Dehumanized::Number(-1_234.5));
+        assert_eq!(parsed(" 42 "), Dehumanized::Number(42.0));
+        assert_eq!(
+            parsed("5m"),
+            Dehumanized::Duration(Duration::from_secs(300))
+        );
+        assert_eq!(
+            parsed("1h 30m"),
+            Dehumanized::Duration(Duration::from_secs(5_400))
+        );
+        assert_eq!(
+            parsed("2 days"),
+            Dehumanized::Duration(Duration::from_secs(172_800))
+        );
+
+        let near = |value: Dehumanized, expected: SystemTime| match value {
+            Dehumanized::Timestamp(time) => {
+                let gap = time
+                    .duration_since(expected)
+                    .unwrap_or_else(|err| err.duration());
+                assert!(gap < Duration::from_secs(5), "{:?}", gap);
+            }
+            other => panic!("not a timestamp: {:?}", other),
+        };
+        let now = SystemTime::now();
+        near(parsed("2 hours ago"), now - Duration::from_secs(7_200));
+        near(parsed("in 3 days"), now + Duration::from_secs(259_200));
+        near(parsed("10m from now"), now + Duration::from_secs(600));
+        near(parsed("just now"), now);
+
+        // "B" reads as bytes or billions: the caller gets both to choose from.
+        let err = dehumanize("5B").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::Ambiguous);
+        assert_eq!(
+            err.candidates(),
+            [Dehumanized::Bytes(5), Dehumanized::Number(5e9)]
+        );
+        assert_eq!(
+            err.to_string(),
+            "ambiguous '5B' at position 0 (could be 5 bytes or 5 billion)"
+        );
+        assert!(
+            dehumanize("5 MiB later")
+                .unwrap_err()
+                .candidates()
+                .is_empty()
+        );
+
+        assert_eq!(dehumanize("").unwrap_err().kind(), ParseErrorKind::Empty);
+        assert_eq!(
+            dehumanize("5 fortnights").unwrap_err().kind(),
+            ParseErrorKind::UnknownUnit
+        );
+        assert_eq!(
+            dehumanize("5 MiB later").unwrap_err().kind(),
+            ParseErrorKind::TrailingInput
+        );
+        let err = dehumanize("2 eons ago").unwrap_err();
+        assert_eq!(
+            (err.kind(), err.position()),
+            (ParseErrorKind::UnknownUnit, 2)
+        );
+        assert_eq!(
+            dehumanize("12,34").unwrap_err().kind(),
+            ParseErrorKind::TrailingInput
+        );
+
+        // Non-finite numbers are rejected, so `Dehumanized` equality holds
+        for input in ["nan%", "inf%", "-inf%", "1e400%"] {
+            let err = dehumanize(input).unwrap_err();
+            assert_eq!(err.kind(), ParseErrorKind::InvalidNumber, "{}", input);
+        }
+        for input in ["nan", "inf", "1e400"] {
+            assert!(dehumanize(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_number_precision() {
+        let number = |n: f64, precision: usize| HumanNumber::from(n).precision(precision);
+        assert_eq!(number(1_234_567.0, 2).concise(), "1.23M");
+        assert_eq!(number(1_234_567.0, 2).full(), "1.23 million");
+        assert_eq!(number(1_234_567.0, 0).concise(), "1M");
+        assert_eq!(number(1_200_000.0, 2).concise(), "1.2M");
+        assert_eq!(
+            number(1_200_000.0, 2).keep_trailing_zeros().concise(),
+            "1.20M"
+        );
+        assert_eq!(
+            number(1_000_000.0, 2).keep_trailing_zeros().full(),
+            "1.00 million"
+        );
+        assert_eq!(number(42.0, 1).keep_trailing_zeros().concise(), "42.0");
+        assert_eq!(number(0.126, 2).concise(), "0.13");
+        assert_eq!(HumanNumber::from(1_234_567).concise(), "1.2M");
+
+        // the suffix moves up only when the value rounds to the next one
+        assert_eq!(number(999_999.0, 2).concise(), "1M");
+        assert_eq!(number(999_999.0, 3).concise(), "999.999k");
+        assert_eq!(number(999_499.0, 0).concise(), "999k");
+        assert_eq!(number(999_500.0, 0).concise(), "1M");
+
+        // singular follows the rounded value
+        let spanish = |precision| number(1_004_000.0, precision).locale(Locale::Spanish);
+        assert_eq!(spanish(2).full(), "1 millón");
+        assert_eq!(spanish(3).full(), "1,004 millones");
+
+        assert_eq!(format!("{:#}", number(2_345_678.0, 2)), "2.35M");
+        assert_eq!(HumanNumber::default().concise(), "0");
+
+        // Huge precisions are capped instead of panicking or wrapping
+        for precision in [1 << 32, 70_000, usize::MAX] {
+            assert_eq!(
+                number(1_500_000.0, precision).concise(),
+                number(1_500_000.0, 300).concise()
+            );
+            assert_eq!(number(1_500_000.0, precision).full(), "1.5 million");
+            assert_eq!(
+                number(2.0, precision).keep_trailing_zeros().concise(),
+                format!("2.{}", "0".repeat(300))
+            );
+        }
+    }
+
+    #[test]
+    fn test_negative_number() {
+        use crate::HumanizeNumber;
+
+        assert_eq!(HumanNumber::from(-1_500_000).concise(), "-1.5M");
+        assert_eq!(HumanNumber::from(-1_500_000).full(), "-1.5 million");
+        assert_eq!(HumanNumber::from(-999).concise(), "-999");
+        assert_eq!(HumanNumber::from(-999).full(), "-999");
+        assert_eq!(HumanNumber::from(-999_950.0).concise(), "-1M");
+        assert_eq!(HumanNumber::from(-2.5e9).full(), "-2.5 billion");
+        assert_eq!((-1_500_000_i64).human_number().concise(), "-1.5M");
+
+        // zero, however it got there, has no sign
+        assert_eq!(HumanNumber::from(-0.0).concise(), "0");
+        assert_eq!(HumanNumber::from(-0.0).full(), "0");
+        assert_eq!(HumanNumber::from(-0.04).concise(), "0");
+        assert_eq!(HumanNumber::from(-0.004).precision(2).concise(), "0");
+        assert_eq!(HumanNumber::from(-0.005).precision(2).concise(), "-0.01");
+        assert_eq!(
+            HumanNumber::from(-0.0).keep_trailing_zeros().concise(),
+            "0.0"
+        );
+    }
+
+    #[test]
+    fn test_size_bits() {
+        let bits = |bytes: u64| HumanSize::from(bytes).bits();
+        assert_eq!(bits(125_000).concise(), "1 Mbit");
+        assert_eq!(bits(125_000).full(), "1 megabit");
+        assert_eq!(bits(187_500).concise(), "1.5 Mbit");
+        assert_eq!(bits(187_500).full(), "1.5 megabits");
+        assert_eq!(bits(12_500_000).concise(), "100 Mbit");
+        assert_eq!(bits(125_000_000).concise(), "1 Gbit");
+        assert_eq!(bits(0).full(), "0 bits");
+        assert_eq!(bits(1).concise(), "8 bit");
+        assert_eq!(bits(1).full(), "8 bits");
+        assert_eq!(bits(124).concise(), "992 bit");
+        // 999.96 kbit rounds up to the next unit
+        assert_eq!(bits(124_995).concise(), "1 Mbit");
+        assert_eq!(bits(u64::MAX).concise(), "147.6 Ebit");
+
+        // decimal() and binary() switch back to bytes
+        assert_eq!(bits(1_500_000).decimal().concise(), "1.5 MB");
+        assert_eq!(bits(1_500_000).binary().bits().concise(), "12 Mbit");
+        assert_eq!(format!("{:#}", bits(187_500)), "1.5 Mbit");
+        assert_eq!(bits(187_500).locale(Locale::Spanish).full(), "1,5 megabits");
+    }
+
+    #[test]
+    fn test_percent_from_ratio() {
+        assert_eq!(HumanPercent::from_ratio(3.0, 8.0, 1).concise(), "37.5%");
+        assert_eq!(
+            HumanPercent::from_ratio(3.0, 8.0, 1).to_string(),
+            "37.5 percent"
+        );
+        assert_eq!(HumanPercent::from_ratio(1.0, 3.0, 2).concise(), "33.33%");
+        assert_eq!(HumanPercent::from_ratio(29.0, 100.0, 0).concise(), "29%");
+        assert_eq!(HumanPercent::from_ratio(5.0, 4.0, 0).concise(), "125%");
+        assert_eq!(HumanPercent::from_ratio(-1.0, 4.0, 1).concise(), "-25.0%");
+        // rounds like `from`
+        assert_eq!(
+            HumanPercent::from_ratio(2.0, 3.0, 1),
+            HumanPercent::from(2.0 / 3.0 * 100.0, 1)
+        );
+
+        // no denominator, no percentage
+        assert_eq!(HumanPercent::from_ratio(3.0, 0.0, 1).concise(), "-");
+        assert_eq!(HumanPercent::from_ratio(0.0, 0.0, 1).to_string(), "-");
+        let empty = HumanPercent::from_ratio(0.0, 0.0, 1).fallback("n/a");
+        assert_eq!(empty.concise(), "n/a");
+        assert_eq!(empty.to_string(), "n/a");
+        assert_eq!(HumanPercent::from(f64::NAN, 1).fallback("—").concise(), "—");
+    }
+
+    #[test]
+    fn test_parse_size() {
+        use crate::{ParseErrorKind, UnitSystem};
+
+        assert_eq!(HumanSize::parse("512MiB"), Ok(536_870_912));
+        assert_eq!(HumanSize::parse("512 mib"), Ok(536_870_912));
+        assert_eq!(HumanSize::parse("512 MIB"), Ok(536_870_912));
+        assert_eq!(HumanSize::parse("1.5 GB"), Ok(1_500_000_000));
+        assert_eq!(HumanSize::parse("1.5GiB"), Ok(1_610_612_736));
+        assert_eq!(HumanSize::parse(" 2048 "), Ok(2_048));
+        assert_eq!(HumanSize::parse("4k"), Ok(4_000));
+        assert_eq!(HumanSize::parse("4Ki"), Ok(4_096));
+
+        let kind = |input: &str| HumanSize::parse(input).unwrap_err().kind();
+        assert_eq!(kind("lots"), ParseErrorKind::InvalidNumber);
+        assert_eq!(kind("-5 MB"), ParseErrorKind::InvalidNumber);
+        assert_eq!(kind("5 XB"), ParseErrorKind::UnknownUnit);
+        assert_eq!(kind("5 MB free"), ParseErrorKind::TrailingInput);
+        assert_eq!(kind(""), ParseErrorKind::Empty);
+        assert_eq!(kind("20 EiB"), ParseErrorKind::OutOfRange);
+        assert_eq!(kind("16 EiB"), ParseErrorKind::OutOfRange);
+        assert_eq!(kind("18446744073709551616"), ParseErrorKind::OutOfRange);
+        assert_eq!(kind("18446744073709551615.5"), ParseErrorKind::OutOfRange);
+        assert_eq!(HumanSize::parse("18446744073709551615"), Ok(u64::MAX));
+        assert_eq!(HumanSize::parse("15 EiB"), Ok(15 << 60));
+
+        let size: HumanSize = "1.5 GB".parse().unwrap();
+        assert_eq!(size, HumanSize::from(1_500_000_000));
+        assert!("1.5 GBs".parse::<HumanSize>().is_err());
+
+        // concise output reads back to within its rounding
+        let mut bytes = 1_u64;
+        while bytes < u64::MAX / 3 {
+            for system in [UnitSystem::Binary, UnitSystem::Decimal] {
+                let mut size = HumanSize::from(bytes);
+                size = match system {
+                    UnitSystem::Binary => size.binary(),
+                    UnitSystem::Decimal => size.decimal(),
+                    // KB reads back as SI
+                    UnitSystem::Jedec => unreachable!(),
+                };
+                let parsed = HumanSize::parse(&size.concise()).unwrap() as f64;
+                let error = (parsed - bytes as f64).abs() / bytes as f64;
+                assert!(error <= 0.05, "{} read back as {}", bytes, parsed);
+            }
+            bytes = bytes * 3 + 1;
+        }
+    }
+
+    #[test]
+    fn test_full_methods() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let duration = HumanDuration::from(Some(now - Duration::from_secs(120))).as_of(now);
+        assert_eq!(duration.full(), "2 minutes ago");
+        assert_eq!(duration.full(), duration.to_string());
+
+        let time = HumanTime::from(Duration::from_secs(90));
+        assert_eq!(time.full(), "1 minute 30 seconds");
+        assert_eq!(time.full(), time.to_string());
+
+        let percent = HumanPercent::from(45.0, 0);
+        assert_eq!(percent.full(), "45 percent");
+        assert_eq!(percent.full(), percent.to_string());
+    }
+
+    #[test]
+    fn test_indian_number_system() {
+        use crate::NumberSystem;
+
+        let indian = |n: f64| HumanNumber::from(n).system(NumberSystem::Indian);
+        assert_eq!(indian(150_000.0).concise(), "1.5L");
+        assert_eq!(indian(150_000.0).full(), "1.5 lakh");
+        assert_eq!(indian(20_000_000.0).concise(), "2Cr");
+        assert_eq!(indian(20_000_000.0).full(), "2 crore");
+        assert_eq!(indian(1_500.0).concise(), "1.5k");
+        assert_eq!(indian(1_500.0).full(), "1.5 thousand");
+        assert_eq!(indian(999.0).full(), "999");
+        assert_eq!(indian(12.34).concise(), "12.3");
+        assert_eq!(indian(10_000_000_000.0).concise(), "1000Cr");
+
+        // moves up once rounding reaches the next unit
+        assert_eq!(indian(99_999.0).concise(), "1L");
+        assert_eq!(indian(99_940.0).concise(), "99.9k");
+        assert_eq!(indian(9_999_999.0).concise(), "1Cr");
+
+        // with precision, negatives and locales
+        assert_eq!(indian(1_234_567.0).precision(2).concise(), "12.35L");
+        assert_eq!(
+            indian(150_000.0)
+                .precision(2)
+                .keep_trailing_zeros()
+                .concise(),
+            "1.50L"
+        );
+        assert_eq!(indian(-150_000.0).concise(), "-1.5L");
+        assert_eq!(indian(-20_000_000.0).full(), "-2 crore");
+        assert_eq!(indian(-0.04).concise(), "0");
+        assert_eq!(indian(150_000.0).locale(Locale::Spanish).full(), "1,5 lakh");
+        assert_eq!(indian(1_500.0).locale(Locale::Spanish).full(), "1,5 mil");
+
+        // Western stays the default
+        assert_eq!(HumanNumber::from(150_000).concise(), "150k");
+        assert_eq!(
+            indian(20_000_000.0).system(NumberSystem::Western).concise(),
+            "20M"
+        );
+    }
+
+    #[test]
+    fn test_signed_size() {
+        let delta = |bytes: i64| HumanSize::from_signed(bytes);
+        assert_eq!(delta(-2_411_725).concise(), "-2.3 MiB");
+        assert_eq!(delta(-2_411_725).full(), "-2.3 mebibytes");
+        assert_eq!(delta(-2_300_000).decimal().concise(), "-2.3 MB");
+        assert_eq!(delta(-2_411_725).decimal().binary().concise(), "-2.3 MiB");
+        assert_eq!(delta(-1_024).full(), "-1 kibibyte");
+        assert_eq!(delta(-1).full(), "-1 byte");
+        assert_eq!(delta(-512).concise(), "-512 B");
+        assert_eq!(delta(2_411_725).concise(), "2.3 MiB");
+        assert_eq!(delta(0).concise(), "0 B");
+        assert_eq!(delta(0), HumanSize::from(0));
+        assert_eq!(delta(i64::MIN).concise(), "-8 EiB");
+        assert_eq!(delta(-125_000).bits().concise(), "-1 Mbit");
+        assert_eq!(format!("{:#}", delta(-1_024)), "-1 KiB");
+
+        // compare() keeps the sign
+        assert_eq!(delta(-100).compare(&delta(-50)).concise(), "2×");
+        assert_eq!(delta(-100).compare(&delta(50)).concise(), "-");
+    }
+
+    #[test]
+    fn test_clock() {
+        let time = |secs: u64| HumanTime::from(Duration::from_secs(secs));
+        assert_eq!(time(3_665).clock(), "1:01:05");
+        assert_eq!(time(452).clock(), "07:32");
+        assert_eq!(time(5).clock(), "00:05");
+        assert_eq!(time(0).clock(), "00:00");
+        assert_eq!(time(129_600).clock(), "36:00:00");
+        assert_eq!(time(452).clock_hours().clock(), "0:07:32");
+        assert_eq!(time(3_665).clock_hours().clock(), "1:01:05");
+
+        let millis = |ms: u64| HumanTime::from(Duration::from_millis(ms));
+        assert_eq!(millis(3_665_250).clock_precision(3).clock(), "1:01:05.250");
+        assert_eq!(millis(3_665_250).clock_precision(1).clock(), "1:01:05.3");
+        assert_eq!(millis(3_665_250).clock(), "1:01:05");
+        // rounding carries into the minute and hour
+        assert_eq!(millis(59_999).clock_precision(2).clock(), "01:00.00");
+        assert_eq!(millis(3_599_600).clock(), "1:00:00");
+        assert_eq!(
+            HumanTime::from(Duration::from_nanos(1))
+                .clock_precision(12)
+                .clock(),
+            "00:00.000000001"
+        );
+
+        // negative, and beside the other forms
+        assert_eq!(
+            HumanTime::from_signed(Duration::from_secs(452), true).clock(),
+            "-07:32"
+        );
+        assert_eq!(
+            HumanTime::from_signed(Duration::ZERO, true).clock(),
+            "00:00"
+        );
+        assert_eq!(time(3_665).concise(), "1h 1m 5s");
+    }
+
+    #[test]
+    fn test_percent_decimals() {
+        assert_eq!(HumanPercent::from(12.3, 2).concise(), "12.30%");
+        assert_eq!(HumanPercent::from(100.0, 2).concise(), "100.00%");
+        assert_eq!(HumanPercent::from(0.0, 1).concise(), "0.0%");
+        assert_eq!(HumanPercent::from(-0.01, 1).concise(), "0.0%");
+        assert_eq!(HumanPercent::from(12.345, 0).concise(), "12%");
+        assert_eq!(HumanPercent::from(12.3, 2).full(), "12.30 percent");
+        assert_eq!(
+            HumanPercent::from(12.3, 2).locale(Locale::Spanish).full(),
+            "12,30 por ciento"
+        );
+
+        // the old trimmed output on request
+        assert_eq!(HumanPercent::from(12.3, 2).trim_zeros().concise(), "12.3%");
+        assert_eq!(HumanPercent::from(100.0, 2).trim_zeros().concise(), "100%");
+        assert_eq!(
+            HumanPercent::from(12.345, 2).trim_zeros().concise(),
+            "12.35%"
+        );
+
+        // Huge decimal counts are capped instead of panicking or wrapping
+        for decimals in [1 << 32, 70_000, usize::MAX] {
+            assert_eq!(
+                HumanPercent::from(12.5, decimals).concise(),
+                format!("12.5{}%", "0".repeat(299))
+            );
+            assert_eq!(
+                HumanPercent::from(12.5, decimals).trim_zeros().concise(),
+                "12.5%"
+            );
+            assert_eq!(
+                HumanPercent::from_ratio(1.0, 8.0, decimals)
+                    .trim_zeros()
+                    .concise(),
+                "12.5%"
+            );
+            assert_eq!(
+                HumanPercent::from(1e10, decimals).trim_zeros().concise(),
+                "10000000000%"
+            );
+        }
+    }
+
+    #[test]
+    fn test_duration_granularity() {
+        use std::time::UNIX_EPOCH;
+
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let ago = |secs| HumanDuration::from(Some(now - Duration::from_secs(secs))).as_of(now);
+
+        // Default stays a single unit
+        assert_eq!(ago(10_740).concise(), "2h ago");
+        assert_eq!(ago(10_740).granularity(2).concise(), "2h 59m ago");
+        assert_eq!(ago(10_740).granularity(2).full(), "2 hours 59 minutes ago");
+        assert_eq!(
+            ago(3 * 86_400 + 4 * 3_600).granularity(2).concise(),
+            "3d 4h ago"
+        );
+
+        // Zero units are skipped and the rest is truncated
+        assert_eq!(ago(3_600 + 5).granularity(2).concise(), "1h 5s ago");
+        assert_eq!(ago(3_661).granularity(3).concise(), "1h 1m 1s ago");
+        assert_eq!(ago(3_661).granularity(0).concise(), "1h ago");
+        assert_eq!(ago(86_400).granularity(2).full(), "yesterday");
+        assert_eq!(ago(86_400 + 60).granularity(2).full(), "1 day 1 minute ago");
+        // Any granularity past the number of units shows every part
+        assert_eq!(ago(3_661).granularity(usize::MAX).concise(), "1h 1m 1s ago");
+        assert_eq!(
+            ago(3_661).granularity(1 << 60).full(),
+            "1 hour 1 minute 1 second ago"
+        );
+
+        let ahead = HumanDuration::from(Some(now + Duration::from_secs(90_000)))
+            .as_of(now)
+            .granularity(2);
+        assert_eq!(ahead.concise(), "1d 1h from now");
+    }
+
+    #[test]
+    fn test_permissions_formats() {
+        let cases = [
+            (
+                0o000,
+                "---------",
+                "Owner cannot do anything; group cannot do anything; others cannot do anything.",
+            ),
+            (
+                0o644,
+                "rw-r--r--",
+                "Owner can read and write; group can read; others can read.",
+            ),
+            (
+                0o4755,
+                "rwsr-xr-x",
+                "Owner can read, write and execute; group can read and execute; others can read and execute. The setuid bit is set.",
+            ),
+            (
+                0o1777,
+                "rwxrwxrwt",
+                "Owner can read, write and execute; group can read, write and execute; others can read, write and execute. The sticky bit is set.",
+            ),
+        ];
+        for (mode, concise, full) in cases {
+            let permissions = HumanPermissions::from(mode);
+            assert_eq!(permissions.concise(), concise);
+            assert_eq!(format!("{:#}", permissions), concise);
+            assert_eq!(permissions.full(), full);
+            assert_eq!(permissions.to_string(), full);
+            assert_eq!(permissions.describe(), full);
+        }
+
+        // File type bits from st_mode are ignored
+        assert_eq!(HumanPermissions::from(0o100644).concise(), "rw-r--r--");
+        assert_eq!(
+            format!("[{:>#11}]", HumanPermissions::from(0o2750)),
+            "[  rwxr-s---]"
+        );
+    }
+
+    #[test]
+    fn test_number_display() {
+        for n in [
+            0.0,
+            7.0,
+            999.0,
+            1_500.0,
+            12_345.0,
+            1_500_000.0,
+            -2_400_000_000.0,
+            9.2e18,
+        ] {
+            let number = HumanNumber::from(n);
+            assert_eq!(number.to_string(), number.full());
+            assert_eq!(format!("{:#}", number), number.concise());
+        }
+        assert_eq!(format!("{}", HumanNumber::from(1500)), "1.5 thousand");
+
+        // Width and fill line values up in tables
+        assert_eq!(format!("[{:>#8}]", HumanNumber::from(1500)), "[    1.5k]");
+        assert_eq!(format!("[{:*<#6}]", HumanNumber::from(42)), "[42****]");
+    }
+
+    #[test]
+    fn test_size_precision() {
+        // Default stays one decimal, trimmed
+        let size = HumanSize::from(10_749_999_999).decimal();
+        assert_eq!(size.concise(), "10.7 GB");
+        assert_eq!(HumanSize::from(10_000_000_000).decimal().concise(), "10 GB");
+
+        assert_eq!(size.precision(2).concise(), "10.75 GB");
+        assert_eq!(size.precision(3).full(), "10.75 gigabytes");
+        assert_eq!(size.precision(0).concise(), "11 GB");
+        assert_eq!(
+            HumanSize::from(10_700_000_000)
+                .decimal()
+                .precision(2)
+                .concise(),
+            "10.7 GB"
+        );
+
+        // Binary sizes, and rounding up into the next unit
+        assert_eq!(
+            HumanSize::from(1_500_000).binary().precision(3).concise(),
+            "1.431 MiB"
+        );
+        assert_eq!(
+            HumanSize::from(1_048_064).binary().precision(0).concise(),
+            "1 MiB"
+        );
+        assert_eq!(
+            HumanSize::from(1_048_064).binary().precision(2).concise(),
+            "1023.5 KiB"
+        );
+        assert_eq!(
+            HumanSize::from(1_536).binary().precision(0).full(),
+            "2 kibibytes"
+        );
+
+        // Trailing zeros for columns
+        let column = |bytes| {
+            HumanSize::from(bytes)
+                .decimal()
+                .precision(2)
+                .keep_trailing_zeros()
+                .concise()
+        };
+        assert_eq!(column(10_000_000_000), "10.00 GB");
+        assert_eq!(column(10_749_999_999), "10.75 GB");
+        assert_eq!(
+            HumanSize::from(2_048).binary().keep_trailing_zeros().full(),
+            "2.0 kibibytes"
+        );
+        assert_eq!(
+            HumanSize::from(512)
+                .precision(2)
+                .keep_trailing_zeros()
+                .concise(),
+            "512 B"
+        );
+
+        // Bits and negative sizes follow the same settings
+        assert_eq!(
+            HumanSize::from(123_456).bits().precision(2).concise(),
+            "987.65 kbit"
+        );
+        assert_eq!(
+            HumanSize::from(125_000)
+                .bits()
+                .precision(2)
+                .keep_trailing_zeros()
+                .concise(),
+            "1.00 Mbit"
+        );
+        assert_eq!(
+            HumanSize::from_signed(-1_500_000)
+                .binary()
+                .precision(2)
+                .concise(),
+            "-1.43 MiB"
+        );
+        assert_eq!(
+            HumanSize::from(u64::MAX)
+                .binary()
+                .precision(usize::MAX)
+                .concise(),
+            "16 EiB"
+        );
+        for precision in [1 << 32, 70_000, usize::MAX] {
+            let size = HumanSize::from(1_536).precision(precision);
+            assert_eq!(size.concise(), "1.5 KiB");
+            assert_eq!(
+                size.keep_trailing_zeros().concise(),
+                format!("1.5{} KiB", "0".repeat(299))
+            );
+            assert_eq!(
+                size.bits().concise(),
+                HumanSize::from(1_536).precision(300).bits().concise()
+            );
+        }
+    }
+
+    #[test]
+    fn test_duration_month_year_boundaries() {
+        use std::time::UNIX_EPOCH;
+
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let days = |n: u64| Duration::from_secs(n * 86_400);
+        let cases = [
+            (29, "4w", "4 weeks"),
+            (30, "4w", "4 weeks"),
+            (31, "1mo", "1 month"),
+            (364, "11mo", "11 months"),
+            (365, "11mo", "11 months"),
+            (366, "1y", "1 year"),
+        ];
+        for (n, concise, full) in cases {
+            let ago = HumanDuration::from(Some(now - days(n))).as_of(now);
+            assert_eq!(ago.concise(), format!("{} ago", concise));
+            assert_eq!(ago.full(), format!("{} ago", full));
+
+            // The future uses the same cutoffs and suffixes
+            let ahead = HumanDuration::from(Some(now + days(n))).as_of(now);
+            assert_eq!(ahead.concise(), format!("{} from now", concise));
+        }
+    }
+
+    #[test]
+    fn test_parse_time() {
+        use crate::{ParseErrorKind, TimeUnit};
+
+        let secs = Duration::from_secs;
+        assert_eq!(HumanTime::parse("90s"), Ok(secs(90)));
+        assert_eq!(HumanTime::parse("1h 30m"), Ok(secs(5_400)));
+        assert_eq!(HumanTime::parse("1h30m"), Ok(secs(5_400)));
+        assert_eq!(HumanTime::parse("2h"), Ok(secs(7_200)));
+        assert_eq!(HumanTime::parse("1.5h"), Ok(secs(5_400)));
+        assert_eq!(HumanTime::parse(" 45 "), Ok(secs(45)));
+        assert_eq!(HumanTime::parse("2d 3h"), Ok(secs(183_600)));
+        assert_eq!(HumanTime::parse("1 hour 30 minutes"), Ok(secs(5_400)));
+
+        let time: HumanTime = "1m 30s".parse().unwrap();
+        assert_eq!(time, HumanTime::from(secs(90)));
+
+        // Errors point at the offending unit
+        let err = HumanTime::parse("1h 2h").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::DuplicateUnit);
+        assert_eq!(err.to_string(), "duplicate unit 'h' at position 4");
+        let err = HumanTime::parse("1h 30q").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::UnknownUnit);
+        assert_eq!(err.position(), 5);
+        assert!("".parse::<HumanTime>().is_err());
+
+        // Concise output reads back to the same whole seconds
+        for n in [
+            0, 1, 59, 60, 61, 3_599, 3_600, 3_661, 86_399, 200_000, 1_234_567,
+        ] {
+            let time = HumanTime::from(secs(n));
+            assert_eq!(HumanTime::parse(&time.concise()), Ok(secs(n)));
+            assert_eq!(HumanTime::parse(&time.full()), Ok(secs(n)));
+            let weeks = time.max_unit(TimeUnit::Week).concise();
+            assert_eq!(HumanTime::parse(&weeks), Ok(secs(n)));
+        }
+    }
+
+    #[test]
+    fn test_number_words() {
+        let cases: [(f64, &str); 16] = [
+            (0.0, "zero"),
+            (7.0, "seven"),
+            (42.0, "forty-two"),
+            (100.0, "one hundred"),
+            (110.0, "one hundred ten"),
+            (1_234.0, "one thousand two hundred thirty-four"),
+            (1_000_010.0, "one million ten"),
+            (1_000_000_001.0, "one billion one"),
+            (3_000_000_000_021.0, "three trillion twenty-one"),
+            (-42.0, "negative forty-two"),
+            (-0.4, "zero"),
+            (2.5, "three"),
+            (-1.6, "negative two"),
+            (f64::NAN, "-"),
+            (f64::INFINITY, "-"),
+            (1e20, "-"),
+        ];
+        for (number, words) in cases {
+            assert_eq!(HumanNumber::from(number).words(), words, "{}", number);
+        }
+    }
+
+    #[test]
+    fn test_size_compact_spacing() {
+        let compact = |bytes| HumanSize::from(bytes).compact_spacing();
+
+        assert_eq!(compact(5_242_880).concise(), "5MiB");
+        assert_eq!(compact(1_500_000).decimal().concise(), "1.5MB");
+        assert_eq!(compact(187_500).bits().concise(), "1.5Mbit");
+        assert_eq!(
+            HumanSize::from_signed(-2_411_725)
+                .compact_spacing()
+                .concise(),
+            "-2.3MiB"
+        );
+        assert_eq!(compact(1_500_000).concise(), "1.4MiB");
+
+        // Whole bytes too; the full form is unchanged
+        assert_eq!(compact(500).concise(), "500B");
+        assert_eq!(compact(500).bare_bytes().concise(), "500");
+        assert_eq!(compact(5_242_880).full(), "5 mebibytes");
+        assert_eq!(compact(5_242_880).to_string(), "5 mebibytes");
+        assert_eq!(format!("{:#}", compact(5_242_880)), "5MiB");
+    }
+
+    #[test]
+    fn test_duration_from_elapsed() {
+        assert_eq!(HumanDuration::from_secs(0).concise(), "just now");
+        assert_eq!(HumanDuration::from_secs(90).concise(), "1m ago");
+        assert_eq!(HumanDuration::from_secs(90).full(), "1 minute ago");
+        assert_eq!(HumanDuration::from_secs(86_400).full(), "yesterday");
+        assert_eq!(
+            HumanDuration::from_duration(Duration::from_millis(3_599_999)).concise(),
+            "59m ago"
+        );
+        assert_eq!(
+            HumanDuration::from_secs(7_200).future().concise(),
+            "2h from now"
+        );
+        assert_eq!(HumanDuration::from_secs(86_400).future().full(), "tomorrow");
+        assert_eq!(
+            HumanDuration::from_secs(10_740).granularity(2).concise(),
+            "2h 59m ago"
+        );
+
+        // Anchoring to another time changes nothing without a timestamp
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        assert_eq!(HumanDuration::from_secs(90).as_of(now).concise(), "1m ago");
+
+        // `future` only applies to elapsed durations
+        let past = HumanDuration::from(Some(now - Duration::from_secs(90))).as_of(now);
+        assert_eq!(past.future().concise(), "1m ago");
+    }
+
+    #[test]
+    fn test_percent_clamp() {
+        assert_eq!(HumanPercent::from(100.4, 1).clamp().concise(), "100.0%");
+        assert_eq!(HumanPercent::from(100.4, 1).clamp().full(), "100.0 percent");
+        assert_eq!(HumanPercent::from(-0.2, 1).clamp().concise(), "0.0%");
+        assert_eq!(HumanPercent::from(42.25, 1).clamp().concise(), "42.3%");
+        assert_eq!(
+            HumanPercent::from(f64::INFINITY, 0).clamp().concise(),
+            "100%"
+        );
+        assert_eq!(
+            HumanPercent::from(f64::NEG_INFINITY, 0).clamp().concise(),
+            "0%"
+        );
+        assert_eq!(HumanPercent::from(f64::NAN, 0).clamp().concise(), "-");
+        assert_eq!(HumanPercent::from_ratio(3.0, 0.0, 1).clamp().concise(), "-");
+        assert_eq!(HumanPercent::from_ratio(-3.0, 0.0, 1).clamp().full(), "-");
+        assert_eq!(
+            HumanPercent::from_ratio(0.0, 0.0, 0)
+                .clamp()
+                .fallback("n/a")
+                .concise(),
+            "n/a"
+        );
+
+        // Unclamped by default
+        assert_eq!(HumanPercent::from(100.4, 1).concise(), "100.4%");
+        assert_eq!(HumanPercent::from(-0.2, 1).concise(), "-0.2%");
+        assert_eq!(HumanPercent::from(f64::INFINITY, 0).concise(), "-");
+    }
+
+    #[test]
+    fn test_number_from_primitives() {
+        // Every primitive is accepted as is
+        assert_eq!(HumanNumber::from(1_500_u16).concise(), "1.5k");
+        assert_eq!(HumanNumber::from(1_500_000_u32).concise(), "1.5M");
+        assert_eq!(HumanNumber::from(1_500_000_usize).concise(), "1.5M");
+        assert_eq!(HumanNumber::from(-1_500_000_isize).concise(), "-1.5M");
+        assert_eq!(HumanNumber::from(-2_000_000_000_i64).full(), "-2 billion");
+        assert_eq!(HumanNumber::from(7_u8).concise(), "7");
+        assert_eq!(HumanNumber::from(-7_i8).concise(), "-7");
+        assert_eq!(HumanNumber::from(1.5e3_f32).concise(), "1.5k");
+        let number: HumanNumber = 1_500_u64.into();
+        assert_eq!(number.concise(), "1.5k");
+
+        // Wide integers are scaled without going through f64: it rounds
+        // 999_949_999_999_999_999 up to 999_950_000_000_000_000, "1Qi"
+        assert_eq!(
+            HumanNumber::from(999_949_999_999_999_999.0).concise(),
+            "1Qi"
+        );
+        assert_eq!(
+            HumanNumber::from(999_949_999_999_999_999_u64).concise(),
+            "999.9Q"
+        );
+        assert_eq!(HumanNumber::from(u64::MAX).concise(), "18.4Qi");
+        assert_eq!(HumanNumber::from(i64::MIN).concise(), "-9.2Qi");
+        assert_eq!(
+            HumanNumber::from(u128::MAX).concise(),
+            "340282366920938463463.4Qi"
+        );
+        assert_eq!(
+            HumanNumber::from(u128::MAX).full(),
+            "340282366920938463463.4 quintillion"
+        );
+        assert_eq!(
+            HumanNumber::from(i128::MIN).precision(3).concise(),
+            "-170141183460469231731.687Qi"
+        );
+        assert_eq!(
+            HumanNumber::from(i128::MAX).precision(0).concise(),
+            "170141183460469231732Qi"
+        );
+        assert_eq!(HumanNumber::from(1_234_567_u128).concise(), "1.2M");
+
+        let mut buf = [0u8; HumanNumber::MAX_CONCISE_LEN];
+        assert!(
+            HumanNumber::from(u128::MAX)
+                .format_into(&mut buf, crate::Style::Concise)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_time_subsecond() {
+        let time = |d: Duration| HumanTime::from(d);
+
+        assert_eq!(time(Duration::from_millis(42)).concise(), "42ms");
+        assert_eq!(time(Duration::from_millis(42)).full(), "42 milliseconds");
+        assert_eq!(time(Duration::from_nanos(999_999)).concise(), "999µs");
+        assert_eq!(time(Duration::from_nanos(120)).concise(), "120ns");
+        assert_eq!(time(Duration::from_nanos(1)).full(), "1 nanosecond");
+        assert_eq!(time(Duration::from_micros(1_500)).concise(), "1ms");
+        assert_eq!(
+            time(Duration::from_millis(1))
+                .locale(Locale::Spanish)
+                .full(),
+            "1 milisegundo"
+        );
+        assert_eq!(time(Duration::ZERO).concise(), "0s");
+        assert_eq!(time(Duration::ZERO).precise().full(), "0 seconds");
+
+        // Longer durations drop the remainder unless asked
+        assert_eq!(time(Duration::from_millis(90_250)).concise(), "1m 30s");
+        assert_eq!(
+            time(Duration::from_millis(90_250)).precise().concise(),
+            "1m 30s 250ms"
+        );
+        assert_eq!(
+            time(Duration::new(3_600, 5_000)).precise().concise(),
+            "1h 0m 5µs"
+        );
+        assert_eq!(time(Duration::from_secs(60)).precise().concise(), "1m");
+        assert_eq!(
+            time(Duration::from_millis(42)).into_parts(crate::Style::Concise)[0].unit,
+            "ms"
+        );
+    }
+
+    #[test]
+    fn test_duration_future() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let ahead =
+            |secs: u64| HumanDuration::from(Some(now + Duration::from_secs(secs))).as_of(now);
+
+        let cases = [
+            (45, "45s from now", "45 seconds from now"),
+            (120, "2m from now", "2 minutes from now"),
+            (7_200, "2h from now", "2 hours from now"),
+            (86_400, "1d from now", "tomorrow"),
+            (172_800, "2d from now", "2 days from now"),
+            (1_209_600, "2w from now", "2 weeks from now"),
+            (5_259_492, "2mo from now", "2 months from now"),
+            (31_556_952, "1y from now", "1 year from now"),
+            (63_113_904, "2y from now", "2 years from now"),
+        ];
+        for (secs, concise, full) in cases {
+            assert_eq!(ahead(secs).concise(), concise);
+            assert_eq!(ahead(secs).full(), full);
+        }
+
+        assert_eq!(
+            ahead(10_740).granularity(2).full(),
+            "2 hours 59 minutes from now"
+        );
+        assert_eq!(
+            HumanDuration::from_secs(60).future().full(),
+            "1 minute from now"
+        );
+        assert_eq!(
+            ahead(7_200).locale(Locale::Spanish).full(),
+            "dentro de 2 horas"
+        );
+        assert_eq!(ahead(86_400).locale(Locale::Spanish).full(), "mañana");
+        assert_eq!(
+            ahead(7_200).into_parts(crate::Style::Full).qualifier,
+            " from now"
+        );
+    }
+
+    #[test]
+    fn test_size_jedec() {
+        use crate::{Config, HumanRange, UnitSystem};
+
+        assert_eq!(HumanSize::from(1_048_576).jedec().concise(), "1 MB");
+        assert_eq!(HumanSize::from(1_048_576).jedec().full(), "1 megabyte");
+        assert_eq!(HumanSize::from(1_048_576).binary().concise(), "1 MiB");
+        assert_eq!(HumanSize::from(1_048_576).decimal().concise(), "1 MB");
+        assert_eq!(HumanSize::from(1_000_000).decimal().concise(), "1 MB");
+        assert_eq!(HumanSize::from(1_000_000).jedec().concise(), "976.6 KB");
+        assert_eq!(HumanSize::from(1_536).jedec().full(), "1.5 kilobytes");
+        assert_eq!(HumanSize::from(500).jedec().concise(), "500 B");
+        assert_eq!(HumanSize::from(1_047_552).jedec().concise(), "1023 KB");
+
+        // Chains like the other systems; the last one wins
+        assert_eq!(
+            HumanSize::from(1_048_576).jedec().binary().concise(),
+            "1 MiB"
+        );
+        assert_eq!(
+            HumanSize::from(1_048_576).binary().jedec().concise(),
+            "1 MB"
+        );
+        assert_eq!(HumanSize::from(1_048_576).bits().jedec().concise(), "1 MB");
+
+        let config = Config::default().unit_system(UnitSystem::Jedec);
+        Config::with(config, |_| {
+            assert_eq!(HumanSize::from(5_368_709_120).concise(), "5 GB");
+        });
+        assert_eq!(
+            HumanRange::sizes(1_048_576, 3_145_728).jedec().concise(),
+            "1–3 MB"
+        );
+        let labels: Vec<String> = HumanSize::ticks(0, 2_097_152, 3, UnitSystem::Jedec)
+            .into_iter()
+            .map(|tick| tick.label)
+            .collect();
+        assert_eq!(labels, ["0 B", "1 MB", "2 MB"]);
+    }
+
+    #[test]
+    fn test_count_grouping() {
+        use crate::HumanCount;
+
+        assert_eq!(HumanCount::from(0).concise(), "0");
+        assert_eq!(HumanCount::from(999).concise(), "999");
+        assert_eq!(HumanCount::from(1000).concise(), "1,000");
+        assert_eq!(HumanCount::from(1_234_567).concise(), "1,234,567");
+        assert_eq!(HumanCount::from(1_234_567).full(), "1,234,567");
+        assert_eq!(HumanCount::from(-1_234_567).concise(), "-1,234,567");
+        assert_eq!(HumanCount::from(-123_456).concise(), "-123,456");
+        assert_eq!(HumanCount::from(-999).concise(), "-999");
+        assert_eq!(HumanCount::from(1234.5678).concise(), "1,234.5678");
+        assert_eq!(HumanCount::from(-1234.5678).concise(), "-1,234.5678");
+        assert_eq!(HumanCount::from(0.00012345).concise(), "0.00012345");
+        assert_eq!(HumanCount::from(-0.0).concise(), "0");
+        assert_eq!(HumanCount::from(f64::NAN).concise(), "-");
+        assert_eq!(
+            HumanCount::from(u64::MAX).concise(),
+            "18,446,744,073,709,551,615"
+        );
+        assert_eq!(
+            HumanCount::from(i64::MIN).concise(),
+            "-9,223,372,036,854,775,808"
+        );
+        assert_eq!(HumanCount::from(u128::MAX).concise().len(), 51);
+        assert_eq!(HumanCount::from(1_500_u16).to_string(), "1,500");
+
+        let downloads = HumanCount::from(1_234_567_u64).unit("downloads");
+        assert_eq!(downloads.concise(), "1,234,567");
+        assert_eq!(downloads.full(), "1,234,567 downloads");
+        assert_eq!(format!("{:>12}", HumanCount::from(12_345)), "      12,345");
+    }
+
+    #[test]
+    fn test_count_separators() {
+        use crate::HumanCount;
+
+        let european = HumanCount::from(1_234_567.89)
+            .separator('.')
+            .decimal_mark(',');
+        assert_eq!(european.concise(), "1.234.567,89");
+        assert_eq!(
+            HumanCount::from(1_234_567).separator(' ').concise(),
+            "1 234 567"
+        );
+        assert_eq!(
+            HumanCount::from(1_234_567).separator('\'').concise(),
+            "1'234'567"
+        );
+        assert_eq!(
+            HumanCount::from(-1_234_567).separator('\u{2009}').full(),
+            "-1\u{2009}234\u{2009}567"
+        );
+        // the fractional digits are never grouped
+        assert_eq!(
+            HumanCount::from(0.123456789).separator(' ').concise(),
+            "0.123456789"
+        );
+
+        // taking the other mark's character swaps the two
+        assert_eq!(HumanCount::from(1234.5).separator('.').concise(), "1.234,5");
+        assert_eq!(
+            HumanCount::from(1234.5).decimal_mark(',').concise(),
+            "1.234,5"
+        );
+        assert_eq!(
+            HumanCount::from(1234.5)
+                .decimal_mark(',')
+                .separator('.')
+                .concise(),
+            "1.234,5"
+        );
+    }
+
+    #[test]
+    fn test_duration_calendar() {
+        use std::time::UNIX_EPOCH;
+
+        // 2024-03-12 00:00 UTC, a Tuesday
+        let midnight = UNIX_EPOCH + Duration::from_secs(1_710_201_600);
+        let at = |now: SystemTime, secs: i64| {
+            let then = if secs < 0 {
+                now - Duration::from_secs(secs.unsigned_abs())
+            } else {
+                now + Duration::from_secs(secs as u64)
+            };
+            HumanDuration::from(Some(then)).as_of(now).calendar()
+        };
+
+        // a second either side of midnight is a different day
+        let just_after = midnight + Duration::from_secs(1);
+        assert_eq!(at(just_after, -2).full(), "yesterday");
+        assert_eq!(at(just_after, -1).full(), "today");
+        assert_eq!(at(midnight - Duration::from_secs(1), 2).full(), "tomorrow");
+        assert_eq!(at(midnight, 0).full(), "just now");
+
+        // 20 hours ago at 6am was yesterday morning; 30 hours ago at 1am
+        // was the day before yesterday
+        let six = midnight + Duration::from_secs(6 * 3_600);
+        assert_eq!(at(six, -20 * 3_600).full(), "yesterday");
+        let one = midnight + Duration::from_secs(3_600);
+        assert_eq!(at(one, -30 * 3_600).full(), "last Sunday");
+        assert_eq!(at(one, -30 * 3_600).concise(), "last Sun");
+        let plain = HumanDuration::from(Some(one - Duration::from_secs(30 * 3_600)));
+        assert_eq!(plain.as_of(one).full(), "yesterday");
+
+        // weekdays up to six days away, then the usual count
+        assert_eq!(at(six, -6 * 86_400).full(), "last Wednesday");
+        assert_eq!(at(six, -7 * 86_400).full(), "1 week ago");
+        assert_eq!(at(six, 3 * 86_400).full(), "next Friday");
+        assert_eq!(at(six, 7 * 86_400).concise(), "1w from now");
+
+        // midnight follows the offset: 23:00 UTC is already Wednesday at +2h
+        let late = midnight + Duration::from_secs(23 * 3_600);
+        assert_eq!(at(late, -22 * 3_600).full(), "today");
+        assert_eq!(at(late, -22 * 3_600).utc_offset(120).full(), "yesterday");
+        assert_eq!(at(late, -2 * 3_600).utc_offset(-60).full(), "today");
+
+        // elapsed durations are placed before `as_of`
+        let elapsed = HumanDuration::from_secs(20 * 3_600).as_of(six).calendar();
+        assert_eq!(elapsed.full(), "yesterday");
+        assert_eq!(elapsed.future().locale(Locale::Spanish).full(), "mañana");
+        assert_eq!(
+            at(six, -3 * 86_400).locale(Locale::Spanish).full(),
+            "el sábado pasado"
+        );
+    }
+
+    #[test]
+    fn test_time_iso8601() {
+        use crate::ParseErrorKind;
+
+        let iso = |secs: u64, nanos: u32| HumanTime::from(Duration::new(secs, nanos)).iso8601();
+        assert_eq!(iso(0, 0), "PT0S");
+        assert_eq!(iso(5, 0), "PT5S");
+        assert_eq!(iso(5_400, 0), "PT1H30M");
+        assert_eq!(iso(183_845, 0), "P2DT3H4M5S");
+        assert_eq!(iso(86_400, 0), "P1D");
+        assert_eq!(iso(86_460, 0), "P1DT1M");
+        assert_eq!(iso(0, 500_000_000), "PT0.5S");
+        assert_eq!(iso(60, 1), "PT1M0.000000001S");
+        assert_eq!(
+            iso(u64::MAX, 999_999_999),
+            "P213503982334601DT7H15.999999999S"
+        );
+        assert_eq!(
+            HumanTime::from_signed(Duration::from_secs(90), true).iso8601(),
+            "-PT1M30S"
+        );
+
+        let parse = HumanTime::parse_iso8601;
+        assert_eq!(parse("PT1H30M"), Ok(Duration::from_secs(5_400)));
+        assert_eq!(parse(" p1w2dt3h "), Ok(Duration::from_secs(788_400)));
+        assert_eq!(parse("PT1.5H"), Ok(Duration::from_secs(5_400)));
+        assert_eq!(parse("PT0,25S"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse("PT0S"), Ok(Duration::ZERO));
+        // every output reads back to the same duration
+        for (secs, nanos) in [(0, 0), (183_845, 0), (60, 1), (u64::MAX, 999_999_999)] {
+            let duration = Duration::new(secs, nanos);
+            assert_eq!(parse(&HumanTime::from(duration).iso8601()), Ok(duration));
+        }
+
+        let kind = |input: &str| parse(input).unwrap_err().kind();
+        assert_eq!(kind(""), ParseErrorKind::Empty);
+        assert_eq!(kind("1H"), ParseErrorKind::UnknownUnit);
+        assert_eq!(kind("P1Y"), ParseErrorKind::UnknownUnit);
+        assert_eq!(kind("P1M"), ParseErrorKind::UnknownUnit);
+        assert_eq!(kind("PT1D"), ParseErrorKind::UnknownUnit);
+        assert_eq!(kind("PT5"), ParseErrorKind::UnknownUnit);
+        assert_eq!(kind("PT1H2H"), ParseErrorKind::DuplicateUnit);
+        assert_eq!(kind("P"), ParseErrorKind::InvalidNumber);
+        assert_eq!(kind("P1DT"), ParseErrorKind::InvalidNumber);
+        assert_eq!(kind("PT1.S"), ParseErrorKind::InvalidNumber);
+        assert_eq!(kind("PTS"), ParseErrorKind::InvalidNumber);
+        assert_eq!(kind("P99999999999999999999D"), ParseErrorKind::OutOfRange);
+        assert_eq!(kind("P213503982334602D"), ParseErrorKind::OutOfRange);
+        assert_eq!(
+            parse("P1M").unwrap_err().to_string(),
+            "unknown unit 'M' at position 2 (expected one of W, D, T, H, M, S)"
+        );
+    }
+
+    #[test]
+    fn test_percent_signed() {
+        let signed = |value: f64, decimals: usize| HumanPercent::from(value, decimals).signed();
+
+        assert_eq!(signed(2.5, 1).concise(), "+2.5%");
+        assert_eq!(signed(2.5, 1).full(), "+2.5 percent");
+        assert_eq!(signed(-0.3, 1).concise(), "-0.3%");
+        assert_eq!(signed(-0.3, 1).full(), "-0.3 percent");
+        assert_eq!(signed(0.0, 1).concise(), "0.0%");
+        // the sign follows the rounded value
+        assert_eq!(signed(-0.04, 1).concise(), "0.0%");
+        assert_eq!(signed(0.04, 1).concise(), "0.0%");
+        assert_eq!(signed(0.05, 1).concise(), "+0.1%");
+        assert_eq!(signed(0.0, 0).zero_sign("±").concise(), "±0%");
+        assert_eq!(signed(0.4, 0).zero_sign("±").concise(), "±0%");
+        assert_eq!(signed(12.30, 2).trim_zeros().concise(), "+12.3%");
+        assert_eq!(signed(f64::NAN, 1).concise(), "-");
+        assert_eq!(HumanPercent::from(2.5, 1).concise(), "2.5%");
+        assert_eq!(
+            signed(2.5, 1).locale(Locale::Spanish).full(),
+            "+2,5 por ciento"
+        );
+    }
+
+    #[test]
+    fn test_size_fixed_unit() {
+        use crate::SizeUnit;
+
+        let size = |bytes: u64, unit: SizeUnit| HumanSize::from(bytes).unit(unit);
+
+        assert_eq!(size(512, SizeUnit::MiB).concise(), "0 MiB");
+        assert_eq!(
+            size(512, SizeUnit::MiB).precision(4).concise(),
+            "0.0005 MiB"
+        );
+        assert_eq!(size(5_368_709_120, SizeUnit::MiB).concise(), "5120 MiB");
+        assert_eq!(size(5_368_709_120, SizeUnit::MiB).full(), "5120 mebibytes");
+        assert_eq!(size(1_048_576, SizeUnit::MiB).full(), "1 mebibyte");
+        assert_eq!(
+            size(1_572_864, SizeUnit::MiB).precision(2).concise(),
+            "1.5 MiB"
+        );
+        assert_eq!(
+            size(1_572_864, SizeUnit::MiB)
+                .precision(2)
+                .keep_trailing_zeros()
+                .concise(),
+            "1.50 MiB"
+        );
+        assert_eq!(size(2_500_000, SizeUnit::KB).concise(), "2500 kB");
+        assert_eq!(
+            size(2_500_000, SizeUnit::GB).precision(4).concise(),
+            "0.0025 GB"
+        );
+        assert_eq!(size(5_368_709_120, SizeUnit::B).concise(), "5368709120 B");
+        assert_eq!(size(5_368_709_120, SizeUnit::B).full(), "5368709120 bytes");
+        assert_eq!(size(u64::MAX, SizeUnit::EiB).concise(), "16 EiB");
+        assert_eq!(size(u64::MAX, SizeUnit::YB).precision(2).concise(), "0 YB");
+        assert_eq!(
+            size(u64::MAX, SizeUnit::ZiB).precision(3).concise(),
+            "0.016 ZiB"
+        );
+        assert_eq!(
+            HumanSize::from_signed(-1_048_576)
+                .unit(SizeUnit::KiB)
+                .concise(),
+            "-1024 KiB"
+        );
+        // the sign follows the rounded value, so there is no "-0 MiB"
+        let tiny_loss = HumanSize::from_signed(-10).unit(SizeUnit::MiB);
+        assert_eq!(tiny_loss.precision(0).concise(), "0 MiB");
+        assert_eq!(tiny_loss.precision(0).full(), "0 mebibytes");
+        assert_eq!(
+            HumanSize::from_signed(-524_288)
+                .unit(SizeUnit::MiB)
+                .concise(),
+            "-0.5 MiB"
+        );
+
+        // a unit system or bits picks the unit again
+        assert_eq!(
+            size(5_368_709_120, SizeUnit::MiB).binary().concise(),
+            "5 GiB"
+        );
+        assert_eq!(size(125_000, SizeUnit::MiB).bits().concise(), "1 Mbit");
+    }
+
+    #[test]
+    fn test_number_threshold() {
+        let million = |number: f64| HumanNumber::from(number).threshold(1_000_000);
+
+        assert_eq!(million(1_200.0).concise(), "1,200");
+        assert_eq!(million(1_200.0).full(), "1,200");
+        assert_eq!(million(999_999.0).concise(), "999,999");
+        assert_eq!(million(1_000_000.0).concise(), "1M");
+        assert_eq!(million(1_200_000.0).full(), "1.2 million");
+        // the rounded value decides, so 999,999.96 is not "1,000,000"
+        assert_eq!(million(999_999.96).concise(), "1M");
+        assert_eq!(million(-1_234.56).concise(), "-1,234.6");
+        assert_eq!(million(-0.04).concise(), "0");
+        assert_eq!(million(12.0).concise(), "12");
+        assert_eq!(
+            million(1_234.5)
+                .precision(2)
+                .keep_trailing_zeros()
+                .concise(),
+            "1,234.50"
+        );
+        assert_eq!(million(1_234.5).locale(Locale::Spanish).full(), "1.234,5");
+
+        // integers keep every digit
+        assert_eq!(
+            HumanNumber::from(u64::MAX - 1)
+                .threshold(u64::MAX)
+                .concise(),
+            "18,446,744,073,709,551,614"
+        );
+        assert_eq!(
+            HumanNumber::from(1.5e19).threshold(u64::MAX).concise(),
+            "15,000,000,000,000,000,000"
+        );
+        assert_eq!(HumanNumber::from(1_200).threshold(0).concise(), "1.2k");
+        assert_eq!(
+            HumanNumber::from(f64::NAN).threshold(u64::MAX).concise(),
+            HumanNumber::from(f64::NAN).concise()
+        );
+    }
+
+    #[test]
+    fn test_duration_just_now_window() {
+        use std::time::UNIX_EPOCH;
+
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let window = Duration::from_secs(30);
+        let past = |secs: u64, millis: u64| {
+            let then = now - Duration::from_secs(secs) - Duration::from_millis(millis);
+            HumanDuration::from(Some(then)).as_of(now)
+        };
+        let ahead =
+            |secs: u64| HumanDuration::from(Some(now + Duration::from_secs(secs))).as_of(now);
+
+        // the default stays at one second
+        assert_eq!(past(0, 999).concise(), "just now");
+        assert_eq!(past(1, 0).concise(), "1s ago");
+        assert_eq!(ahead(1).concise(), "1s from now");
+
+        // either side of the boundary second, both directions
+        assert_eq!(past(29, 999).just_now_within(window).full(), "just now");
+        assert_eq!(past(30, 0).just_now_within(window).full(), "30 seconds ago");
+        assert_eq!(ahead(29).just_now_within(window).full(), "just now");
+        assert_eq!(
+            ahead(30).just_now_within(window).full(),
+            "30 seconds from now"
+        );
+        assert_eq!(
+            HumanDuration::from_secs(29)
+                .future()
+                .just_now_within(window)
+                .concise(),
+            "just now"
+        );
+
+        // a window under a second still shows sub-second times as "just now"
+        assert_eq!(
+            past(0, 500).just_now_within(Duration::ZERO).concise(),
+            "just now"
+        );
+        assert_eq!(
+            past(10, 0)
+                .just_now_within(window)
+                .locale(Locale::Spanish)
+                .full(),
+            "justo ahora"
+        );
+        assert_eq!(HumanDuration::default(), HumanDuration::from(None));
+    }
+
+    #[test]
+    fn test_time_approximate() {
+        let eta = |secs: u64| HumanTime::from(Duration::from_secs(secs)).approximate();
+
+        assert_eq!(eta(0).full(), "less than a minute");
+        assert_eq!(eta(59).concise(), "<1m");
+        assert_eq!(eta(60).full(), "about 1 minute");
+        assert_eq!(eta(89).concise(), "~1m");
+        assert_eq!(eta(90).concise(), "~2m");
+        assert_eq!(eta(2_700).full(), "about 45 minutes");
+        // 59m 30s rounds up into the next unit
+        assert_eq!(eta(3_569).full(), "about 59 minutes");
+        assert_eq!(eta(3_570).full(), "about 1 hour");
+
+        // the midpoints of an hour: about, over, then up to the next
+        assert_eq!(eta(4_499).full(), "about 1 hour");
+        assert_eq!(eta(4_500).full(), "over 1 hour");
+        assert_eq!(eta(4_500).concise(), ">1h");
+        assert_eq!(eta(5_399).full(), "over 1 hour");
+        assert_eq!(eta(5_400).full(), "about 2 hours");
+        assert_eq!(eta(6_792).full(), "about 2 hours");
+        assert_eq!(eta(8_400).full(), "over 2 hours");
+        assert_eq!(eta(84_600).full(), "about 1 day");
+        assert_eq!(eta(129_600).concise(), "~2d");
+        assert_eq!(eta(1_209_600).full(), "about 14 days");
+
+        // the largest unit follows max_unit
+        assert_eq!(
+            eta(200_000).max_unit(TimeUnit::Hour).full(),
+            "about 56 hours"
+        );
+        assert_eq!(
+            eta(1_209_600).max_unit(TimeUnit::Week).full(),
+            "about 2 weeks"
+        );
+        assert_eq!(eta(561_600).max_unit(TimeUnit::Week).full(), "about 1 week");
+
+        // the exact forms are untouched
+        assert_eq!(
+            HumanTime::from(Duration::from_secs(6_792)).concise(),
+            "1h 53m 12s"
+        );
+        assert_eq!(eta(6_792).clock(), "1:53:12");
+        assert_eq!(
+            HumanTime::from_signed(Duration::from_secs(7_200), true)
+                .approximate()
+                .concise(),
+            "~-2h"
+        );
+        assert_eq!(eta(8_400).locale(Locale::Spanish).full(), "más de 2 horas");
+        assert_eq!(eta(30).locale(Locale::Spanish).full(), "menos de un minuto");
+        assert_eq!(
+            HumanTime::from(Duration::MAX).approximate().concise(),
+            ">213503982334601d"
+        );
+    }
+
+    #[test]
+    fn test_parse_permissions() {
+        use crate::{HumanPermissions, ParseErrorKind};
+
+        let parse = HumanPermissions::parse;
+        assert_eq!(parse("rwsr-xr-x"), Ok(0o4755));
+        assert_eq!(parse("rwxrwxrwt"), Ok(0o1777));
+        assert_eq!(parse("rwSr-Sr-T"), Ok(0o7644));
+        assert_eq!(parse("rwxr-x---"), Ok(0o750));
+        assert_eq!(parse("---------"), Ok(0));
+        assert_eq!(parse("drwxr-xr-x"), Ok(0o755));
+        assert_eq!(parse(" lrwxrwxrwx\n"), Ok(0o777));
+        assert_eq!(
+            "rw-r--r--".parse::<HumanPermissions>(),
+            Ok(HumanPermissions::from(0o644))
+        );
+
+        // every mode survives the round trip
+        for mode in 0..=0o7777 {
+            assert_eq!(parse(&HumanPermissions::from(mode).concise()), Ok(mode));
+        }
+        // the file type bits of st_mode are not part of the result
+        assert_eq!(
+            parse(&HumanPermissions::from(0o100644).concise()),
+            Ok(0o644)
+        );
+
+        let err = |input: &str| parse(input).unwrap_err();
+        assert_eq!(err("").kind(), ParseErrorKind::Empty);
+        assert_eq!(
+            err("rwxr-x").to_string(),
+            "invalid mode 'rwxr-x' at position 0"
+        );
+        assert_eq!(err("rwxr-xr-x+").kind(), ParseErrorKind::InvalidMode);
+        assert_eq!(err("-rwxr-xr-x-").kind(), ParseErrorKind::InvalidMode);
+        assert_eq!(
+            err("xrwxr-xr-x").to_string(),
+            "invalid mode 'x' at position 0 (expected one of -, b, c, d, D, l, p, s)"
+        );
+        assert_eq!(
+            err("rwxr-xr-s").to_string(),
+            "invalid mode 's' at position 8 (expected one of x, t, T, -)"
+        );
+        assert_eq!(
+            err("wrxr-xr-x").to_string(),
+            "invalid mode 'w' at position 0 (expected one of r, -)"
+        );
+        assert_eq!(err(" rwxr-xr-t7").position(), 1);
+        assert_eq!(err("rwxr-xr-é").position(), 8);
+    }
+
+    #[test]
+    fn test_parse_human_number() {
+        use crate::ParseErrorKind;
+
+        assert_eq!(HumanNumber::parse("3.4M"), Ok(3_400_000.0));
+        assert_eq!(HumanNumber::parse("10k"), Ok(10_000.0));
+        assert_eq!(HumanNumber::parse("10K"), Ok(10_000.0));
+        assert_eq!(HumanNumber::parse("1.2b"), Ok(1_200_000_000.0));
+        assert_eq!(HumanNumber::parse(" 2.5 M "), Ok(2_500_000.0));
+        assert_eq!(HumanNumber::parse("1.1Qi"), Ok(1.1e18));
+        assert_eq!(HumanNumber::parse("3.4 million"), Ok(3_400_000.0));
+        assert_eq!(HumanNumber::parse("3.4 Million"), Ok(3_400_000.0));
+        assert_eq!(HumanNumber::parse("1 thousand"), Ok(1_000.0));
+        assert_eq!(HumanNumber::parse("2 quintillion"), Ok(2e18));
+        assert_eq!(HumanNumber::parse("1,234,567"), Ok(1_234_567.0));
+        assert_eq!(HumanNumber::parse("1,234.5k"), Ok(1_234_500.0));
+        assert_eq!(HumanNumber::parse("-1,500"), Ok(-1_500.0));
+        assert_eq!(
+            "2.5k".parse::<HumanNumber>().map(|n| n.concise()),
+            Ok("2.5k".to_string())
+        );
+
+        let err = HumanNumber::parse("3.4 zillion").unwrap_err();
+        assert_eq!(err.kind(), ParseErrorKind::UnknownUnit);
+        assert_eq!(err.fragment(), "zillion");
+        assert_eq!(err.position(), 4);
+        assert_eq!(HumanNumber::parse("3.4X").unwrap_err().fragment(), "X");
+        for malformed in [
+            "1,23", "12,3456", "1234,567", ",123", "1,", "1.5,000", "1..5k", ".",
+        ] {
+            let err = HumanNumber::parse(malformed).unwrap_err();
+            assert_eq!(err.kind(), ParseErrorKind::InvalidNumber, "{}", malformed);
+        }
+        assert_eq!(
+            HumanNumber::parse("").unwrap_err().kind(),
+            ParseErrorKind::Empty
+        );
+        assert_eq!(
+            HumanNumber::parse("3M apples").unwrap_err().kind(),
+            ParseErrorKind::TrailingInput
+        );
+
+        // Both styles read back to within the rounding of their one decimal
+        let mut value: f64 = 0.37;
+        while value < 1e21 {
+            for number in [value, -value, value.round()] {
+                let formatted = HumanNumber::from(number);
+                for text in [formatted.concise(), formatted.full()] {
+                    let parsed = HumanNumber::parse(&text).unwrap();
+                    let tolerance = (number.abs() * 0.05).max(0.05);
+                    assert!(
+                        (parsed - number).abs() <= tolerance,
+                        "{} -> {} -> {}",
+                        number,
+                        text,
+                        parsed
+                    );
+                }
+            }
+            value *= 1.37;
+        }
+    }
+
+    #[test]
+    fn test_size_arithmetic() {
+        let binary = HumanSize::from(1_536).binary();
+        let decimal = HumanSize::from(1_000).decimal();
+
+        // The left-hand operand's unit system wins
+        assert_eq!((binary + decimal).concise(), "2.5 KiB");
+        assert_eq!((decimal + binary).concise(), "2.5 kB");
+        assert_eq!((decimal + binary).bytes, 2_536);
+        assert_eq!((binary - decimal).concise(), "536 B");
+        assert_eq!((decimal - binary).concise(), "-536 B");
+        assert_eq!((decimal - decimal).concise(), "0 B");
+
+        let mut total = HumanSize::from(0).decimal().precision(2);
+        total += HumanSize::from(1_236_000).binary();
+        total += HumanSize::from(1_000);
+        assert_eq!(total.concise(), "1.24 MB");
+        total -= HumanSize::from(2_000_000);
+        assert_eq!(total.concise(), "-763 kB");
+
+        // Saturates rather than overflowing
+        assert_eq!(
+            (HumanSize::from(u64::MAX) + HumanSize::from(1)).bytes,
+            u64::MAX
+        );
+        let below = HumanSize::from_signed(-i64::MAX) - HumanSize::from(u64::MAX);
+        assert_eq!((below.negative, below.bytes), (true, u64::MAX));
+
+        let sizes = [
+            HumanSize::from(1_024).binary(),
+            HumanSize::from(512).decimal(),
+        ];
+        assert_eq!(sizes.iter().sum::<HumanSize>().concise(), "1.5 KiB");
+        assert_eq!(
+            sizes.into_iter().rev().sum::<HumanSize>().concise(),
+            "1.5 kB"
+        );
+        assert_eq!(
+            std::iter::empty::<HumanSize>().sum::<HumanSize>(),
+            HumanSize::default()
+        );
+
+        // Ordering and equality use the byte count alone, sign included
+        assert_eq!(
+            HumanSize::from(1_024).binary(),
+            HumanSize::from(1_024).decimal()
+        );
+        assert_eq!(HumanSize::from_signed(0), HumanSize::from(0).binary());
+        assert!(HumanSize::from(1_000).binary() < HumanSize::from(1_024).decimal());
+        assert!(HumanSize::from_signed(-5) < HumanSize::from(0));
+        let mut sorted = [
+            HumanSize::from(3_000).decimal(),
+            HumanSize::from_signed(-1),
+            HumanSize::from(2_048).binary(),
+        ];
+        sorted.sort();
+        let sorted: Vec<_> = sorted.iter().map(HumanSize::concise).collect();
+        assert_eq!(sorted, ["-1 B", "2 KiB", "3 kB"]);
+        assert_eq!(
+            [HumanSize::from(7), HumanSize::from(9)].iter().max(),
+            Some(&HumanSize::from(9))
+        );
+    }
+
+    #[test]
+    fn test_duration_natural() {
+        use crate::Locale;
+        use std::time::UNIX_EPOCH;
+
+        let units = [
+            (TimeUnit::Week, "week"),
+            (TimeUnit::Month, "month"),
+            (TimeUnit::Year, "year"),
+        ];
+        for (unit, word) in units {
+            let one = HumanDuration::from_secs(unit.seconds()).natural();
+            assert_eq!(one.full(), format!("last {}", word));
+            assert_eq!(one.future().full(), format!("next {}", word));
+            // Concise output and the default keep the count
+            assert_eq!(one.concise(), format!("1{} ago", unit.symbol()));
+            assert_eq!(
+                HumanDuration::from_secs(unit.seconds()).full(),
+                format!("1 {} ago", word)
+            );
+            let two = HumanDuration::from_secs(2 * unit.seconds()).natural();
+            assert_eq!(two.full(), format!("2 {}s ago", word));
+            assert_eq!(two.future().full(), format!("2 {}s from now", word));
+        }
+
+        // Days keep "yesterday", smaller units their count
+        assert_eq!(
+            HumanDuration::from_secs(86_400).natural().full(),
+            "yesterday"
+        );
+        assert_eq!(
+            HumanDuration::from_secs(3_600).natural().full(),
+            "1 hour ago"
+        );
+        // Only a lone unit: "1 week 2 days ago" stays as it is
+        let week_and_days = HumanDuration::from_secs(9 * 86_400)
+            .natural()
+            .granularity(2);
+        assert_eq!(week_and_days.full(), "1 week 2 days ago");
+
+        let spanish = |secs| {
+            HumanDuration::from_secs(secs)
+                .natural()
+                .locale(Locale::Spanish)
+        };
+        assert_eq!(spanish(TimeUnit::Week.seconds()).full(), "la semana pasada");
+        assert_eq!(
+            spanish(TimeUnit::Month.seconds()).future().full(),
+            "el próximo mes"
+        );
+        assert_eq!(spanish(TimeUnit::Year.seconds()).full(), "el año pasado");
+
+        // Timestamps take their direction from the clock
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let ahead = now + Duration::from_secs(TimeUnit::Year.seconds());
+        let then = HumanDuration::from(Some(ahead)).as_of(now).natural();
+        assert_eq!(then.full(), "next year");
+    }
+
+    #[test]
+    fn test_time_separators() {
+        use crate::{Locale, Style};
+
+        let time = |secs| HumanTime::from(Duration::from_secs(secs));
+        let listed = |secs| time(secs).separator(", ").final_separator(" and ");
+
+        assert_eq!(listed(3_672).full(), "1 hour, 1 minute and 12 seconds");
+        assert_eq!(listed(3_900).full(), "1 hour and 5 minutes");
+        assert_eq!(listed(300).full(), "5 minutes");
+        assert_eq!(listed(0).full(), "0 seconds");
+        assert_eq!(
+            listed(200_000).full(),
+            "2 days, 7 hours, 33 minutes and 20 seconds"
+        );
+        assert_eq!(
+            time(3_672).separator(", ").final_separator(", and ").full(),
+            "1 hour, 1 minute, and 12 seconds"
+        );
+        assert_eq!(
+            time(3_900).final_separator(" and ").full(),
+            "1 hour and 5 minutes"
+        );
+        assert_eq!(
+            time(3_672).final_separator(" and ").full(),
+            "1 hour 1 minute and 12 seconds"
+        );
+        assert_eq!(
+            time(3_672).separator(", ").full(),
+            "1 hour, 1 minute, 12 seconds"
+        );
+
+        // Sub-second parts, signs and locales take the separators too
+        let precise = HumanTime::from(Duration::from_millis(90_250))
+            .precise()
+            .separator(", ")
+            .final_separator(" and ");
+        assert_eq!(precise.full(), "1 minute, 30 seconds and 250 milliseconds");
+        let negative = HumanTime::from_signed(Duration::from_secs(3_900), true)
+            .separator(", ")
+            .final_separator(" y ")
+            .locale(Locale::Spanish);
+        assert_eq!(negative.full(), "-1 hora y 5 minutos");
+
+        // Concise output, approximations and parts are unaffected
+        assert_eq!(listed(3_605).concise(), "1h 0m 5s");
+        assert_eq!(listed(6_792).approximate().full(), "about 2 hours");
+        let parts = listed(3_900).into_parts(Style::Full);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(
+            (parts[0].number.as_str(), parts[0].unit.as_str()),
+            ("1", " hour")
+        );
+        assert_eq!(
+            (parts[1].number.as_str(), parts[1].unit.as_str()),
+            ("5", " minutes")
+        );
+    }
+
+    #[test]
+    fn test_percent_rescaled() {
+        use crate::Locale;
+
+        let bps = |value: f64, decimals: usize| HumanPercent::from(value, decimals).basis_points();
+        let mille = |value: f64, decimals: usize| HumanPercent::from(value, decimals).per_mille();
+
+        assert_eq!(bps(0.25, 0).concise(), "25 bps");
+        assert_eq!(bps(0.25, 0).full(), "25 basis points");
+        assert_eq!(bps(0.25, 1).concise(), "25.0 bps");
+        assert_eq!(bps(0.2549, 1).concise(), "25.5 bps");
+        assert_eq!(bps(0.01, 0).full(), "1 basis point");
+        assert_eq!(bps(0.01, 1).full(), "1.0 basis points");
+        assert_eq!(bps(0.01, 1).trim_zeros().full(), "1 basis point");
+        assert_eq!(bps(-0.01, 0).full(), "-1 basis point");
+        assert_eq!(bps(-0.5, 0).concise(), "-50 bps");
+        assert_eq!(bps(0.0, 0).concise(), "0 bps");
+        assert_eq!(bps(-0.001, 0).concise(), "0 bps");
+        assert_eq!(bps(0.75, 0).signed().concise(), "+75 bps");
+        assert_eq!(bps(1.5, 0).clamp().concise(), "150 bps");
+        assert_eq!(bps(120.0, 0).clamp().concise(), "10000 bps");
+
+        assert_eq!(mille(0.25, 1).concise(), "2.5‰");
+        assert_eq!(mille(0.25, 1).full(), "2.5 per mille");
+        assert_eq!(mille(0.25, 0).concise(), "3‰");
+        assert_eq!(mille(-1.23, 1).concise(), "-12.3‰");
+        assert_eq!(mille(0.0, 1).concise(), "0.0‰");
+        assert_eq!(mille(-0.001, 1).concise(), "0.0‰");
+        assert_eq!(mille(0.0, 0).signed().zero_sign("±").concise(), "±0‰");
+        assert_eq!(mille(0.25, 1).locale(Locale::Spanish).full(), "2,5 por mil");
+        assert_eq!(
+            bps(0.01, 0).locale(Locale::Spanish).full(),
+            "1 punto básico"
+        );
+        assert_eq!(mille(f64::NAN, 1).concise(), "-");
+
+        // The last call wins
+        assert_eq!(bps(0.25, 1).per_mille().concise(), "2.5‰");
+        assert_eq!(mille(0.25, 0).basis_points().concise(), "25 bps");
+    }
+
+    #[test]
+    fn test_size_from_path() {
+        use std::fs;
+        use std::io::ErrorKind;
+
+        let dir = std::env::temp_dir().join(format!("humanly-size-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("data.bin");
+        fs::write(&file, vec![0u8; 1_536]).unwrap();
+
+        let size = HumanSize::from_path(&file).unwrap();
+        assert_eq!(size.bytes, 1_536);
+        assert_eq!(size.concise(), "1.5 KiB");
+        assert_eq!(
+            HumanSize::from_path(&file).unwrap().decimal().concise(),
+            "1.5 kB"
+        );
+        let metadata = fs::metadata(&file).unwrap();
+        assert_eq!(HumanSize::from_metadata(&metadata), size);
+
+        // Directories give their own entry's length, not their contents'
+        let entry = fs::metadata(&dir).unwrap().len();
+        assert_eq!(HumanSize::from_path(&dir).unwrap().bytes, entry);
+
+        fs::remove_dir_all(&dir).unwrap();
+        let err = HumanSize::from_path(&file).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_number_notation() {
+        use crate::Locale;
+
+        let sci = |n: f64| HumanNumber::from(n).scientific();
+        let eng = |n: f64| HumanNumber::from(n).engineering();
+
+        assert_eq!(sci(2.5e9).concise(), "2.5e9");
+        assert_eq!(sci(2.5e9).full(), "2.5×10⁹");
+        assert_eq!(sci(1_500_000.0).concise(), "1.5e6");
+        assert_eq!(sci(45_000.0).concise(), "4.5e4");
+        assert_eq!(sci(1.0).concise(), "1e0");
+        assert_eq!(sci(12.0).concise(), "1.2e1");
+        assert_eq!(sci(1_000.0).concise(), "1e3");
+        assert_eq!(sci(0.0003).concise(), "3e-4");
+        assert_eq!(sci(0.0003).full(), "3×10⁻⁴");
+        assert_eq!(sci(0.00012346).precision(3).concise(), "1.235e-4");
+        assert_eq!(sci(-2.5e9).concise(), "-2.5e9");
+        assert_eq!(sci(-0.0003).full(), "-3×10⁻⁴");
+        assert_eq!(sci(1e300).concise(), "1e300");
+        assert_eq!(sci(5e-324).concise(), "5e-324");
+
+        assert_eq!(eng(45_000.0).concise(), "45e3");
+        assert_eq!(eng(2.5e9).concise(), "2.5e9");
+        assert_eq!(eng(123_456.0).concise(), "123.5e3");
+        assert_eq!(eng(999.0).concise(), "999e0");
+        assert_eq!(eng(0.0003).concise(), "300e-6");
+        assert_eq!(eng(0.003).concise(), "3e-3");
+        assert_eq!(eng(0.03).full(), "30×10⁻³");
+        assert_eq!(eng(-45_000.0).full(), "-45×10³");
+
+        // Rounding that reaches the next power moves the exponent
+        assert_eq!(sci(9.96e5).concise(), "1e6");
+        assert_eq!(sci(9.94e5).concise(), "9.9e5");
+        assert_eq!(eng(999_960.0).concise(), "1e6");
+        assert_eq!(eng(999_940.0).concise(), "999.9e3");
+        assert_eq!(eng(999_960.0).precision(2).concise(), "999.96e3");
+
+        // Zero, non-finite values and the other options
+        assert_eq!(sci(0.0).concise(), "0");
+        assert_eq!(eng(-0.0).full(), "0");
+        assert_eq!(sci(f64::NAN).concise(), "-");
+        assert_eq!(eng(f64::INFINITY).full(), "-");
+        assert_eq!(sci(1_500_000.0).precision(0).concise(), "2e6");
+        assert_eq!(
+            sci(2e6).precision(2).keep_trailing_zeros().concise(),
+            "2.00e6"
+        );
+        assert_eq!(sci(1_500_000.0).locale(Locale::Spanish).full(), "1,5×10⁶");
+        assert_eq!(HumanNumber::from(u64::MAX).scientific().concise(), "1.8e19");
+        assert_eq!(sci(1_500.0).threshold(1_000_000).concise(), "1,500");
+        assert_eq!(sci(1_500_000.0).threshold(1_000_000).concise(), "1.5e6");
+        assert_eq!(sci(1_500_000.0).engineering().concise(), "1.5e6");
+        assert_eq!(HumanNumber::from(45_000).concise(), "45k");
+    }
+
+    #[test]
+    fn test_duration_unit_range() {
+        const DAY: u64 = 86_400;
+        let past: fn(u64) -> HumanDuration = HumanDuration::from_secs;
+        let ahead: fn(u64) -> HumanDuration = |secs| HumanDuration::from_secs(secs).future();
+        let year = TimeUnit::Year.seconds();
+        let month = TimeUnit::Month.seconds();
+
+        // Capped at days, years and months overflow into the day count
+        for (then, suffix) in [(past, "ago"), (ahead, "from now")] {
+            let days = |secs| then(secs).max_unit(TimeUnit::Day).concise();
+            assert_eq!(days(year - 1), format!("365d {}", suffix));
+            assert_eq!(days(year), format!("365d {}", suffix));
+            assert_eq!(days(400 * DAY), format!("400d {}", suffix));
+            assert_eq!(days(2 * DAY - 1), format!("1d {}", suffix));
+            assert_eq!(then(year - 1).concise(), format!("11mo {}", suffix));
+            assert_eq!(then(year).concise(), format!("1y {}", suffix));
+        }
+        assert_eq!(
+            past(400 * DAY).max_unit(TimeUnit::Day).full(),
+            "400 days ago"
+        );
+        assert_eq!(
+            ahead(400 * DAY).max_unit(TimeUnit::Day).full(),
+            "400 days from now"
+        );
+        assert_eq!(past(month).max_unit(TimeUnit::Week).full(), "4 weeks ago");
+        assert_eq!(
+            ahead(month).max_unit(TimeUnit::Week).concise(),
+            "4w from now"
+        );
+        assert_eq!(past(7 * DAY).max_unit(TimeUnit::Hour).concise(), "168h ago");
+        assert_eq!(
+            past(7 * DAY - 1).max_unit(TimeUnit::Hour).concise(),
+            "167h ago"
+        );
+        assert_eq!(past(90).max_unit(TimeUnit::Second).full(), "90 seconds ago");
+        assert_eq!(
+            past(400 * DAY + 5 * 3_600)
+                .max_unit(TimeUnit::Day)
+                .granularity(2)
+                .concise(),
+            "400d 5h ago"
+        );
+        // A day still reads "yesterday" and "tomorrow"
+        assert_eq!(past(DAY).max_unit(TimeUnit::Day).full(), "yesterday");
+        assert_eq!(ahead(DAY).max_unit(TimeUnit::Day).full(), "tomorrow");
+
+        // Under the smallest unit is "just now", either way
+        for then in [past, ahead] {
+            let minutes = |secs| then(secs).min_unit(TimeUnit::Minute);
+            assert_eq!(minutes(59).full(), "just now");
+            assert_eq!(minutes(60).concise(), then(60).concise());
+            let hours = |secs| then(secs).min_unit(TimeUnit::Hour);
+            assert_eq!(hours(3_599).concise(), "just now");
+            assert_eq!(hours(3_600).concise(), then(3_600).concise());
+        }
+        assert_eq!(past(60).min_unit(TimeUnit::Minute).full(), "1 minute ago");
+        assert_eq!(
+            ahead(60).min_unit(TimeUnit::Minute).full(),
+            "1 minute from now"
+        );
+        assert_eq!(past(3_725).granularity(3).concise(), "1h 2m 5s ago");
+        assert_eq!(
+            past(3_725)
+                .granularity(3)
+                .min_unit(TimeUnit::Minute)
+                .concise(),
+            "1h 2m ago"
+        );
+        assert_eq!(
+            ahead(3_605)
+                .granularity(2)
+                .min_unit(TimeUnit::Minute)
+                .full(),
+            "1 hour from now"
+        );
+
+        // The smallest unit wins over a smaller largest one
+        assert_eq!(
+            past(3 * DAY)
+                .max_unit(TimeUnit::Minute)
+                .min_unit(TimeUnit::Hour)
+                .concise(),
+            "72h ago"
+        );
+        assert_eq!(
+            past(3_599)
+                .max_unit(TimeUnit::Minute)
+                .min_unit(TimeUnit::Hour)
+                .concise(),
+            "just now"
+        );
+    }
+
+    #[test]
+    fn test_time_coarse() {
+        use crate::{Locale, Rounding};
+
+        let time = |secs: u64| HumanTime::from(Duration::from_secs(secs)).coarse();
+
+        assert_eq!(time(5_400).full(), "1.5 hours");
+        assert_eq!(time(5_400).concise(), "1.5h");
+        assert_eq!(time(7_200).full(), "2 hours");
+        assert_eq!(time(3_600).full(), "1 hour");
+        assert_eq!(time(90).concise(), "1.5m");
+        assert_eq!(time(200_000).full(), "2.3 days");
+        assert_eq!(time(200_000).max_unit(TimeUnit::Hour).concise(), "55.6h");
+        assert_eq!(time(1_209_600).max_unit(TimeUnit::Week).full(), "2 weeks");
+
+        // Under a minute stays in seconds, fractions included
+        assert_eq!(time(59).full(), "59 seconds");
+        assert_eq!(time(1).full(), "1 second");
+        assert_eq!(time(0).concise(), "0s");
+        let subsecond = HumanTime::from(Duration::from_millis(2_450)).coarse();
+        assert_eq!(subsecond.concise(), "2.5s");
+        assert_eq!(subsecond.rounding(Rounding::Down).concise(), "2.4s");
+
+        // The rounding mode applies to the tenth
+        assert_eq!(time(4_330).concise(), "1.2h");
+        assert_eq!(time(4_330).rounding(Rounding::Down).concise(), "1.2h");
+        assert_eq!(time(4_330).rounding(Rounding::Up).concise(), "1.3h");
+        assert_eq!(time(4_500).concise(), "1.3h");
+        assert_eq!(time(4_679).rounding(Rounding::Down).concise(), "1.2h");
+        assert_eq!(time(3_600).rounding(Rounding::Up).concise(), "1h");
+        assert_eq!(time(3_601).rounding(Rounding::Up).concise(), "1.1h");
+
+        // Rounding up to the next unit moves to it
+        assert_eq!(time(3_598).full(), "1 hour");
+        assert_eq!(time(3_590).full(), "59.8 minutes");
+        assert_eq!(time(3_590).rounding(Rounding::Up).full(), "59.9 minutes");
+        assert_eq!(time(59).rounding(Rounding::Up).concise(), "59s");
+        let almost_minute = HumanTime::from(Duration::from_millis(59_990)).coarse();
+        assert_eq!(almost_minute.concise(), "1m");
+        assert_eq!(almost_minute.rounding(Rounding::Down).concise(), "59.9s");
+        assert_eq!(time(86_399).max_unit(TimeUnit::Hour).concise(), "24h");
+
+        // Signs and locales
+        let negative = HumanTime::from_signed(Duration::from_secs(5_400), true).coarse();
+        assert_eq!(negative.concise(), "-1.5h");
+        assert_eq!(time(5_400).locale(Locale::Spanish).full(), "1,5 horas");
+        assert_eq!(time(3_600).locale(Locale::Spanish).full(), "1 hora");
+    }
+
+    #[test]
+    fn test_percent_bar() {
+        let bar = |value: f64, width: usize| HumanPercent::from(value, 0).bar(width);
+
+        assert_eq!(bar(60.0, 10), "██████░░░░ 60%");
+        assert_eq!(bar(0.0, 10), "░░░░░░░░░░ 0%");
+        assert_eq!(bar(100.0, 10), "██████████ 100%");
+        assert_eq!(bar(33.3, 3), "█░░ 33%");
+        assert_eq!(bar(45.0, 10), "█████░░░░░ 45%");
+        assert_eq!(bar(44.9, 10), "████░░░░░░ 45%");
+        assert_eq!(bar(50.0, 0), " 50%");
+
+        // Out of range values clamp the bar but not the number
+        assert_eq!(bar(-20.0, 5), "░░░░░ -20%");
+        assert_eq!(bar(150.0, 5), "█████ 150%");
+        assert_eq!(bar(f64::INFINITY, 2), "██ -");
+        assert_eq!(bar(f64::NAN, 4), "░░░░ -");
+        assert_eq!(
+            HumanPercent::from(f64::NAN, 0).fallback("n/a").bar(2),
+            "░░ n/a"
+        );
+        assert_eq!(HumanPercent::from(150.0, 0).clamp().bar(5), "█████ 100%");
+
+        // The label follows the other options
+        assert_eq!(HumanPercent::from(12.5, 1).bar(4), "█░░░ 12.5%");
+        assert_eq!(HumanPercent::from(2.5, 1).signed().bar(4), "░░░░ +2.5%");
+
+        // Custom characters and the bare bar
+        let custom = HumanPercent::from(60.0, 0).bar_chars('=', ' ');
+        assert_eq!(custom.bar(10), "======     60%");
+        assert_eq!(custom.bare_bar().bar(10), "======    ");
+        let bare = HumanPercent::from(75.0, 0).bare_bar().bar(8);
+        assert_eq!(bare, "██████░░");
+        assert_eq!(bare.chars().count(), 8);
+        for value in [0.0, 12.3, 50.0, 99.9, 100.0, 250.0, -1.0] {
+            let text = HumanPercent::from(value, 1).bare_bar().bar(20);
+            assert_eq!(text.chars().count(), 20, "{}", value);
+        }
+    }
+
+    #[test]
+    fn test_display_alternate() {
+        let now = SystemTime::now();
+
+        assert_eq!(format!("{:#}", HumanNumber::from(1_500)), "1.5k");
+        assert_eq!(format!("{}", HumanNumber::from(1_500)), "1.5 thousand");
+        assert_eq!(format!("{:#}", HumanSize::from(1_048_576)), "1 MiB");
+        assert_eq!(format!("{}", HumanSize::from(1_048_576)), "1 mebibyte");
+        assert_eq!(
+            format!(
+                "{:#}",
+                HumanDuration::from(Some(now - Duration::from_secs(120)))
+            ),
+            "2m ago"
+        );
+        assert_eq!(
+            format!("{:#}", HumanTime::from(Duration::from_secs(3672))),
+            "1h 1m 12s"
+        );
+        assert_eq!(format!("{:#}", HumanPercent::from(12.3456, 1)), "12.3%");
+    }
+
+    #[test]
+    fn test_display_padding() {
+        let sizes: Vec<String> = [1_000_000, 5_242_880, 500]
+            .iter()
+            .map(|&bytes| format!("{:>#10}|", HumanSize::from(bytes)))
+            .collect();
+        assert_eq!(sizes, [" 976.6 KiB|", "     5 MiB|", "     500 B|"]);
+
+        let times: Vec<String> = [45, 90, 3672]
+            .iter()
+            .map(|&secs| format!("{:>#10}|", HumanTime::from(Duration::from_secs(secs))))
+            .collect();
+        assert_eq!(times, ["       45s|", "    1m 30s|", " 1h 1m 12s|"]);
+
+        let percents: Vec<String> = [5.0, 12.34, 100.0]
+            .iter()
+            .map(|&value| format!("{:>#7}|", HumanPercent::from(value, 1)))
+            .collect();
+        assert_eq!(percents, ["   5.0%|", "  12.3%|", " 100.0%|"]);
+
+        assert_eq!(format!("{:<12}|", HumanSize::from(1024)), "1 kibibyte  |");
+        assert_eq!(format!("{:*^#9}", HumanNumber::from(1_500)), "**1.5k***");
+
+        // Left, right and centre alignment, in both styles
+        let size = HumanSize::from(5_242_880);
+        assert_eq!(format!("{:<#8}|", size), "5 MiB   |");
+        assert_eq!(format!("{:>#8}|", size), "   5 MiB|");
+        assert_eq!(format!("{:^#8}|", size), " 5 MiB  |");
+        assert_eq!(format!("{:^14}|", size), " 5 mebibytes  |");
+        let time = HumanTime::from(Duration::from_secs(90));
+        assert_eq!(format!("{:<8}|", time), "1 minute 30 seconds|");
+        assert_eq!(format!("{:<#8}|", time), "1m 30s  |");
+        assert_eq!(format!("{:>#8}|", time), "  1m 30s|");
+        assert_eq!(format!("{:^#8}|", time), " 1m 30s |");
+        assert_eq!(format!("{:-^#10}|", time), "--1m 30s--|");
+
+        // Widths count characters, not bytes, and precision truncates
+        let micros = HumanTime::from(Duration::from_micros(42));
+        assert_eq!(format!("{:>#6}|", micros), "  42µs|");
+        assert_eq!(format!("{:.5}|", size), "5 meb|");
+        assert_eq!(
+            format!("{:>8}|", crate::HumanCount::from(1_234)),
+            "   1,234|"
+        );
+        assert_eq!(
+            format!("{:<10}|", HumanDuration::from_secs(90)),
+            "1 minute ago|"
+        );
+        assert_eq!(
+            format!("{:<#10}|", HumanDuration::from_secs(90)),
+            "1m ago    |"
+        );
+    }
+
+    #[test]
+    fn test_write_into() {
+        use crate::core::Style;
+
+        let mut buf = String::new();
+        HumanNumber::from(1_500_000)
+            .write_into(&mut buf, Style::Concise)
+            .unwrap();
+        assert_eq!(buf, "1.5M");
+
+        buf.clear();
+        HumanSize::from(1_000_000)
+            .write_into(&mut buf, Style::Full)
+            .unwrap();
+        assert_eq!(buf, HumanSize::from(1_000_000).full());
+
+        buf.clear();
+        HumanTime::from(Duration::from_secs(3605))
+            .write_into(&mut buf, Style::Concise)
+            .unwrap();
+        assert_eq!(buf, "1h 0m 5s");
+
+        buf.clear();
+        HumanTime::from(Duration::from_secs(3605))
+            .write_into(&mut buf, Style::Full)
+            .unwrap();
+        assert_eq!(buf, "1 hour 5 seconds");
+
+        buf.clear();
+        HumanPercent::from(f64::NAN, 1)
+            .write_into(&mut buf, Style::Full)
+            .unwrap();
+        assert_eq!(buf, "-");
+
+        // write_concise and write_full are write_into with the style fixed
+        buf.clear();
+        HumanNumber::from(1_500_000)
+            .write_concise(&mut buf)
+            .unwrap();
+        HumanSize::from(1_024).write_full(&mut buf).unwrap();
+        assert_eq!(buf, format!("1.5M{}", HumanSize::from(1_024).full()));
+        let mut cell = String::new();
+        let count = crate::HumanCount::from(1_234).unit("files");
+        count.write_full(&mut cell).unwrap();
+        assert_eq!(cell, "1,234 files");
+        cell.clear();
+        count.write_concise(&mut cell).unwrap();
+        assert_eq!(cell, count.concise());
+
+        // Trailing zeros are trimmed without touching the integer part
+        assert_eq!(HumanNumber::from(1_050_000).concise(), "1.1M");
+        assert_eq!(HumanNumber::from(100_000).concise(), "100k");
+        assert_eq!(HumanNumber::from(100_040).concise(), "100k");
+        assert_eq!(HumanNumber::from(1.05).concise(), "1.1");
+    }
+
+    #[test]
+    fn test_humanize_traits() {
+        use crate::prelude::*;
+
+        assert_eq!(1_200.human_number().concise(), "1.2k");
+        assert_eq!(1_800_000u64.human_number().full(), "1.8 million");
+        assert_eq!(2.5e9.human_number().concise(), "2.5B");
+        assert_eq!((-1_500i64).human_number().concise(), "-1.5k");
+        assert_eq!(5_242_880u64.human_size().concise(), "5 MiB");
+        assert_eq!(1_000_000usize.human_size().decimal().concise(), "1 MB");
+        assert_eq!(Duration::from_secs(3661).human_time().concise(), "1h 1m 1s");
+        assert_eq!(
+            (SystemTime::now() - Duration::from_secs(120))
+                .ago()
+                .to_string(),
+            "2 minutes ago"
+        );
+        assert_eq!(None::<SystemTime>.ago().concise(), "-");
+    }
+
+    #[test]
+    fn test_locale() {
+        let now = SystemTime::now();
+
+        // English is the default and matches the output without a locale
+        assert_eq!(
+            HumanNumber::from(1_500_000).locale(Locale::English).full(),
+            HumanNumber::from(1_500_000).full()
+        );
+
+        // HumanNumber
+        assert_eq!(
+            HumanNumber::from(1_000).locale(Locale::Spanish).full(),
+            "1 mil"
+        );
+        assert_eq!(
+            HumanNumber::from(1_000_000).locale(Locale::Spanish).full(),
+            "1 millón"
+        );
+        assert_eq!(
+            HumanNumber::from(1_500_000).locale(Locale::Spanish).full(),
+            "1,5 millones"
+        );
+        assert_eq!(
+            HumanNumber::from(2_500_000_000.0)
+                .locale(Locale::Spanish)
+                .full(),
+            "2,5 mil millones"
+        );
+        assert_eq!(
+            HumanNumber::from(1_000_000_000_000.0)
+                .locale(Locale::Spanish)
+                .full(),
+            "1 billón"
+        );
+        assert_eq!(
+            HumanNumber::from(1_500_000)
+                .locale(Locale::Spanish)
+                .concise(),
+            "1.5M"
+        );
+
+        // HumanSize
+        assert_eq!(HumanSize::from(1).locale(Locale::Spanish).full(), "1 byte");
+        assert_eq!(
+            HumanSize::from(1_000_000).locale(Locale::Spanish).full(),
+            "976,6 kibibytes"
+        );
+
+        // HumanDuration
+        assert_eq!(
+            HumanDuration::from(Some(now))
+                .as_of(now)
+                .locale(Locale::Spanish)
+                .to_string(),
+            "justo ahora"
+        );
+        assert_eq!(
+            HumanDuration::from(Some(now - Duration::from_secs(7200)))
+                .as_of(now)
+                .locale(Locale::Spanish)
+                .to_string(),
+            "hace 2 horas"
+        );
+        assert_eq!(
+            HumanDuration::from(Some(now - Duration::from_secs(3600)))
+                .as_of(now)
+                .locale(Locale::Spanish)
+                .to_string(),
+            "hace 1 hora"
+        );
+        assert_eq!(
+            HumanDuration::from(Some(now - Duration::from_secs(5_259_492)))
+                .as_of(now)
+                .locale(Locale::Spanish)
+                .to_string(),
+            "hace 2 meses"
+        );
+        assert_eq!(
+            HumanDuration::from(Some(now - Duration::from_secs(86_400)))
+                .as_of(now)
+                .locale(Locale::Spanish)
+                .to_string(),
+            "ayer"
+        );
+
+        // HumanTime
+        assert_eq!(
+            HumanTime::from(Duration::from_secs(3672))
+                .locale(Locale::Spanish)
+                .to_string(),
+            "1 hora 1 minuto 12 segundos"
+        );
+
+        // HumanPercent
+        assert_eq!(
+            HumanPercent::from(12.3456, 1)
+                .locale(Locale::Spanish)
+                .to_string(),
+            "12,3 por ciento"
+        );
+    }
+
+    #[cfg(feature = "clap")]
+    #[test]
+    fn test_clap_value_parsers() {
+        use crate::clap::{DurationValueParser, SizeValueParser};
+        use ::clap::{Arg, Command};
+
+        let command = || {
+            Command::new("app")
+                .arg(
+                    Arg::new("size")
+                        .long("size")
+                        .value_parser(SizeValueParser::new()),
+                )
+                .arg(
+                    Arg::new("timeout")
+                        .long("timeout")
+                        .value_parser(DurationValueParser::new()),
+                )
+        };
+        let size = |value: &str| {
+            command()
+                .try_get_matches_from(["app", "--size", value])
+                .map(|m| *m.get_one::<u64>("size").unwrap())
+        };
+        let timeout = |value: &str| {
+            command()
+                .try_get_matches_from(["app", "--timeout", value])
+                .map(|m| *m.get_one::<Duration>("timeout").unwrap())
+        };
+
+        assert_eq!(size("512MiB").unwrap(), 536_870_912);
+        assert_eq!(size("1.5 GB").unwrap(), 1_500_000_000);
+        assert_eq!(size("2048").unwrap(), 2048);
+        assert_eq!(size("4kib").unwrap(), 4096);
+        assert_eq!(size("10B").unwrap(), 10);
+
+        let err = size("12XB").unwrap_err().to_string();
+        assert!(err.contains("unknown unit 'XB'"), "{}", err);
+        assert!(err.contains("KiB, MB, MiB"), "{}", err);
+        assert!(size("").is_err());
+        assert!(size("MiB").is_err());
+
+        assert_eq!(timeout("1h30m").unwrap(), Duration::from_secs(5400));
+        assert_eq!(timeout("1h 30m").unwrap(), Duration::from_secs(5400));
+        assert_eq!(timeout("90s").unwrap(), Duration::from_secs(90));
+        assert_eq!(timeout("90").unwrap(), Duration::from_secs(90));
+        assert_eq!(timeout("1.5h").unwrap(), Duration::from_secs(5400));
+        assert_eq!(timeout("2d").unwrap(), Duration::from_secs(172_800));
+
+        let err = timeout("5 fortnights").unwrap_err().to_string();
+        assert!(err.contains("unknown unit 'fortnights'"), "{}", err);
+        assert!(err.contains("d, h, m, s"), "{}", err);
+        assert!(timeout("-5").is_err());
+    }
+
+    #[cfg(feature = "wasm")]
+    #[test]
+    fn test_wasm_bindings() {
+        use crate::wasm::{human_ago_unix_ms, human_number, human_size, human_time_secs};
+
+        assert_eq!(human_size(5_242_880.0, false), "5 MiB");
+        assert_eq!(human_size(5_242_880.0, true), "5.2 MB");
+        assert_eq!(human_size(-1.0, false), "-");
+        assert_eq!(human_size(f64::NAN, false), "-");
+        assert_eq!(human_number(1_800_000.0), "1.8M");
+        assert_eq!(human_time_secs(5_400.0), "1h 30m");
+        assert_eq!(human_time_secs(-1.0), "-");
+
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as f64;
+        assert_eq!(human_ago_unix_ms(now - 300_000.0), "5m ago");
+        assert_eq!(human_ago_unix_ms(f64::NAN), "-");
+    }
+
+    #[cfg(feature = "ffi")]
+    #[test]
+    fn test_ffi_bindings() {
+        use crate::ffi::{humanly_ago_unix_secs, humanly_number, humanly_size, humanly_time_secs};
+        use std::ffi::CStr;
+
+        let mut buf = [0 as std::ffi::c_char; 32];
+        let mut call = |f: &dyn Fn(*mut std::ffi::c_char, usize) -> usize| {
+            let n = f(buf.as_mut_ptr(), buf.len());
+            let text = unsafe { CStr::from_ptr(buf.as_ptr()) };
+            (n, text.to_str().unwrap().to_string())
+        };
+
+        assert_eq!(
+            call(&|b, l| unsafe { humanly_size(5_242_880, b, l) }),
+            (5, "5 MiB".to_string())
+        );
+        assert_eq!(
+            call(&|b, l| unsafe { humanly_number(1_800_000.0, b, l) }),
+            (4, "1.8M".to_string())
+        );
+        assert_eq!(
+            call(&|b, l| unsafe { humanly_number(f64::NAN, b, l) }),
+            (1, "-".to_string())
+        );
+        assert_eq!(
+            call(&|b, l| unsafe { humanly_time_secs(5_400.0, b, l) }),
+            (6, "1h 30m".to_string())
+        );
+        assert_eq!(
+            call(&|b, l| unsafe { humanly_time_secs(f64::NAN, b, l) }),
+            (1, "-".to_string())
+        );
+
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+        assert_eq!(
+            call(&|b, l| unsafe { humanly_ago_unix_secs(now - 300, b, l) }),
+            (6, "5m ago".to_string())
+        );
+
+        // Too small: the length is returned and only the NUL written.
+        let mut small = [b'x' as std::ffi::c_char; 5];
+        assert_eq!(unsafe { humanly_size(5_242_880, small.as_mut_ptr(), 5) }, 5);
+        assert_eq!(small[0], 0);
+        assert_eq!(
+            unsafe { humanly_size(5_242_880, std::ptr::null_mut(), 0) },
+            5
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use crate::{HumanCoordinate, HumanCount, HumanPace, HumanTimeOfDay, HumanUptime};
+        use std::time::UNIX_EPOCH;
+
+        // serde_json (self-describing)
+        assert_eq!(
+            serde_json::to_string(&HumanSize::from(5_242_880)).unwrap(),
+            "5242880"
+        );
+        let size: HumanSize = serde_json::from_str("5242880").unwrap();
+        assert_eq!(size.concise(), "5 MiB");
+        let shrunk: HumanSize = serde_json::from_str("-1024").unwrap();
+        assert_eq!(shrunk, HumanSize::from_signed(-1_024));
+        // a difference past i64::MIN goes as exact text rather than a lossy number
+        let deepest = HumanSize::from(0) - HumanSize::from(u64::MAX);
+        let json = serde_json::to_string(&deepest).unwrap();
+        assert_eq!(json, "\"-18446744073709551615\"");
+        assert_eq!(serde_json::from_str::<HumanSize>(&json).unwrap(), deepest);
+        let edge = HumanSize::from_signed(i64::MIN);
+        let json = serde_json::to_string(&edge).unwrap();
+        assert_eq!(json, "-9223372036854775808");
+        assert_eq!(serde_json::from_str::<HumanSize>(&json).unwrap(), edge);
+        assert_eq!(serde_json::to_string(&shrunk).unwrap(), "-1024");
+        assert!(serde_json::from_str::<HumanSize>("1.5").is_err());
+
+        let number: HumanNumber = serde_json::from_str("1800000").unwrap();
+        assert_eq!(number.concise(), "1.8M");
+        assert_eq!(serde_json::to_string(&number).unwrap(), "1800000.0");
+
+        let time: HumanTime = serde_json::from_str("3661").unwrap();
+        assert_eq!(time.concise(), "1h 1m 1s");
+        assert_eq!(serde_json::to_string(&time).unwrap(), "3661.0");
+        let time: HumanTime = serde_json::from_str("-90").unwrap();
+        assert_eq!(time.concise(), "-1m 30s");
+        assert_eq!(serde_json::to_string(&time).unwrap(), "-90.0");
+        assert!(serde_json::from_str::<HumanTime>("1e300").is_err());
+
+        let percent: HumanPercent = serde_json::from_str("12.34").unwrap();
+        assert_eq!(percent.concise(), "12.3%");
+
+        let permissions: HumanPermissions = serde_json::from_str("493").unwrap();
+        assert_eq!(permissions.concise(), "rwxr-xr-x");
+
+        let duration = HumanDuration::from(Some(UNIX_EPOCH - Duration::from_secs(86_400)));
+        let json = serde_json::to_string(&duration).unwrap();
+        assert_eq!(json, "-86400.0");
+        let duration: HumanDuration = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            duration.system_time,
+            Some(UNIX_EPOCH - Duration::from_secs(86_400))
+        );
+        let duration: HumanDuration = serde_json::from_str("null").unwrap();
+        assert_eq!(duration.concise(), "-");
+
+        // An elapsed duration is written as the moment it describes
+        let log_end = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let json = serde_json::to_string(&HumanDuration::from_secs(90).as_of(log_end)).unwrap();
+        assert_eq!(json, "1699999910.0");
+
+        // Human-readable formats also take the humanized text
+        let size: HumanSize = serde_json::from_str("\"5 MiB\"").unwrap();
+        assert_eq!(size.concise(), "5 MiB");
+        let text = serde_json::to_string(&HumanSize::from(1_536).concise()).unwrap();
+        assert_eq!(
+            serde_json::from_str::<HumanSize>(&text).unwrap().concise(),
+            "1.5 KiB"
+        );
+        let time: HumanTime = serde_json::from_str("\"1h 30m\"").unwrap();
+        assert_eq!(time.duration, Duration::from_secs(5_400));
+        let text =
+            serde_json::to_string(&HumanTime::from(Duration::from_secs(90)).concise()).unwrap();
+        assert_eq!(
+            serde_json::from_str::<HumanTime>(&text).unwrap().duration,
+            Duration::from_secs(90)
+        );
+        assert!(serde_json::from_str::<HumanSize>("\"lots\"").is_err());
+        let number: HumanNumber = serde_json::from_str("\"3.4M\"").unwrap();
+        assert_eq!(number.number, 3_400_000.0);
+        let text = serde_json::to_string(&HumanNumber::from(2_500).concise()).unwrap();
+        assert_eq!(
+            serde_json::from_str::<HumanNumber>(&text)
+                .unwrap()
+                .concise(),
+            "2.5k"
+        );
+        let percent: HumanPercent = serde_json::from_str("\"45%\"").unwrap();
+        assert_eq!(percent.concise(), "45.0%");
+        let text = serde_json::to_string(&HumanPercent::from(12.5, 1).concise()).unwrap();
+        assert_eq!(
+            serde_json::from_str::<HumanPercent>(&text)
+                .unwrap()
+                .concise(),
+            "12.5%"
+        );
+        let permissions: HumanPermissions = serde_json::from_str("\"rwxr-xr-x\"").unwrap();
+        assert_eq!(permissions, HumanPermissions::from(0o755));
+        let text = serde_json::to_string(&HumanPermissions::from(0o4755).concise()).unwrap();
+        assert_eq!(
+            serde_json::from_str::<HumanPermissions>(&text).unwrap(),
+            HumanPermissions::from(0o4755)
+        );
+        assert!(serde_json::from_str::<HumanNumber>("\"many\"").is_err());
+        assert!(serde_json::from_str::<HumanPercent>("\"nan%\"").is_err());
+        assert!(serde_json::from_str::<HumanPermissions>("\"rwxr-xr-q\"").is_err());
+        assert!(serde_json::from_str::<HumanPermissions>("4294967296").is_err());
+        assert!(serde_json::from_str::<HumanTime>("\"soon\"").is_err());
+
+        // bincode (not self-describing)
+        let bytes = bincode::serialize(&HumanSize::from(1_000_000)).unwrap();
+        let size: HumanSize = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(size.decimal().concise(), "1 MB");
+        for size in [
+            HumanSize::from_signed(-5),
+            HumanSize::from(0) - HumanSize::from(u64::MAX),
+            HumanSize::from(u64::MAX),
+        ] {
+            let bytes = bincode::serialize(&size).unwrap();
+            assert_eq!(bincode::deserialize::<HumanSize>(&bytes).unwrap(), size);
+        }
+
+        let bytes = bincode::serialize(&HumanNumber::from(2_500_000_000.0)).unwrap();
+        let number: HumanNumber = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(number.full(), "2.5 billion");
+
+        let bytes = bincode::serialize(&HumanTime::from(Duration::from_millis(90_500))).unwrap();
+        let time: HumanTime = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(time.duration, Duration::from_millis(90_500));
+
+        let bytes = bincode::serialize(&HumanPercent::from(37.5, 1)).unwrap();
+        let percent: HumanPercent = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(percent.concise(), "37.5%");
+
+        let bytes = bincode::serialize(&HumanPermissions::from(0o4755)).unwrap();
+        let permissions: HumanPermissions = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(permissions.concise(), "rwsr-xr-x");
+
+        let moment = UNIX_EPOCH + Duration::from_secs(1_709_222_400);
+        let bytes = bincode::serialize(&HumanDuration::from(Some(moment))).unwrap();
+        let duration: HumanDuration = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(duration.system_time, Some(moment));
+        let bytes = bincode::serialize(&HumanDuration::from(None)).unwrap();
+        let duration: HumanDuration = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(duration.system_time, None);
+
+        let bytes = bincode::serialize(&HumanOrdinal::from(-21)).unwrap();
+        let ordinal: HumanOrdinal = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(ordinal.concise(), "-21st");
+
+        let json = serde_json::to_string(&HumanBitrate::from_bits_per_sec(2_400_000)).unwrap();
+        assert_eq!(json, "2400000.0");
+        let bitrate: HumanBitrate = serde_json::from_str(&json).unwrap();
+        assert_eq!(bitrate.concise(), "2.4 Mbps");
+        let bytes = bincode::serialize(&HumanBitrate::from_transfer(1, Duration::ZERO)).unwrap();
+        let bitrate: HumanBitrate = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(bitrate.concise(), "-");
+
+        let bytes = bincode::serialize(&HumanFrequency::from_hz(44_100.0)).unwrap();
+        let frequency: HumanFrequency = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(frequency.concise(), "44.1 kHz");
+
+        let price = HumanMoney::new(-1_234, Currency::EUR);
+        let json = serde_json::to_string(&price).unwrap();
+        assert_eq!(json, r#"[-1234,"EUR"]"#);
+        let price: HumanMoney = serde_json::from_str(&json).unwrap();
+        assert_eq!(price.concise(), "-€12.34");
+        assert!(serde_json::from_str::<HumanMoney>(r#"[1,"XYZ"]"#).is_err());
+        let bytes = bincode::serialize(&HumanMoney::new(1_234_567, Currency::JPY)).unwrap();
+        let price: HumanMoney = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(price.concise(), "¥1,234,567");
+
+        let fraction: HumanFraction = serde_json::from_str("0.75").unwrap();
+        assert_eq!(fraction.concise(), "3/4");
+        assert_eq!(serde_json::to_string(&fraction).unwrap(), "0.75");
+
+        let date = HumanDate::from(UNIX_EPOCH + Duration::from_secs(1_710_252_300));
+        let json = serde_json::to_string(&date).unwrap();
+        assert_eq!(json, "1710252300.0");
+        let date: HumanDate = serde_json::from_str(&json).unwrap();
+        assert_eq!(date.concise(), "12 Mar 2024");
+
+        let power = HumanQuantity::new(1_234.0, "W");
+        let json = serde_json::to_string(&power).unwrap();
+        assert_eq!(json, r#"[1234.0,"W"]"#);
+        let power: HumanQuantity = serde_json::from_str(&json).unwrap();
+        assert_eq!(power.full(), "1.2 kilowatts");
+
+        let distance: HumanDistance = serde_json::from_str("1200").unwrap();
+        assert_eq!(distance.concise(), "1.2 km");
+        assert_eq!(serde_json::to_string(&distance).unwrap(), "1200.0");
+
+        let mass: HumanMass = serde_json::from_str("1200").unwrap();
+        assert_eq!(mass.concise(), "1.2 kg");
+        assert_eq!(serde_json::to_string(&mass).unwrap(), "1200.0");
+
+        let temperature: HumanTemperature = serde_json::from_str("23.5").unwrap();
+        assert_eq!(temperature.concise(), "23.5°C");
+        assert_eq!(serde_json::to_string(&temperature).unwrap(), "23.5");
+
+        let age: HumanAge = serde_json::from_str("645408000").unwrap();
+        assert_eq!(serde_json::to_string(&age).unwrap(), "645408000.0");
+
+        for (count, json) in [
+            (HumanCount::from(1_234_567), "1234567"),
+            (HumanCount::from(-42), "-42"),
+            (HumanCount::from(i64::MIN), "-9223372036854775808"),
+            (
+                HumanCount::from(u128::MAX),
+                "\"340282366920938463463374607431768211455\"",
+            ),
+            (
+                HumanCount::from(i128::MIN),
+                "\"-170141183460469231731687303715884105728\"",
+            ),
+            (HumanCount::from(1234.5), "1234.5"),
+        ] {
+            assert_eq!(serde_json::to_string(&count).unwrap(), json);
+            assert_eq!(serde_json::from_str::<HumanCount>(json).unwrap(), count);
+            let bytes = bincode::serialize(&count).unwrap();
+            assert_eq!(bincode::deserialize::<HumanCount>(&bytes).unwrap(), count);
+        }
+        let count: HumanCount = serde_json::from_str("\"1,234,567\"").unwrap();
+        assert_eq!(count, HumanCount::from(1_234_567));
+        let count: HumanCount = serde_json::from_str("\"-1,234.5\"").unwrap();
+        assert_eq!(count, HumanCount::from(-1234.5));
+        assert!(serde_json::from_str::<HumanCount>("\"lots\"").is_err());
+        assert!(serde_json::from_str::<HumanCount>("\"inf\"").is_err());
+
+        let time = HumanTimeOfDay::from_hms(15, 17, 42).twenty_four_hour();
+        let json = serde_json::to_string(&time).unwrap();
+        assert_eq!(json, "[15,17,42]");
+        let time: HumanTimeOfDay = serde_json::from_str(&json).unwrap();
+        assert_eq!(time, HumanTimeOfDay::from_hms(15, 17, 42));
+        assert_eq!(time.concise(), "3:17 pm");
+        let bytes = bincode::serialize(&time).unwrap();
+        assert_eq!(
+            bincode::deserialize::<HumanTimeOfDay>(&bytes).unwrap(),
+            time
+        );
+
+        let paris = HumanCoordinate::from_lat_lon(48.8567, 2.3522).unwrap();
+        let json = serde_json::to_string(&paris).unwrap();
+        assert_eq!(json, "[48.8567,2.3522]");
+        assert_eq!(
+            serde_json::from_str::<HumanCoordinate>(&json).unwrap(),
+            paris
+        );
+        let bytes = bincode::serialize(&paris).unwrap();
+        assert_eq!(
+            bincode::deserialize::<HumanCoordinate>(&bytes).unwrap(),
+            paris
+        );
+        let parsed: HumanCoordinate = serde_json::from_str("\"48°51′24″N 2°21′08″E\"").unwrap();
+        assert_eq!(parsed.concise(), paris.concise());
+        let parsed: HumanCoordinate = serde_json::from_str("\"-33.8688, 151.2093\"").unwrap();
+        assert_eq!(parsed.latitude(), -33.8688);
+        assert!(serde_json::from_str::<HumanCoordinate>("[91.0,0.0]").is_err());
+        assert!(serde_json::from_str::<HumanCoordinate>("[48.8567]").is_err());
+        assert!(serde_json::from_str::<HumanCoordinate>("\"Paris\"").is_err());
+
+        let run = HumanPace::new(10_000.0, Duration::from_secs(55 * 60)).per_mile();
+        let json = serde_json::to_string(&run).unwrap();
+        assert_eq!(json, "[10000.0,3300.0]");
+        let pace: HumanPace = serde_json::from_str(&json).unwrap();
+        assert_eq!(pace, run.per_km());
+        assert_eq!(pace.concise(), "5:30 /km");
+        let bytes = bincode::serialize(&run).unwrap();
+        assert_eq!(
+            bincode::deserialize::<HumanPace>(&bytes).unwrap(),
+            run.per_km()
+        );
+        assert!(serde_json::from_str::<HumanPace>("[10000.0,-1.0]").is_err());
+
+        let uptime = HumanUptime::from(Duration::from_secs(3 * 86_400 + 4 * 3_600 + 5 * 60));
+        let json = serde_json::to_string(&uptime).unwrap();
+        assert_eq!(json, "273900.0");
+        assert_eq!(serde_json::from_str::<HumanUptime>(&json).unwrap(), uptime);
+        assert_eq!(
+            serde_json::from_str::<HumanUptime>("273900").unwrap(),
+            uptime
+        );
+        let bytes = bincode::serialize(&uptime).unwrap();
+        assert_eq!(bincode::deserialize::<HumanUptime>(&bytes).unwrap(), uptime);
+        assert!(serde_json::from_str::<HumanUptime>("-1").is_err());
+
+        // Timestamps past what SystemTime holds are errors, not panics
+        for json in ["1e19", "-1e19", "1e300"] {
+            assert!(
+                serde_json::from_str::<HumanDuration>(json).is_err(),
+                "{}",
+                json
+            );
+            assert!(serde_json::from_str::<HumanDate>(json).is_err(), "{}", json);
+            assert!(serde_json::from_str::<HumanAge>(json).is_err(), "{}", json);
+        }
     }
 }
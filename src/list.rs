@@ -0,0 +1,144 @@
+use std::fmt::{self, Display, Write};
+
+use crate::core::{HumanFormat, Style, collect, pad, typeset};
+
+/* -------------------- HumanList -------------------- */
+
+/// Joins items into natural language: "apples, pears, and plums".
+///
+/// `full()` uses the conjunction ("Alice and Bob"), `concise()` only commas
+/// ("Alice, Bob"). An empty list renders as an empty string and a single item
+/// as itself. With a [`limit`](HumanList::limit), the remaining items are
+/// counted instead: "Alice, Bob, and 3 others" / "Alice, Bob, +3". A limit of
+/// zero is treated as one.
+#[derive(Debug, PartialEq)]
+pub struct HumanList<'a, T: Display> {
+    items: &'a [T],
+    conjunction: &'static str,
+    oxford_comma: bool,
+    limit: Option<usize>,
+}
+
+// Derived Clone/Copy would require `T: Copy`, but only the slice is copied.
+impl<T: Display> Clone for HumanList<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Display> Copy for HumanList<'_, T> {}
+
+// Same as `human_display!`, which doesn't take generic types.
+impl<T: Display> Display for HumanList<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let style = if f.alternate() {
+            Style::Concise
+        } else {
+            Style::Full
+        };
+
+        if f.width().is_some() || f.precision().is_some() {
+            pad(f, |mut out| self.write_into(&mut out, style))
+        } else {
+            self.write_into(f, style)
+        }
+    }
+}
+
+impl<T: Display> HumanFormat for HumanList<'_, T> {
+    fn concise(&self) -> String {
+        collect(|out| self.write_into(out, Style::Concise))
+    }
+
+    fn full(&self) -> String {
+        collect(|out| self.write_into(out, Style::Full))
+    }
+}
+
+impl<'a, T: Display> HumanList<'a, T> {
+    pub fn from(items: &'a [T]) -> Self {
+        Self {
+            items,
+            conjunction: "and",
+            oxford_comma: true,
+            limit: None,
+        }
+    }
+
+    /// Join with "or" instead of "and".
+    pub fn or(mut self) -> Self {
+        self.conjunction = "or";
+        self
+    }
+
+    /// Drop the comma before the conjunction: "a, b and c".
+    pub fn no_oxford_comma(mut self) -> Self {
+        self.oxford_comma = false;
+        self
+    }
+
+    /// Show at most `limit` items and count the rest.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit.max(1));
+        self
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        let shown = match self.limit {
+            Some(limit) if limit < self.items.len() => &self.items[..limit],
+            _ => self.items,
+        };
+        let hidden = self.items.len() - shown.len();
+
+        if style == Style::Concise {
+            for (i, item) in shown.iter().enumerate() {
+                if i > 0 {
+                    w.write_str(", ")?;
+                }
+                write!(w, "{}", item)?;
+            }
+            if hidden > 0 {
+                write!(w, ", +{}", hidden)?;
+            }
+            return Ok(());
+        }
+
+        // The "N others" tail counts as the last item for punctuation.
+        let total = shown.len() + usize::from(hidden > 0);
+        for (i, item) in shown.iter().enumerate() {
+            self.write_separator(w, i, total)?;
+            write!(w, "{}", item)?;
+        }
+        if hidden > 0 {
+            self.write_separator(w, total - 1, total)?;
+            let others = if hidden == 1 { "other" } else { "others" };
+            write!(w, "{} {}", hidden, others)?;
+        }
+        Ok(())
+    }
+
+    // Punctuation before the item at `index` in a list of `total`.
+    fn write_separator(&self, w: &mut impl Write, index: usize, total: usize) -> fmt::Result {
+        if index == 0 {
+            Ok(())
+        } else if index < total - 1 {
+            w.write_str(", ")
+        } else if total > 2 && self.oxford_comma {
+            write!(w, ", {} ", self.conjunction)
+        } else {
+            write!(w, " {} ", self.conjunction)
+        }
+    }
+}
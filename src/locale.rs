@@ -0,0 +1,283 @@
+/* -------------------- Locale -------------------- */
+
+/// Language used for the words in `full()` output.
+///
+/// Concise output is the same in every locale.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    English,
+    Spanish,
+}
+
+/// A unit of time, smallest first, for capping the largest unit
+/// [`HumanTime`](crate::HumanTime) breaks a duration into, or the range of
+/// units [`HumanDuration`](crate::HumanDuration) reports in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl TimeUnit {
+    // Concise suffix, the same in every locale.
+    pub(crate) fn symbol(self) -> &'static str {
+        match self {
+            TimeUnit::Second => "s",
+            TimeUnit::Minute => "m",
+            TimeUnit::Hour => "h",
+            TimeUnit::Day => "d",
+            TimeUnit::Week => "w",
+            TimeUnit::Month => "mo",
+            TimeUnit::Year => "y",
+        }
+    }
+
+    // Length in seconds. Months and years are the Gregorian averages
+    // (30.436875 and 365.2425 days), so two years is 730 days and a bit
+    // rather than 720.
+    pub(crate) fn seconds(self) -> u64 {
+        match self {
+            TimeUnit::Second => 1,
+            TimeUnit::Minute => 60,
+            TimeUnit::Hour => 3_600,
+            TimeUnit::Day => 86_400,
+            TimeUnit::Week => 604_800,
+            TimeUnit::Month => 2_629_746,
+            TimeUnit::Year => 31_556_952,
+        }
+    }
+}
+
+impl Locale {
+    // Both locales only use the singular for exactly one.
+    pub(crate) fn is_singular(self, count: f64) -> bool {
+        count.abs() == 1.0
+    }
+
+    pub(crate) fn decimal_separator(self) -> char {
+        match self {
+            Locale::English => '.',
+            Locale::Spanish => ',',
+        }
+    }
+
+    // `magnitude` is 1 for thousands, 2 for millions, and so on (short scale).
+    pub(crate) fn number_word(self, magnitude: usize, singular: bool) -> &'static str {
+        const ENGLISH: [&str; 6] = [
+            "thousand",
+            "million",
+            "billion",
+            "trillion",
+            "quadrillion",
+            "quintillion",
+        ];
+        const SPANISH: [(&str, &str); 6] = [
+            ("mil", "mil"),
+            ("millón", "millones"),
+            ("mil millones", "mil millones"),
+            ("billón", "billones"),
+            ("mil billones", "mil billones"),
+            ("trillón", "trillones"),
+        ];
+
+        match self {
+            Locale::English => ENGLISH[magnitude - 1],
+            Locale::Spanish => {
+                let (one, many) = SPANISH[magnitude - 1];
+                if singular { one } else { many }
+            }
+        }
+    }
+
+    pub(crate) fn byte_word(self, singular: bool) -> &'static str {
+        if singular { "byte" } else { "bytes" }
+    }
+
+    pub(crate) fn time_unit(self, unit: TimeUnit, singular: bool) -> &'static str {
+        let (one, many) = match self {
+            Locale::English => match unit {
+                TimeUnit::Second => ("second", "seconds"),
+                TimeUnit::Minute => ("minute", "minutes"),
+                TimeUnit::Hour => ("hour", "hours"),
+                TimeUnit::Day => ("day", "days"),
+                TimeUnit::Week => ("week", "weeks"),
+                TimeUnit::Month => ("month", "months"),
+                TimeUnit::Year => ("year", "years"),
+            },
+            Locale::Spanish => match unit {
+                TimeUnit::Second => ("segundo", "segundos"),
+                TimeUnit::Minute => ("minuto", "minutos"),
+                TimeUnit::Hour => ("hora", "horas"),
+                TimeUnit::Day => ("día", "días"),
+                TimeUnit::Week => ("semana", "semanas"),
+                TimeUnit::Month => ("mes", "meses"),
+                TimeUnit::Year => ("año", "años"),
+            },
+        };
+        if singular { one } else { many }
+    }
+
+    // Milliseconds, microseconds and nanoseconds for `idx` 0, 1 and 2.
+    pub(crate) fn subsecond_unit(self, idx: usize, singular: bool) -> &'static str {
+        let (one, many) = match self {
+            Locale::English => [
+                ("millisecond", "milliseconds"),
+                ("microsecond", "microseconds"),
+                ("nanosecond", "nanoseconds"),
+            ][idx],
+            Locale::Spanish => [
+                ("milisegundo", "milisegundos"),
+                ("microsegundo", "microsegundos"),
+                ("nanosegundo", "nanosegundos"),
+            ][idx],
+        };
+        if singular { one } else { many }
+    }
+
+    // Text around the quantity: "2 hours ago" / "hace 2 horas"
+    pub(crate) fn ago(self) -> (&'static str, &'static str) {
+        match self {
+            Locale::English => ("", " ago"),
+            Locale::Spanish => ("hace ", ""),
+        }
+    }
+
+    // The same for the future: "2 hours from now" / "dentro de 2 horas"
+    pub(crate) fn ahead(self) -> (&'static str, &'static str) {
+        match self {
+            Locale::English => ("", " from now"),
+            Locale::Spanish => ("dentro de ", ""),
+        }
+    }
+
+    pub(crate) fn just_now(self) -> &'static str {
+        match self {
+            Locale::English => "just now",
+            Locale::Spanish => "justo ahora",
+        }
+    }
+
+    pub(crate) fn yesterday(self) -> &'static str {
+        match self {
+            Locale::English => "yesterday",
+            Locale::Spanish => "ayer",
+        }
+    }
+
+    pub(crate) fn tomorrow(self) -> &'static str {
+        match self {
+            Locale::English => "tomorrow",
+            Locale::Spanish => "mañana",
+        }
+    }
+
+    // "last week" or "next year" for one week, month or year away
+    pub(crate) fn adjacent(self, unit: TimeUnit, future: bool) -> Option<&'static str> {
+        let phrase = match (self, unit, future) {
+            (Locale::English, TimeUnit::Week, false) => "last week",
+            (Locale::English, TimeUnit::Week, true) => "next week",
+            (Locale::English, TimeUnit::Month, false) => "last month",
+            (Locale::English, TimeUnit::Month, true) => "next month",
+            (Locale::English, TimeUnit::Year, false) => "last year",
+            (Locale::English, TimeUnit::Year, true) => "next year",
+            (Locale::Spanish, TimeUnit::Week, false) => "la semana pasada",
+            (Locale::Spanish, TimeUnit::Week, true) => "la próxima semana",
+            (Locale::Spanish, TimeUnit::Month, false) => "el mes pasado",
+            (Locale::Spanish, TimeUnit::Month, true) => "el próximo mes",
+            (Locale::Spanish, TimeUnit::Year, false) => "el año pasado",
+            (Locale::Spanish, TimeUnit::Year, true) => "el próximo año",
+            _ => return None,
+        };
+        Some(phrase)
+    }
+
+    pub(crate) fn today(self) -> &'static str {
+        match self {
+            Locale::English => "today",
+            Locale::Spanish => "hoy",
+        }
+    }
+
+    // `weekday` counts from 0 = Sunday, as in `CivilDateTime`
+    pub(crate) fn weekday(self, weekday: u32) -> &'static str {
+        const ENGLISH: [&str; 7] = [
+            "Sunday",
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+        ];
+        const SPANISH: [&str; 7] = [
+            "domingo",
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+        ];
+        match self {
+            Locale::English => ENGLISH[weekday as usize],
+            Locale::Spanish => SPANISH[weekday as usize],
+        }
+    }
+
+    // Around a weekday name: "last Tuesday" / "el martes pasado"
+    pub(crate) fn weekday_relative(self, future: bool) -> (&'static str, &'static str) {
+        match (self, future) {
+            (Locale::English, false) => ("last ", ""),
+            (Locale::English, true) => ("next ", ""),
+            (Locale::Spanish, false) => ("el ", " pasado"),
+            (Locale::Spanish, true) => ("el próximo ", ""),
+        }
+    }
+
+    // Before an approximate time: "about 2 hours", or "over 2 hours" when
+    // the count was rounded down
+    pub(crate) fn approximately(self, over: bool) -> &'static str {
+        match (self, over) {
+            (Locale::English, false) => "about ",
+            (Locale::English, true) => "over ",
+            (Locale::Spanish, false) => "alrededor de ",
+            (Locale::Spanish, true) => "más de ",
+        }
+    }
+
+    pub(crate) fn less_than_a_minute(self) -> &'static str {
+        match self {
+            Locale::English => "less than a minute",
+            Locale::Spanish => "menos de un minuto",
+        }
+    }
+
+    pub(crate) fn percent_word(self) -> &'static str {
+        match self {
+            Locale::English => "percent",
+            Locale::Spanish => "por ciento",
+        }
+    }
+
+    pub(crate) fn per_mille_word(self) -> &'static str {
+        match self {
+            Locale::English => "per mille",
+            Locale::Spanish => "por mil",
+        }
+    }
+
+    pub(crate) fn basis_point_word(self, singular: bool) -> &'static str {
+        match (self, singular) {
+            (Locale::English, true) => "basis point",
+            (Locale::English, false) => "basis points",
+            (Locale::Spanish, true) => "punto básico",
+            (Locale::Spanish, false) => "puntos básicos",
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt::{self, Write};
+
+use crate::config::decimal_mark;
+use crate::core::{HumanFormat, Style, Unit, human_display, pick_unit, typeset, write_trimmed};
+
+/* -------------------- HumanMass -------------------- */
+
+const METRIC: [Unit; 4] = [
+    Unit::new(0.001, 0.0, "mg", "milligram", "milligrams"),
+    Unit::new(1.0, 1.0, "g", "gram", "grams"),
+    Unit::new(1_000.0, 1.0, "kg", "kilogram", "kilograms"),
+    Unit::new(1_000_000.0, 1.0, "t", "tonne", "tonnes"),
+];
+
+const IMPERIAL: [Unit; 2] = [
+    Unit::new(OUNCE, 0.0, "oz", "ounce", "ounces"),
+    Unit::new(POUND, 1.0, "lb", "pound", "pounds"),
+];
+
+const OUNCE: f64 = 28.349_523_125;
+const POUND: f64 = 453.592_37;
+
+/// Masses: "1.2 kg" concise, "1.2 kilograms" full.
+///
+/// Metric output moves between mg, g, kg and t; with
+/// [`imperial`](HumanMass::imperial) it uses ounces and pounds instead, and
+/// [`pounds_and_ounces`](HumanMass::pounds_and_ounces) splits pounds into
+/// "2 lb 10 oz". Negative and non-finite masses render as "-".
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HumanMass {
+    pub(crate) grams: f64,
+    imperial: bool,
+    split: bool,
+    precision: usize,
+}
+
+human_display!(HumanMass);
+
+impl HumanMass {
+    pub fn from_grams(grams: f64) -> Self {
+        Self {
+            grams,
+            imperial: false,
+            split: false,
+            precision: 1,
+        }
+    }
+
+    /// Ounces and pounds instead of metric units.
+    pub fn imperial(mut self) -> Self {
+        self.imperial = true;
+        self
+    }
+
+    /// Metric units (the default).
+    pub fn metric(mut self) -> Self {
+        self.imperial = false;
+        self.split = false;
+        self
+    }
+
+    /// Imperial units with the ounces left over after whole pounds:
+    /// "2 lb 10 oz", "2 pounds 10 ounces".
+    pub fn pounds_and_ounces(mut self) -> Self {
+        self.imperial = true;
+        self.split = true;
+        self
+    }
+
+    /// Maximum number of decimal places (default 1); trailing zeros are
+    /// trimmed. With [`pounds_and_ounces`](HumanMass::pounds_and_ounces) it
+    /// applies to the ounces.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision.min(300);
+        self
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    fn write_value(
+        &self,
+        w: &mut impl Write,
+        style: Style,
+        value: f64,
+        unit: &Unit,
+    ) -> fmt::Result {
+        write_trimmed(w, value, self.precision, decimal_mark(None, style))?;
+        match style {
+            Style::Concise => write!(w, " {}", unit.symbol),
+            Style::Full if value == 1.0 => write!(w, " {}", unit.singular),
+            Style::Full => write!(w, " {}", unit.plural),
+        }
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        if !self.grams.is_finite() || self.grams < 0.0 {
+            return w.write_str("-");
+        }
+
+        if self.split {
+            // Round the total first, so 15.99 oz carries into "1 lb".
+            let multiplier = 10_f64.powi(self.precision as i32);
+            // a value too large to scale has no fraction left to round
+            let round = |v: f64| match v * multiplier {
+                scaled if scaled.is_finite() => scaled.round() / multiplier,
+                _ => v,
+            };
+            let ounces = round(self.grams / OUNCE);
+            let pounds = (ounces / 16.0).floor();
+            let ounces = round(ounces - pounds * 16.0);
+
+            if pounds == 0.0 {
+                return self.write_value(w, style, ounces, &IMPERIAL[0]);
+            }
+            self.write_value(w, style, pounds, &IMPERIAL[1])?;
+            if ounces > 0.0 {
+                w.write_char(' ')?;
+                self.write_value(w, style, ounces, &IMPERIAL[0])?;
+            }
+            return Ok(());
+        }
+
+        let (units, base): (&[Unit], usize) = if self.imperial {
+            (&IMPERIAL, 0)
+        } else {
+            (&METRIC, 1)
+        };
+        let (rounded, unit) = pick_unit(self.grams, units, base, self.precision);
+        self.write_value(w, style, rounded, unit)
+    }
+}
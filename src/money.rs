@@ -0,0 +1,166 @@
+use std::fmt::{self, Write};
+
+use crate::HumanNumber;
+use crate::core::{HumanFormat, Style, human_display, typeset, write_grouped};
+
+/* -------------------- Currency -------------------- */
+
+/// ISO 4217 currencies supported by [`HumanMoney`].
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Currency {
+    USD,
+    EUR,
+    GBP,
+    JPY,
+}
+
+impl Currency {
+    /// Number of minor units in one major unit, as a power of ten
+    /// (2 for cents, 0 for the yen).
+    pub fn exponent(self) -> u32 {
+        match self {
+            Currency::USD | Currency::EUR | Currency::GBP => 2,
+            Currency::JPY => 0,
+        }
+    }
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Currency::USD => "USD",
+            Currency::EUR => "EUR",
+            Currency::GBP => "GBP",
+            Currency::JPY => "JPY",
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Currency::USD => "$",
+            Currency::EUR => "€",
+            Currency::GBP => "£",
+            Currency::JPY => "¥",
+        }
+    }
+
+    fn name(self, singular: bool) -> &'static str {
+        let (one, many) = match self {
+            Currency::USD => ("US dollar", "US dollars"),
+            Currency::EUR => ("euro", "euros"),
+            Currency::GBP => ("British pound", "British pounds"),
+            Currency::JPY => ("Japanese yen", "Japanese yen"),
+        };
+        if singular { one } else { many }
+    }
+}
+
+/* -------------------- HumanMoney -------------------- */
+
+/// Currency amounts: "$12,345.67" concise, "12,345.67 US dollars" full.
+///
+/// The amount is kept in integer minor units (cents for USD) so no precision
+/// is lost before formatting. Negative amounts get a leading minus sign, or
+/// are wrapped in parentheses with [`parentheses`](HumanMoney::parentheses).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HumanMoney {
+    pub(crate) minor_units: i64,
+    pub(crate) currency: Currency,
+    abbreviated: bool,
+    parentheses: bool,
+    code: bool,
+}
+
+human_display!(HumanMoney);
+
+impl HumanMoney {
+    pub fn new(minor_units: i64, currency: Currency) -> Self {
+        Self {
+            minor_units,
+            currency,
+            abbreviated: false,
+            parentheses: false,
+            code: false,
+        }
+    }
+
+    /// Shorten amounts of a thousand or more: "$12.3M", "12.3 million US dollars".
+    pub fn abbreviated(mut self) -> Self {
+        self.abbreviated = true;
+        self
+    }
+
+    /// Write negative amounts as "($12.34)" instead of "-$12.34".
+    pub fn parentheses(mut self) -> Self {
+        self.parentheses = true;
+        self
+    }
+
+    /// Use the currency code instead of the symbol: "USD 12,345.67".
+    pub fn code(mut self) -> Self {
+        self.code = true;
+        self
+    }
+
+    /// Use the currency symbol (the default): "$12,345.67".
+    pub fn symbol(mut self) -> Self {
+        self.code = false;
+        self
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        let negative = self.minor_units < 0;
+        if negative {
+            w.write_str(if self.parentheses { "(" } else { "-" })?;
+        }
+
+        if style == Style::Concise {
+            if self.code {
+                write!(w, "{} ", self.currency.code())?;
+            } else {
+                w.write_str(self.currency.symbol())?;
+            }
+        }
+
+        let magnitude = self.minor_units.unsigned_abs();
+        let scale = 10_u64.pow(self.currency.exponent());
+        let (major, minor) = (magnitude / scale, magnitude % scale);
+
+        let singular = if self.abbreviated && major >= 1_000 {
+            let number = HumanNumber::from(magnitude as f64 / scale as f64);
+            number.write_into(w, style)?;
+            false
+        } else {
+            write_grouped(w, &major.to_string(), ',')?;
+            if self.currency.exponent() > 0 {
+                write!(
+                    w,
+                    ".{:0width$}",
+                    minor,
+                    width = self.currency.exponent() as usize
+                )?;
+            }
+            major == 1 && minor == 0
+        };
+
+        if style == Style::Full {
+            write!(w, " {}", self.currency.name(singular))?;
+        }
+
+        if negative && self.parentheses {
+            w.write_char(')')?;
+        }
+        Ok(())
+    }
+}
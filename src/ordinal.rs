@@ -0,0 +1,59 @@
+use std::fmt::{self, Write};
+
+use crate::core::{HumanFormat, Style, human_display, typeset};
+use crate::words::spell_ordinal;
+
+/* -------------------- HumanOrdinal -------------------- */
+
+/// Ordinal numbers: "21st" concise, "twenty-first" full.
+///
+/// Zero is "0th" / "zeroth". Negative numbers keep their sign: -3 is "-3rd"
+/// concise and "negative third" full.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HumanOrdinal {
+    pub(crate) number: i64,
+}
+
+human_display!(HumanOrdinal);
+
+impl HumanOrdinal {
+    pub fn from(number: i64) -> Self {
+        Self { number }
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        let magnitude = self.number.unsigned_abs();
+
+        match style {
+            Style::Concise => {
+                // 11th, 12th and 13th are exceptions to the last-digit rule
+                let suffix = match (magnitude % 10, magnitude % 100) {
+                    (_, 11..=13) => "th",
+                    (1, _) => "st",
+                    (2, _) => "nd",
+                    (3, _) => "rd",
+                    _ => "th",
+                };
+                write!(w, "{}{}", self.number, suffix)
+            }
+            Style::Full => {
+                if self.number < 0 {
+                    w.write_str("negative ")?;
+                }
+                w.write_str(&spell_ordinal(magnitude))
+            }
+        }
+    }
+}
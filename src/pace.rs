@@ -0,0 +1,139 @@
+use std::fmt::{self, Write};
+use std::time::Duration;
+
+use crate::core::{HumanFormat, Style, human_display, typeset};
+
+/* -------------------- HumanPace -------------------- */
+
+const METERS_PER_MILE: f64 = 1_609.344;
+
+/// Pace as time per distance: "5:30 /km" concise, "5 minutes 30 seconds per
+/// kilometer" full.
+///
+/// Paces of an hour or more per unit gain hours: "1:05:30 /km". Use
+/// [`per_mile`](HumanPace::per_mile) for "8:51 /mi". A distance that is zero,
+/// negative or not finite has no pace and renders as "-".
+///
+/// ```rust
+/// use humanly::HumanPace;
+/// use std::time::Duration;
+///
+/// let run = HumanPace::new(10_000.0, Duration::from_secs(55 * 60));
+/// assert_eq!(run.concise(), "5:30 /km");
+/// assert_eq!(run.per_mile().concise(), "8:51 /mi");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HumanPace {
+    pub(crate) meters: f64,
+    pub(crate) elapsed: Duration,
+    per_mile: bool,
+}
+
+human_display!(HumanPace);
+
+impl HumanPace {
+    pub fn new(distance_meters: f64, elapsed: Duration) -> Self {
+        Self {
+            meters: distance_meters,
+            elapsed,
+            per_mile: false,
+        }
+    }
+
+    /// Time per kilometer (the default).
+    pub fn per_km(mut self) -> Self {
+        self.per_mile = false;
+        self
+    }
+
+    /// Time per mile.
+    pub fn per_mile(mut self) -> Self {
+        self.per_mile = true;
+        self
+    }
+
+    // Distance in meters, or `None` when it can't be divided by.
+    fn distance(&self) -> Option<f64> {
+        (self.meters.is_finite() && self.meters > 0.0).then_some(self.meters)
+    }
+
+    /// Seconds per kilometer or mile, or `None` without a usable distance.
+    pub fn seconds_per_unit(&self) -> Option<f64> {
+        let unit = if self.per_mile {
+            METERS_PER_MILE
+        } else {
+            1_000.0
+        };
+        self.distance()
+            .map(|meters| self.elapsed.as_secs_f64() * unit / meters)
+    }
+
+    /// The inverse view: speed in kilometers per hour, or `None` without a
+    /// usable distance or with no elapsed time.
+    pub fn speed_kmh(&self) -> Option<f64> {
+        let hours = self.elapsed.as_secs_f64() / 3_600.0;
+        self.distance()
+            .filter(|_| hours > 0.0)
+            .map(|meters| meters / 1_000.0 / hours)
+    }
+
+    /// Speed in miles per hour, as [`speed_kmh`](HumanPace::speed_kmh).
+    pub fn speed_mph(&self) -> Option<f64> {
+        self.speed_kmh().map(|kmh| kmh * 1_000.0 / METERS_PER_MILE)
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        let Some(seconds) = self.seconds_per_unit() else {
+            return w.write_str("-");
+        };
+        let total = seconds.round() as u64;
+        let (hours, minutes, seconds) = (total / 3600, total % 3600 / 60, total % 60);
+
+        match style {
+            Style::Concise => {
+                if hours > 0 {
+                    write!(w, "{}:{:02}:{:02}", hours, minutes, seconds)?;
+                } else {
+                    write!(w, "{}:{:02}", minutes, seconds)?;
+                }
+                w.write_str(if self.per_mile { " /mi" } else { " /km" })
+            }
+            Style::Full => {
+                let mut first = true;
+                for (count, singular, plural) in [
+                    (hours, "hour", "hours"),
+                    (minutes, "minute", "minutes"),
+                    (seconds, "second", "seconds"),
+                ] {
+                    // Zero parts are left out, unless everything is zero
+                    if count == 0 && !(first && singular == "second") {
+                        continue;
+                    }
+                    if !first {
+                        w.write_char(' ')?;
+                    }
+                    first = false;
+                    let unit = if count == 1 { singular } else { plural };
+                    write!(w, "{} {}", count, unit)?;
+                }
+                w.write_str(if self.per_mile {
+                    " per mile"
+                } else {
+                    " per kilometer"
+                })
+            }
+        }
+    }
+}
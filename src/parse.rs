@@ -0,0 +1,588 @@
+use std::time::Duration;
+
+use crate::error::{ParseError, ParseErrorKind};
+
+/* -------------------- Size -------------------- */
+
+const SIZE_UNITS: &[(&str, u64)] = &[
+    ("b", 1),
+    ("k", 1_000),
+    ("kb", 1_000),
+    ("ki", 1 << 10),
+    ("kib", 1 << 10),
+    ("m", 1_000_000),
+    ("mb", 1_000_000),
+    ("mi", 1 << 20),
+    ("mib", 1 << 20),
+    ("g", 1_000_000_000),
+    ("gb", 1_000_000_000),
+    ("gi", 1 << 30),
+    ("gib", 1 << 30),
+    ("t", 1_000_000_000_000),
+    ("tb", 1_000_000_000_000),
+    ("ti", 1 << 40),
+    ("tib", 1 << 40),
+    ("p", 1_000_000_000_000_000),
+    ("pb", 1_000_000_000_000_000),
+    ("pi", 1 << 50),
+    ("pib", 1 << 50),
+    ("e", 1_000_000_000_000_000_000),
+    ("eb", 1_000_000_000_000_000_000),
+    ("ei", 1 << 60),
+    ("eib", 1 << 60),
+];
+
+pub(crate) const SIZE_SUFFIXES: &str = "B, kB, KiB, MB, MiB, GB, GiB, TB, TiB, PB, PiB, EB, EiB";
+
+// Byte offset of `part`, a subslice of `input`.
+pub(crate) fn offset(input: &str, part: &str) -> usize {
+    part.as_ptr() as usize - input.as_ptr() as usize
+}
+
+// Splits "1.5 GiB" into ("1.5", "GiB").
+fn split_number(input: &str) -> (&str, &str) {
+    let end = input
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(input.len());
+    (&input[..end], input[end..].trim_start())
+}
+
+// Splits "GiB later" into ("GiB", " later").
+fn split_unit(input: &str) -> (&str, &str) {
+    let end = input
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(input.len());
+    (&input[..end], &input[end..])
+}
+
+// Splits a leading sign off, accepting the minus sign (U+2212) written with
+// `Typography::Unicode` as well as "-": returns -1.0 or 1.0 and the rest.
+fn split_sign(input: &str) -> (f64, &str) {
+    match input.strip_prefix(['-', '\u{2212}']) {
+        Some(unsigned) => (-1.0, unsigned),
+        None => (1.0, input.strip_prefix('+').unwrap_or(input)),
+    }
+}
+
+fn parse_number(input: &str, number: &str) -> Result<f64, ParseError> {
+    number
+        .parse()
+        .map_err(|_| ParseError::new(ParseErrorKind::InvalidNumber, offset(input, number), number))
+}
+
+pub(crate) fn parse_size(input: &str) -> Result<u64, ParseError> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(ParseError::new(ParseErrorKind::Empty, 0, input));
+    }
+
+    let (number, after) = split_number(trimmed);
+    let value = parse_number(input, number)?;
+    let (unit, rest) = split_unit(after);
+    if !rest.is_empty() {
+        return Err(ParseError::new(
+            ParseErrorKind::TrailingInput,
+            offset(input, rest),
+            rest,
+        ));
+    }
+
+    let multiplier = if unit.is_empty() {
+        1
+    } else {
+        SIZE_UNITS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(unit))
+            .map(|&(_, multiplier)| multiplier)
+            .ok_or_else(|| {
+                ParseError::new(ParseErrorKind::UnknownUnit, offset(input, unit), unit)
+                    .expected(SIZE_SUFFIXES)
+            })?
+    };
+
+    let out_of_range =
+        || ParseError::new(ParseErrorKind::OutOfRange, offset(input, trimmed), trimmed);
+    // Whole numbers are counted exactly, since `f64` can't tell u64::MAX
+    // from 2^64
+    if number.bytes().all(|b| b.is_ascii_digit()) {
+        return number
+            .parse::<u64>()
+            .ok()
+            .and_then(|whole| whole.checked_mul(multiplier))
+            .ok_or_else(out_of_range);
+    }
+    // `u64::MAX as f64` is 2^64, the first value past the range
+    let bytes = (value * multiplier as f64).round();
+    if bytes >= u64::MAX as f64 {
+        return Err(out_of_range());
+    }
+    Ok(bytes as u64)
+}
+
+/* -------------------- Duration -------------------- */
+
+const DURATION_UNITS: &[(&[&str], u64)] = &[
+    (&["s", "sec", "secs", "second", "seconds"], 1),
+    (&["m", "min", "mins", "minute", "minutes"], 60),
+    (&["h", "hr", "hrs", "hour", "hours"], 3_600),
+    (&["d", "day", "days"], 86_400),
+    (&["w", "wk", "wks", "week", "weeks"], 604_800),
+];
+
+pub(crate) const DURATION_SUFFIXES: &str = "w, d, h, m, s";
+
+pub(crate) fn parse_duration(input: &str) -> Result<Duration, ParseError> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(ParseError::new(ParseErrorKind::Empty, 0, input));
+    }
+    let out_of_range =
+        || ParseError::new(ParseErrorKind::OutOfRange, offset(input, trimmed), trimmed);
+
+    // A bare number is a count of seconds
+    if let Ok(secs) = trimmed.parse::<f64>() {
+        return Duration::try_from_secs_f64(secs).map_err(|_| out_of_range());
+    }
+
+    let mut total = 0.0;
+    let mut seen = [false; DURATION_UNITS.len()];
+    let mut rest = trimmed;
+    while !rest.is_empty() {
+        let (number, after) = split_number(rest);
+        let value = parse_number(input, number)?;
+        let (unit, after) = split_unit(after);
+        let idx = DURATION_UNITS
+            .iter()
+            .position(|(names, _)| names.iter().any(|name| name.eq_ignore_ascii_case(unit)))
+            .ok_or_else(|| {
+                ParseError::new(ParseErrorKind::UnknownUnit, offset(input, unit), unit)
+                    .expected(DURATION_SUFFIXES)
+            })?;
+
+        // "1h 2h" is more likely a typo than a sum
+        if std::mem::replace(&mut seen[idx], true) {
+            return Err(ParseError::new(
+                ParseErrorKind::DuplicateUnit,
+                offset(input, unit),
+                unit,
+            ));
+        }
+
+        total += value * DURATION_UNITS[idx].1 as f64;
+        rest = after.trim_start();
+    }
+
+    Duration::try_from_secs_f64(total).map_err(|_| out_of_range())
+}
+
+/* -------------------- ISO 8601 duration -------------------- */
+
+// Designator, length in seconds and whether it belongs after the "T".
+const ISO_UNITS: [(char, u64, bool); 5] = [
+    ('W', 604_800, false),
+    ('D', 86_400, false),
+    ('H', 3_600, true),
+    ('M', 60, true),
+    ('S', 1, true),
+];
+
+const ISO_DESIGNATORS: &str = "W, D, T, H, M, S";
+
+// "12.5" as nanoseconds of a unit `unit_secs` long. Digits past the 18th
+// decimal place are below a nanosecond for every unit and are dropped.
+fn parse_iso_number(input: &str, number: &str, unit_secs: u64) -> Result<u128, ParseError> {
+    let invalid = || ParseError::new(ParseErrorKind::InvalidNumber, offset(input, number), number);
+    let (whole, fraction) = match number.split_once(['.', ',']) {
+        Some((whole, fraction)) => (whole, fraction),
+        None => (number, ""),
+    };
+    let well_formed = |digits: &str| digits.bytes().all(|b| b.is_ascii_digit());
+    if whole.is_empty() || !well_formed(whole) || !well_formed(fraction) {
+        return Err(invalid());
+    }
+    if number.len() > whole.len() && fraction.is_empty() {
+        return Err(invalid());
+    }
+
+    let unit_nanos = u128::from(unit_secs) * 1_000_000_000;
+    let whole: u64 = whole
+        .parse()
+        .map_err(|_| ParseError::new(ParseErrorKind::OutOfRange, offset(input, number), number))?;
+    let fraction = &fraction[..fraction.len().min(18)];
+    let scale = 10_u128.pow(fraction.len() as u32);
+    let fraction = fraction.parse::<u128>().unwrap_or(0);
+    Ok(u128::from(whole) * unit_nanos + fraction * unit_nanos / scale)
+}
+
+// "P2DT3H4M5S", "PT0.5S", "P1W": an ISO 8601 duration without years and
+// months, whose length varies. Lowercase designators and a comma as the
+// decimal mark are accepted, and the components may come in any order.
+pub(crate) fn parse_iso8601_duration(input: &str) -> Result<Duration, ParseError> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(ParseError::new(ParseErrorKind::Empty, 0, input));
+    }
+    let Some(mut rest) = trimmed.strip_prefix(['P', 'p']) else {
+        let first = &trimmed[..trimmed.chars().next().map_or(0, char::len_utf8)];
+        return Err(
+            ParseError::new(ParseErrorKind::UnknownUnit, offset(input, first), first).expected("P"),
+        );
+    };
+
+    let mut nanos = 0_u128;
+    let mut seen = [false; ISO_UNITS.len()];
+    let mut time = false;
+    let mut components = 0;
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix(['T', 't']).filter(|_| !time) {
+            time = true;
+            components = 0;
+            rest = after;
+            continue;
+        }
+
+        let end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+            .unwrap_or(rest.len());
+        let (number, after) = rest.split_at(end);
+        let designator = &after[..after.chars().next().map_or(0, char::len_utf8)];
+        let idx = ISO_UNITS
+            .iter()
+            .position(|&(c, _, in_time)| {
+                in_time == time && designator.eq_ignore_ascii_case(c.encode_utf8(&mut [0; 4]))
+            })
+            .ok_or_else(|| {
+                ParseError::new(
+                    ParseErrorKind::UnknownUnit,
+                    offset(input, after),
+                    designator,
+                )
+                .expected(ISO_DESIGNATORS)
+            })?;
+        if std::mem::replace(&mut seen[idx], true) {
+            return Err(ParseError::new(
+                ParseErrorKind::DuplicateUnit,
+                offset(input, designator),
+                designator,
+            ));
+        }
+
+        nanos += parse_iso_number(input, number, ISO_UNITS[idx].1)?;
+        components += 1;
+        rest = &after[designator.len()..];
+    }
+
+    // "P" and a trailing "T" need at least one component after them
+    if components == 0 {
+        return Err(ParseError::new(
+            ParseErrorKind::InvalidNumber,
+            trimmed.len() + offset(input, trimmed),
+            "",
+        ));
+    }
+
+    let secs = u64::try_from(nanos / 1_000_000_000).map_err(|_| {
+        ParseError::new(ParseErrorKind::OutOfRange, offset(input, trimmed), trimmed)
+    })?;
+    Ok(Duration::new(secs, (nanos % 1_000_000_000) as u32))
+}
+
+/* -------------------- Permissions -------------------- */
+
+const FILE_TYPES: &str = "-, b, c, d, D, l, p, s";
+
+// What each slot of a triplet accepts, the execute slot by class.
+const MODE_SLOTS: [&str; 5] = ["r, -", "w, -", "x, s, S, -", "x, s, S, -", "x, t, T, -"];
+
+// "rwxr-xr-x", or "drwxr-xr-x" with the file type first as `ls -l` shows
+// it, to the low twelve bits of a mode.
+pub(crate) fn parse_permissions(input: &str) -> Result<u32, ParseError> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(ParseError::new(ParseErrorKind::Empty, 0, input));
+    }
+    let start = offset(input, trimmed);
+    let invalid = |at: usize, c: char| {
+        ParseError::new(
+            ParseErrorKind::InvalidMode,
+            start + at,
+            c.encode_utf8(&mut [0; 4]),
+        )
+    };
+
+    let chars: Vec<(usize, char)> = trimmed.char_indices().collect();
+    let slots = match chars.len() {
+        9 => &chars[..],
+        10 => {
+            let (at, kind) = chars[0];
+            if !"-bcdDlps".contains(kind) {
+                return Err(invalid(at, kind).expected(FILE_TYPES));
+            }
+            &chars[1..]
+        }
+        _ => {
+            return Err(ParseError::new(ParseErrorKind::InvalidMode, start, trimmed));
+        }
+    };
+
+    let mut mode = 0;
+    for (i, &(at, c)) in slots.iter().enumerate() {
+        let shift = 6 - i as u32 / 3 * 3;
+        let (special, letter) = [(0o4000, 's'), (0o2000, 's'), (0o1000, 't')][i / 3];
+        mode |= match (i % 3, c) {
+            (_, '-') => 0,
+            (0, 'r') => 0o4 << shift,
+            (1, 'w') => 0o2 << shift,
+            (2, 'x') => 0o1 << shift,
+            (2, c) if c == letter => 0o1 << shift | special,
+            (2, c) if c == letter.to_ascii_uppercase() => special,
+            (slot, c) => {
+                let expected = if slot < 2 { slot } else { 2 + i / 3 };
+                return Err(invalid(at, c).expected(MODE_SLOTS[expected]));
+            }
+        };
+    }
+    Ok(mode)
+}
+
+/* -------------------- Number -------------------- */
+
+// Exponents appended to the digits, so the value is read in one exactly
+// rounded step: "3.4M" is parsed as "3.4e6" rather than as 3.4 × 1e6.
+const NUMBER_UNITS: &[(&str, &str)] = &[
+    ("k", "e3"),
+    ("thousand", "e3"),
+    ("m", "e6"),
+    ("million", "e6"),
+    ("b", "e9"),
+    ("billion", "e9"),
+    ("t", "e12"),
+    ("trillion", "e12"),
+    ("q", "e15"),
+    ("quadrillion", "e15"),
+    ("qi", "e18"),
+    ("quintillion", "e18"),
+];
+
+pub(crate) const NUMBER_SUFFIXES: &str =
+    "k, M, B, T, Q, Qi, thousand, million, billion, trillion, quadrillion, quintillion";
+
+// Splits "1,234.5 million" into ("1,234.5", "million").
+fn split_grouped_number(input: &str) -> (&str, &str) {
+    let end = input
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+        .unwrap_or(input.len());
+    (&input[..end], input[end..].trim_start())
+}
+
+// "1,234.5" -> "1234.5". Commas must fall between groups of three digits, after
+// a first group of one to three, and only before the decimal point.
+fn strip_separators(number: &str) -> Option<String> {
+    let (whole, fraction) = match number.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (number, None),
+    };
+    if !whole.contains(',') {
+        return Some(number.to_owned());
+    }
+
+    let mut groups = whole.split(',');
+    let first = groups.next()?;
+    let digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if first.is_empty() || first.len() > 3 || !digits(first) {
+        return None;
+    }
+    let mut stripped = first.to_owned();
+    for group in groups {
+        if group.len() != 3 || !digits(group) {
+            return None;
+        }
+        stripped.push_str(group);
+    }
+    if let Some(fraction) = fraction {
+        stripped.push('.');
+        stripped.push_str(fraction);
+    }
+    Some(stripped)
+}
+
+// "1.8M", "-15k", "42", "1,234", "3.4 million": the concise and full
+// `HumanNumber` formats, in any case.
+pub(crate) fn parse_human_number(input: &str) -> Result<f64, ParseError> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(ParseError::new(ParseErrorKind::Empty, 0, input));
+    }
+
+    let (sign, unsigned) = split_sign(trimmed);
+    let (number, after) = split_grouped_number(unsigned);
+    let invalid = || ParseError::new(ParseErrorKind::InvalidNumber, offset(input, number), number);
+    // Rejects "", "." and the like before they are given an exponent
+    if !number.bytes().any(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let mut digits = strip_separators(number).ok_or_else(invalid)?;
+    let (unit, rest) = split_unit(after);
+    if !rest.is_empty() {
+        return Err(ParseError::new(
+            ParseErrorKind::TrailingInput,
+            offset(input, rest),
+            rest,
+        ));
+    }
+
+    if !unit.is_empty() {
+        let exponent = NUMBER_UNITS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(unit))
+            .map(|&(_, exponent)| exponent)
+            .ok_or_else(|| {
+                ParseError::new(ParseErrorKind::UnknownUnit, offset(input, unit), unit)
+                    .expected(NUMBER_SUFFIXES)
+            })?;
+        digits.push_str(exponent);
+    }
+    let value: f64 = digits.parse().map_err(|_| invalid())?;
+    Ok(sign * value)
+}
+
+/* -------------------- Percent -------------------- */
+
+// "12.3%", "12.3 %", "−12.3%" or "12.3".
+pub(crate) fn parse_percent(input: &str) -> Result<f64, ParseError> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(ParseError::new(ParseErrorKind::Empty, 0, input));
+    }
+    let (sign, unsigned) = split_sign(trimmed);
+    let number = unsigned.strip_suffix('%').unwrap_or(unsigned).trim_end();
+    // `f64` parsing takes "nan" and "inf", and overflows "1e400" to infinity
+    match parse_number(input, number)? {
+        value if value.is_finite() => Ok(sign * value),
+        _ => Err(ParseError::new(
+            ParseErrorKind::InvalidNumber,
+            offset(input, number),
+            number,
+        )),
+    }
+}
+
+/* -------------------- Coordinate -------------------- */
+
+const HEMISPHERES: &str = "N, S, E, W";
+
+// One axis of a coordinate as written, before the hemisphere is applied.
+struct Angle<'a> {
+    degrees: f64,
+    sign: f64,
+    hemisphere: Option<char>,
+    text: &'a str,
+}
+
+// Degrees with optional minutes and seconds, then an optional hemisphere:
+// "48°51′24″N", "48°51'24\"N", "48.8567° N", "-33.8688". Returns the angle
+// and what follows it.
+fn parse_angle<'a>(input: &str, part: &'a str) -> Result<(Angle<'a>, &'a str), ParseError> {
+    let (sign, unsigned) = split_sign(part);
+    let (number, after) = split_number(unsigned);
+    let mut degrees = parse_number(input, number)?;
+    let mut rest = after.strip_prefix(['°', 'º']).unwrap_or(after).trim_start();
+
+    // Minutes and seconds only count with their marks, so that a bare number
+    // after the degrees is left for the next axis.
+    for (marks, divisor) in [(['′', '\''], 60.0), (['″', '"'], 3600.0)] {
+        let (number, after) = split_number(rest);
+        let Some(after) = after.strip_prefix(marks).filter(|_| !number.is_empty()) else {
+            continue;
+        };
+        let value = parse_number(input, number)?;
+        if value >= 60.0 {
+            return Err(ParseError::new(
+                ParseErrorKind::OutOfRange,
+                offset(input, number),
+                number,
+            ));
+        }
+        degrees += value / divisor;
+        rest = after.trim_start();
+    }
+
+    let mut hemisphere = None;
+    let mut chars = rest.chars();
+    if let Some(letter) = chars.next().filter(|c| "NSEWnsew".contains(*c))
+        && !chars.next().is_some_and(|c| c.is_alphabetic())
+    {
+        hemisphere = Some(letter.to_ascii_uppercase());
+        rest = &rest[1..];
+    }
+
+    let text = part[..part.len() - rest.len()].trim_end();
+    let angle = Angle {
+        degrees,
+        sign,
+        hemisphere,
+        text,
+    };
+    Ok((angle, rest))
+}
+
+// "48°51′24″N 2°21′08″E", "48.8567° N, 2.3522° E" or "-33.8688, 151.2093":
+// latitude and longitude in degrees. The axes may come in either order when
+// both carry a hemisphere.
+pub(crate) fn parse_coordinate(input: &str) -> Result<(f64, f64), ParseError> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(ParseError::new(ParseErrorKind::Empty, 0, input));
+    }
+
+    let (first, rest) = parse_angle(input, trimmed)?;
+    let rest = rest.trim_start();
+    let rest = rest.strip_prefix(',').unwrap_or(rest).trim_start();
+    if rest.is_empty() {
+        return Err(ParseError::new(
+            ParseErrorKind::InvalidNumber,
+            offset(input, rest),
+            rest,
+        ));
+    }
+    let (second, rest) = parse_angle(input, rest)?;
+    if !rest.trim().is_empty() {
+        return Err(ParseError::new(
+            ParseErrorKind::TrailingInput,
+            offset(input, rest),
+            rest.trim(),
+        ));
+    }
+
+    let (latitude, longitude) = match (first.hemisphere, second.hemisphere) {
+        (Some('E' | 'W'), _) | (_, Some('N' | 'S')) => (second, first),
+        _ => (first, second),
+    };
+
+    let axis = |angle: Angle, positive: char, negative: char, max: f64| {
+        let unit = |c: char| {
+            let at = offset(input, angle.text) + angle.text.len() - c.len_utf8();
+            ParseError::new(ParseErrorKind::UnknownUnit, at, &c.to_string())
+        };
+        let degrees = match angle.hemisphere {
+            None => angle.sign * angle.degrees,
+            Some(c) if angle.sign < 0.0 => return Err(unit(c)),
+            Some(c) if c == positive => angle.degrees,
+            Some(c) if c == negative => -angle.degrees,
+            Some(c) => return Err(unit(c).expected(HEMISPHERES)),
+        };
+        if degrees.abs() > max {
+            return Err(ParseError::new(
+                ParseErrorKind::OutOfRange,
+                offset(input, angle.text),
+                angle.text,
+            ));
+        }
+        Ok(degrees)
+    };
+
+    Ok((
+        axis(latitude, 'N', 'S', 90.0)?,
+        axis(longitude, 'E', 'W', 180.0)?,
+    ))
+}
@@ -0,0 +1,99 @@
+use std::fmt;
+
+/* -------------------- Parts -------------------- */
+
+/// A formatted value split into pieces that can be styled separately, e.g.
+/// with the number and the unit in different fonts.
+///
+/// Concatenating `prefix`, `sign`, `number`, `unit` and `qualifier` in that
+/// order (which is what `Display` does) gives exactly the formatted string.
+/// Output without a number, like "just now" or the "-" placeholder, is all
+/// `qualifier`.
+///
+/// ```rust
+/// use humanly::{HumanSize, Style};
+///
+/// let parts = HumanSize::from(1_572_864).into_parts(Style::Full);
+/// assert_eq!(parts.number, "1.5");
+/// assert_eq!(parts.unit, " mebibytes");
+/// assert_eq!(parts.to_string(), "1.5 mebibytes");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Parts {
+    /// Text before the value: "hace " in Spanish relative times, or the space
+    /// between components of a [`HumanTime`](crate::HumanTime).
+    pub prefix: String,
+    /// "-" for negative numbers, otherwise empty.
+    pub sign: String,
+    /// The digits, with the decimal mark: "1.5", "1,5".
+    pub number: String,
+    /// The unit or suffix with any space before it: "k", " MiB", " million".
+    pub unit: String,
+    /// Text after the unit, like " ago".
+    pub qualifier: String,
+}
+
+impl Parts {
+    // Splits formatted output around its first number. `qualifiers` lists the
+    // endings that belong in `qualifier` rather than `unit`.
+    pub(crate) fn split(text: &str, qualifiers: &[&str]) -> Self {
+        let Some(start) = text.find(|c: char| c.is_ascii_digit()) else {
+            return Self {
+                qualifier: text.to_string(),
+                ..Self::default()
+            };
+        };
+        let end = text[start..]
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+            .map_or(text.len(), |i| start + i);
+        let (before, rest) = text[..end].split_at(start);
+        let sign_len = before
+            .chars()
+            .next_back()
+            .filter(|c| matches!(c, '-' | '+' | '−'))
+            .map_or(0, char::len_utf8);
+        let (prefix, sign) = before.split_at(before.len() - sign_len);
+
+        let tail = &text[end..];
+        let qualifier = qualifiers
+            .iter()
+            .find(|q| !q.is_empty() && tail.ends_with(*q))
+            .map_or("", |q| *q);
+
+        Self {
+            prefix: prefix.to_string(),
+            sign: sign.to_string(),
+            number: rest.to_string(),
+            unit: tail[..tail.len() - qualifier.len()].to_string(),
+            qualifier: qualifier.to_string(),
+        }
+    }
+
+    // Splits output made of several numbers, like "1h 0m 5s", into one
+    // `Parts` per number; the space between them starts the next one.
+    pub(crate) fn split_components(text: &str) -> Vec<Self> {
+        let mut starts: Vec<usize> = text
+            .char_indices()
+            .zip(text.chars().skip(1))
+            .filter(|((_, c), next)| *c == ' ' && next.is_ascii_digit())
+            .map(|((i, _), _)| i)
+            .collect();
+        starts.insert(0, 0);
+        starts.push(text.len());
+
+        starts
+            .windows(2)
+            .map(|range| Self::split(&text[range[0]..range[1]], &[]))
+            .collect()
+    }
+}
+
+impl fmt::Display for Parts {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}{}{}",
+            self.prefix, self.sign, self.number, self.unit, self.qualifier
+        )
+    }
+}
@@ -0,0 +1,209 @@
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+use crate::HumanList;
+use crate::core::{HumanFormat, Style, human_display, typeset};
+use crate::error::ParseError;
+use crate::parse::parse_permissions;
+
+/* -------------------- HumanPermissions -------------------- */
+
+#[derive(Clone, Copy)]
+struct Triplet {
+    read: bool,
+    write: bool,
+    execute: bool,
+}
+
+#[derive(Clone, Copy)]
+struct Bits {
+    owner: Triplet,
+    group: Triplet,
+    others: Triplet,
+    setuid: bool,
+    setgid: bool,
+    sticky: bool,
+}
+
+impl Bits {
+    // Both the symbolic and the verbose formatters read the mode through this,
+    // so they can never disagree about which bits are set.
+    fn decode(mode: u32) -> Self {
+        let triplet = |shift: u32| Triplet {
+            read: mode >> shift & 0o4 != 0,
+            write: mode >> shift & 0o2 != 0,
+            execute: mode >> shift & 0o1 != 0,
+        };
+
+        Self {
+            owner: triplet(6),
+            group: triplet(3),
+            others: triplet(0),
+            setuid: mode & 0o4000 != 0,
+            setgid: mode & 0o2000 != 0,
+            sticky: mode & 0o1000 != 0,
+        }
+    }
+}
+
+/// Unix permission bits: "rwxr-xr-x" concise, and a sentence in full:
+/// "Owner can read, write and execute; group can read and execute; others can
+/// read and execute."
+///
+/// Only the low twelve bits of the mode are read, so the `st_mode` of a file
+/// can be passed as it is. The setuid, setgid and sticky bits show as `s`/`t`
+/// over the execute slot, capitalised when execute itself is not set.
+///
+/// ```rust
+/// use humanly::HumanPermissions;
+///
+/// assert_eq!(HumanPermissions::from(0o4755).concise(), "rwsr-xr-x");
+/// assert_eq!(
+///     HumanPermissions::from(0o644).full(),
+///     "Owner can read and write; group can read; others can read."
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HumanPermissions {
+    pub(crate) mode: u32,
+}
+
+human_display!(HumanPermissions);
+
+impl HumanPermissions {
+    pub fn from(mode: u32) -> Self {
+        Self { mode }
+    }
+
+    /// Reads a symbolic mode back into its bits, for `chmod`: "rwxr-x---" is
+    /// 0o750. A leading file type as `ls -l` writes it ("drwxr-xr-x") is
+    /// skipped, and `s`/`S` and `t`/`T` set the special bits. Anything other
+    /// than nine slots, each with its own letter or "-", is rejected.
+    ///
+    /// ```rust
+    /// use humanly::{HumanPermissions, ParseErrorKind};
+    ///
+    /// assert_eq!(HumanPermissions::parse("rwxr-x---"), Ok(0o750));
+    /// assert_eq!(HumanPermissions::parse("-rwsr-xr-x"), Ok(0o4755));
+    /// assert_eq!(HumanPermissions::parse("drwxrwxrwt"), Ok(0o1777));
+    /// assert_eq!(
+    ///     HumanPermissions::parse("rwxr-xr-q").unwrap_err().to_string(),
+    ///     "invalid mode 'q' at position 8 (expected one of x, t, T, -)"
+    /// );
+    /// ```
+    pub fn parse(input: &str) -> Result<u32, ParseError> {
+        parse_permissions(input)
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    /// Same as [`full`](Self::full).
+    pub fn describe(&self) -> String {
+        self.full()
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        let bits = Bits::decode(self.mode);
+
+        match style {
+            Style::Concise => {
+                for (triplet, special, special_char) in [
+                    (bits.owner, bits.setuid, 's'),
+                    (bits.group, bits.setgid, 's'),
+                    (bits.others, bits.sticky, 't'),
+                ] {
+                    w.write_char(if triplet.read { 'r' } else { '-' })?;
+                    w.write_char(if triplet.write { 'w' } else { '-' })?;
+                    w.write_char(match (triplet.execute, special) {
+                        (true, true) => special_char,
+                        (false, true) => special_char.to_ascii_uppercase(),
+                        (true, false) => 'x',
+                        (false, false) => '-',
+                    })?;
+                }
+                Ok(())
+            }
+            Style::Full => write_sentence(w, &bits),
+        }
+    }
+}
+
+fn write_sentence(w: &mut impl Write, bits: &Bits) -> fmt::Result {
+    for (i, (class, triplet)) in [
+        ("Owner", bits.owner),
+        ("group", bits.group),
+        ("others", bits.others),
+    ]
+    .iter()
+    .enumerate()
+    {
+        if i > 0 {
+            w.write_str("; ")?;
+        }
+
+        let mut actions = Vec::new();
+        if triplet.read {
+            actions.push("read");
+        }
+        if triplet.write {
+            actions.push("write");
+        }
+        if triplet.execute {
+            actions.push("execute");
+        }
+
+        if actions.is_empty() {
+            write!(w, "{} cannot do anything", class)?;
+        } else {
+            let actions = HumanList::from(&actions).no_oxford_comma();
+            write!(w, "{} can {}", class, actions)?;
+        }
+    }
+    w.write_char('.')?;
+
+    let mut specials = Vec::new();
+    if bits.setuid {
+        specials.push("setuid");
+    }
+    if bits.setgid {
+        specials.push("setgid");
+    }
+    if bits.sticky {
+        specials.push("sticky");
+    }
+
+    if !specials.is_empty() {
+        let (noun, verb) = if specials.len() == 1 {
+            ("bit", "is")
+        } else {
+            ("bits", "are")
+        };
+        write!(
+            w,
+            " The {} {} {} set.",
+            HumanList::from(&specials).no_oxford_comma(),
+            noun,
+            verb
+        )?;
+    }
+
+    Ok(())
+}
+
+impl FromStr for HumanPermissions {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        parse_permissions(input).map(HumanPermissions::from)
+    }
+}
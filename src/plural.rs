@@ -0,0 +1,102 @@
+use std::borrow::Cow;
+use std::fmt::{self, Write};
+
+use crate::HumanNumber;
+use crate::core::{HumanFormat, Style, human_display, typeset};
+
+/* -------------------- HumanPlural -------------------- */
+
+/// A count with a noun that agrees with it: "1 file", "3 files", "0 files".
+///
+/// Concise output writes the count as digits; full output writes it through
+/// [`HumanNumber`] ("1.2 thousand files"). Regular plurals are formed with the
+/// usual English spelling rules ("boxes", "cities"); irregular ones are given
+/// with [`from_pair`](HumanPlural::from_pair).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HumanPlural {
+    pub(crate) count: i64,
+    singular: Cow<'static, str>,
+    plural: Option<Cow<'static, str>>,
+    show_count: bool,
+}
+
+human_display!(HumanPlural);
+
+impl HumanPlural {
+    pub fn from(count: i64, singular: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            count,
+            singular: singular.into(),
+            plural: None,
+            show_count: true,
+        }
+    }
+
+    /// For irregular nouns: `from_pair(2, "index", "indices")`.
+    pub fn from_pair(
+        count: i64,
+        singular: impl Into<Cow<'static, str>>,
+        plural: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self {
+            plural: Some(plural.into()),
+            ..Self::from(count, singular)
+        }
+    }
+
+    /// Write only the noun, in the form that agrees with the count.
+    pub fn without_count(mut self) -> Self {
+        self.show_count = false;
+        self
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        if self.show_count {
+            match style {
+                Style::Concise => write!(w, "{}", self.count)?,
+                Style::Full => HumanNumber::from(self.count as f64).write_into(w, style)?,
+            }
+            w.write_char(' ')?;
+        }
+
+        if self.count.unsigned_abs() == 1 {
+            w.write_str(&self.singular)
+        } else if let Some(plural) = &self.plural {
+            w.write_str(plural)
+        } else {
+            write_regular_plural(w, &self.singular)
+        }
+    }
+}
+
+// "file" -> "files", "box" -> "boxes", "city" -> "cities", "day" -> "days"
+fn write_regular_plural(w: &mut impl Write, singular: &str) -> fmt::Result {
+    let lower = singular.to_ascii_lowercase();
+    let consonant_y = lower
+        .strip_suffix('y')
+        .and_then(|stem| stem.chars().last())
+        .is_some_and(|c| c.is_ascii_alphabetic() && !"aeiou".contains(c));
+
+    if consonant_y {
+        write!(w, "{}ies", &singular[..singular.len() - 1])
+    } else if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|suffix| lower.ends_with(suffix))
+    {
+        write!(w, "{}es", singular)
+    } else {
+        write!(w, "{}s", singular)
+    }
+}
@@ -0,0 +1,159 @@
+use std::borrow::Cow;
+use std::fmt::{self, Write};
+
+use crate::config::decimal_mark;
+use crate::core::{HumanFormat, Style, human_display, typeset, write_trimmed};
+
+/* -------------------- HumanQuantity -------------------- */
+
+// SI prefixes from pico to tera; index 4 is the unprefixed unit.
+const PREFIXES: [(&str, &str); 9] = [
+    ("p", "pico"),
+    ("n", "nano"),
+    ("µ", "micro"),
+    ("m", "milli"),
+    ("", ""),
+    ("k", "kilo"),
+    ("M", "mega"),
+    ("G", "giga"),
+    ("T", "tera"),
+];
+const BASE: usize = 4;
+
+// Symbols with a known name: (symbol, singular, plural).
+const UNIT_NAMES: &[(&str, &str, &str)] = &[
+    ("A", "ampere", "amperes"),
+    ("C", "coulomb", "coulombs"),
+    ("F", "farad", "farads"),
+    ("g", "gram", "grams"),
+    ("H", "henry", "henries"),
+    ("Hz", "hertz", "hertz"),
+    ("J", "joule", "joules"),
+    ("L", "liter", "liters"),
+    ("l", "liter", "liters"),
+    ("m", "meter", "meters"),
+    ("N", "newton", "newtons"),
+    ("Pa", "pascal", "pascals"),
+    ("s", "second", "seconds"),
+    ("S", "siemens", "siemens"),
+    ("V", "volt", "volts"),
+    ("W", "watt", "watts"),
+    ("Wh", "watt-hour", "watt-hours"),
+    ("Ω", "ohm", "ohms"),
+];
+
+/// Any unit with SI prefixes: `HumanQuantity::new(1_234.0, "W")` is "1.2 kW"
+/// concise and "1.2 kilowatts" full.
+///
+/// Common SI symbols know their names; for anything else set one with
+/// [`name`](HumanQuantity::name), and [`plural`](HumanQuantity::plural) for
+/// units whose plural isn't the name plus "s". Non-finite values render as "-".
+#[derive(Clone, Debug, PartialEq)]
+pub struct HumanQuantity {
+    pub(crate) value: f64,
+    pub(crate) unit: Cow<'static, str>,
+    name: Option<Cow<'static, str>>,
+    plural: Option<Cow<'static, str>>,
+    precision: usize,
+}
+
+human_display!(HumanQuantity);
+
+impl HumanQuantity {
+    pub fn new(value: f64, unit: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            value,
+            unit: unit.into(),
+            name: None,
+            plural: None,
+            precision: 1,
+        }
+    }
+
+    /// Singular name of the unit for `full()`, e.g. "lumen" for "lm".
+    pub fn name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Plural name of the unit for `full()`, e.g. "lux".
+    pub fn plural(mut self, plural: impl Into<Cow<'static, str>>) -> Self {
+        self.plural = Some(plural.into());
+        self
+    }
+
+    /// Maximum number of decimal places (default 1); trailing zeros are trimmed.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision.min(300);
+        self
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    // Rounds before picking a prefix, so 999.96 W is "1 kW" and not "1000 W".
+    fn scale(&self) -> (f64, usize) {
+        let multiplier = 10_f64.powi(self.precision as i32);
+        // a value too large to scale has no fraction left to round
+        let round = |v: f64| match v * multiplier {
+            scaled if scaled.is_finite() => scaled.round() / multiplier,
+            _ => v,
+        };
+
+        let mut scaled = self.value;
+        let mut idx = BASE;
+        if scaled != 0.0 {
+            while idx > 0 && round(scaled.abs()) < 1.0 {
+                scaled *= 1000.0;
+                idx -= 1;
+            }
+            while idx < PREFIXES.len() - 1 && round(scaled.abs()) >= 1000.0 {
+                scaled /= 1000.0;
+                idx += 1;
+            }
+        }
+        (round(scaled), idx)
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        if !self.value.is_finite() {
+            return w.write_str("-");
+        }
+
+        let (rounded, idx) = self.scale();
+        write_trimmed(w, rounded, self.precision, decimal_mark(None, style))?;
+
+        let (symbol, prefix) = PREFIXES[idx];
+        match style {
+            Style::Concise => write!(w, " {}{}", symbol, self.unit),
+            Style::Full => {
+                let known = UNIT_NAMES.iter().find(|(s, _, _)| *s == self.unit);
+                let singular = match (&self.name, known) {
+                    (Some(name), _) => name.as_ref(),
+                    (None, Some((_, name, _))) => name,
+                    (None, None) => self.unit.as_ref(),
+                };
+                write!(w, " {}", prefix)?;
+                if rounded.abs() == 1.0 {
+                    return w.write_str(singular);
+                }
+                match (&self.plural, &self.name, known) {
+                    (Some(plural), _, _) => w.write_str(plural),
+                    (None, None, Some((_, _, plural))) => w.write_str(plural),
+                    // A bare symbol has no plural form
+                    (None, None, None) => w.write_str(singular),
+                    (None, Some(_), _) => write!(w, "{}s", singular),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use std::fmt::{self, Write};
+use std::time::Duration;
+
+use crate::HumanNumber;
+use crate::config::{Config, decimal_mark};
+use crate::core::{
+    HumanFormat, Style, UnitSystem, human_display, magnitude, number_scale, typeset,
+    write_localized,
+};
+use crate::locale::{Locale, TimeUnit};
+
+/* -------------------- HumanRange -------------------- */
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Endpoints {
+    Number(f64, f64),
+    Size(u64, u64),
+    Time(Duration, Duration),
+}
+
+/// Two values sharing one unit: "1.5–2.0 GiB", "3–5 minutes".
+///
+/// The unit is picked for the larger endpoint and the smaller one is written
+/// in it; when either endpoint needs a decimal place both get one, so they line
+/// up. Concise number ranges keep a suffix on each end ("10k–20k"), since the
+/// suffix is part of the number. Endpoints may be given in either order, and
+/// equal endpoints collapse to a single value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HumanRange {
+    endpoints: Endpoints,
+    system: Option<UnitSystem>,
+    ascii: bool,
+}
+
+human_display!(HumanRange);
+
+impl HumanRange {
+    fn new(endpoints: Endpoints) -> Self {
+        Self {
+            endpoints,
+            system: None,
+            ascii: false,
+        }
+    }
+
+    pub fn numbers(low: impl Into<f64>, high: impl Into<f64>) -> Self {
+        let (low, high) = (low.into(), high.into());
+        Self::new(Endpoints::Number(low.min(high), low.max(high)))
+    }
+
+    pub fn sizes(low: u64, high: u64) -> Self {
+        Self::new(Endpoints::Size(low.min(high), low.max(high)))
+    }
+
+    pub fn times(low: Duration, high: Duration) -> Self {
+        Self::new(Endpoints::Time(low.min(high), low.max(high)))
+    }
+
+    /// SI units for size ranges (kB, MB…).
+    pub fn decimal(mut self) -> Self {
+        self.system = Some(UnitSystem::Decimal);
+        self
+    }
+
+    /// IEC units for size ranges (KiB, MiB…).
+    pub fn binary(mut self) -> Self {
+        self.system = Some(UnitSystem::Binary);
+        self
+    }
+
+    /// JEDEC units for size ranges (KB, MB… in steps of 1024).
+    pub fn jedec(mut self) -> Self {
+        self.system = Some(UnitSystem::Jedec);
+        self
+    }
+
+    /// Separate the endpoints with "-" instead of an en dash.
+    pub fn ascii(mut self) -> Self {
+        self.ascii = true;
+        self
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    // Writes both scaled endpoints, or one if they're equal once rounded.
+    fn write_pair(&self, w: &mut impl Write, style: Style, low: f64, high: f64) -> fmt::Result {
+        let round = |v: f64| (v * 10.0).round() / 10.0;
+        let (low, high) = (round(low), round(high));
+        let decimals = if low.fract() == 0.0 && high.fract() == 0.0 {
+            0
+        } else {
+            1
+        };
+        let mark = decimal_mark(None, style);
+
+        if low != high {
+            write_localized(w, mark, format_args!("{:.*}", decimals, low))?;
+            w.write_str(if self.ascii { "-" } else { "–" })?;
+        }
+        write_localized(w, mark, format_args!("{:.*}", decimals, high))
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        match self.endpoints {
+            Endpoints::Number(low, high) => {
+                let (divisor, _, magnitude) = number_scale(high.abs().max(low.abs()));
+                match style {
+                    Style::Concise => {
+                        HumanNumber::from(low).write_into(w, style)?;
+                        if low != high {
+                            w.write_str(if self.ascii { "-" } else { "–" })?;
+                            HumanNumber::from(high).write_into(w, style)?;
+                        }
+                        Ok(())
+                    }
+                    Style::Full => {
+                        self.write_pair(w, style, low / divisor, high / divisor)?;
+                        if magnitude > 0 {
+                            let singular = low == high && (high / divisor) == 1.0;
+                            write!(w, " {}", Locale::English.number_word(magnitude, singular))?;
+                        }
+                        Ok(())
+                    }
+                }
+            }
+            Endpoints::Size(low, high) => {
+                let system = self.system.unwrap_or(Config::current().unit_system);
+                let (units_short, units_full, step) = system.units();
+                let (idx, _) = magnitude(high, 0.0, system, units_short.len(), 1);
+                let divisor = step.powi(idx as i32);
+                self.write_pair(w, style, low as f64 / divisor, high as f64 / divisor)?;
+
+                let singular = (high as f64 / divisor * 10.0).round() == 10.0;
+                match (style, idx) {
+                    (Style::Concise, 0) => w.write_str(" B"),
+                    (Style::Concise, idx) => write!(w, " {}", units_short[idx - 1]),
+                    (Style::Full, 0) => write!(w, " {}", Locale::English.byte_word(singular)),
+                    (Style::Full, idx) => {
+                        write!(w, " {}", units_full[idx - 1])?;
+                        if !singular {
+                            w.write_char('s')?;
+                        }
+                        Ok(())
+                    }
+                }
+            }
+            Endpoints::Time(low, high) => {
+                let units = [
+                    (TimeUnit::Day, 86_400.0),
+                    (TimeUnit::Hour, 3_600.0),
+                    (TimeUnit::Minute, 60.0),
+                    (TimeUnit::Second, 1.0),
+                ];
+                let high_secs = high.as_secs_f64();
+                let &(unit, secs) = units
+                    .iter()
+                    .find(|(_, secs)| (high_secs / secs * 10.0).round() >= 10.0)
+                    .unwrap_or(&units[3]);
+                self.write_pair(w, style, low.as_secs_f64() / secs, high_secs / secs)?;
+
+                match style {
+                    Style::Concise => w.write_str(unit.symbol()),
+                    Style::Full => {
+                        let singular = (high_secs / secs * 10.0).round() == 10.0;
+                        write!(w, " {}", Locale::English.time_unit(unit, singular))
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! Conversions from [`rust_decimal`](https://docs.rs/rust_decimal) amounts,
+//! enabled with the `rust_decimal` feature.
+//!
+//! The types' own `from` constructors take primitive numbers, so convert
+//! with `into`. The unit and the displayed digits are worked out on the
+//! decimal itself, so values that `f64` can't hold still round correctly:
+//!
+//! ```rust
+//! use humanly::HumanNumber;
+//! use rust_decimal::Decimal;
+//!
+//! let amount: Decimal = "999949.99999999999999999".parse().unwrap();
+//! let number: HumanNumber = amount.into();
+//! assert_eq!(number.concise(), "999.9k");
+//! ```
+
+use ::rust_decimal::Decimal;
+
+use crate::decimal::Exact;
+use crate::{HumanNumber, HumanPercent};
+
+fn exact(value: Decimal) -> Exact {
+    Exact {
+        mantissa: value.mantissa(),
+        exponent: -(value.scale() as i32),
+    }
+}
+
+impl From<Decimal> for HumanNumber {
+    fn from(value: Decimal) -> Self {
+        HumanNumber::from_exact(exact(value))
+    }
+}
+
+/// A percentage with one decimal place: "12.5%".
+impl From<Decimal> for HumanPercent {
+    fn from(value: Decimal) -> Self {
+        HumanPercent::from_exact(exact(value), 1)
+    }
+}
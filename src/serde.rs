@@ -0,0 +1,1034 @@
+//! `Serialize`/`Deserialize` impls, enabled with the `serde` feature.
+//!
+//! Every type serializes as its underlying numeric value so it round-trips
+//! losslessly through machine formats:
+//!
+//! - [`HumanNumber`](crate::HumanNumber) and [`HumanPercent`](crate::HumanPercent) as `f64`
+//! - [`HumanSize`](crate::HumanSize) as `u64` bytes, or `i64` for a negative size; a
+//!   negative size past `i64::MIN` is written as text, "-18446744073709551615". Binary
+//!   formats get the signed byte count as an `i128`
+//! - [`HumanTime`](crate::HumanTime) as `f64` seconds, negative for negative durations
+//! - [`HumanDuration`](crate::HumanDuration) as optional `f64` seconds since the Unix epoch
+//! - [`HumanPermissions`](crate::HumanPermissions) as the `u32` mode
+//! - [`HumanOrdinal`](crate::HumanOrdinal) as `i64`
+//! - [`HumanBitrate`](crate::HumanBitrate) as optional `f64` bits per second
+//! - [`HumanFrequency`](crate::HumanFrequency) as `f64` hertz
+//! - [`HumanDate`](crate::HumanDate) as `f64` seconds since the Unix epoch
+//! - [`HumanAge`](crate::HumanAge) as the birth date, in `f64` seconds since the Unix epoch
+//! - [`HumanDistance`](crate::HumanDistance) as `f64` meters
+//! - [`HumanFraction`](crate::HumanFraction) as `f64`
+//! - [`HumanMass`](crate::HumanMass) as `f64` grams
+//! - [`HumanMoney`](crate::HumanMoney) as an `(i64, &str)` pair of minor units and currency code
+//! - [`HumanQuantity`](crate::HumanQuantity) as an `(f64, &str)` pair of value and unit symbol
+//! - [`HumanTemperature`](crate::HumanTemperature) as `f64` degrees Celsius
+//! - [`HumanCount`](crate::HumanCount) as an integer, or `f64` for a fractional count; an
+//!   integer past the `u64`/`i64` range is written as text, "-18446744073709551616"
+//! - [`HumanTimeOfDay`](crate::HumanTimeOfDay) as an `(hour, minute, second)` triple of `u32`
+//! - [`HumanCoordinate`](crate::HumanCoordinate) as an `(f64, f64)` pair of latitude and longitude
+//! - [`HumanPace`](crate::HumanPace) as an `(f64, f64)` pair of meters and elapsed seconds
+//! - [`HumanUptime`](crate::HumanUptime) as `f64` seconds
+//!
+//! In human-readable formats, `HumanNumber`, `HumanSize`, `HumanTime`,
+//! `HumanPercent`, `HumanPermissions` and `HumanCoordinate` also deserialize
+//! from their humanized text, such as "3.4M", "5 MiB", "1h 30m", "45%",
+//! "rwxr-xr-x" or "48°51′24″N 2°21′08″E".
+//!
+//! Display settings (unit system, decimals) are not part of the value and are
+//! reset to their defaults when deserializing; percentages come back with one
+//! decimal place.
+//!
+//! ## Field adapters
+//!
+//! The [`size`], [`duration_secs`], [`number`] and [`percent`] modules
+//! humanize plain fields with `#[serde(with = "...")]`:
+//!
+//! ```rust
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Settings {
+//!     #[serde(with = "humanly::serde::size")]
+//!     max_cache: u64,
+//! }
+//!
+//! let json = serde_json::to_string(&Settings { max_cache: 536_870_912 }).unwrap();
+//! assert_eq!(json, r#"{"max_cache":"512 MiB"}"#);
+//!
+//! let settings: Settings = serde_json::from_str(r#"{"max_cache":1024}"#).unwrap();
+//! assert_eq!(settings.max_cache, 1024);
+//! ```
+//!
+//! Human-readable formats (JSON, YAML, TOML…) get the concise string when it
+//! reads back as exactly the same value, and the raw number otherwise; either
+//! is accepted when deserializing. Binary formats always use the raw number.
+//! The text is written with the default [`Config`](crate::Config), so it
+//! parses the same whatever the display settings are.
+
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ::serde::de::{self, Deserialize, Deserializer, Visitor};
+use ::serde::ser::{Serialize, Serializer};
+
+use crate::config::Config;
+use crate::count::Count;
+use crate::parse::{parse_duration, parse_human_number, parse_percent, parse_size};
+use crate::{
+    Currency, HumanAge, HumanBitrate, HumanCoordinate, HumanCount, HumanDate, HumanDistance,
+    HumanDuration, HumanFraction, HumanFrequency, HumanMass, HumanMoney, HumanNumber, HumanOrdinal,
+    HumanPace, HumanPercent, HumanPermissions, HumanQuantity, HumanSize, HumanTemperature,
+    HumanTime, HumanTimeOfDay, HumanUptime,
+};
+
+const DESERIALIZED_PERCENT_DECIMALS: usize = 1;
+
+/* -------------------- Visitors -------------------- */
+
+struct F64Visitor;
+
+impl Visitor<'_> for F64Visitor {
+    type Value = f64;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a number")
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<f64, E> {
+        Ok(v)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<f64, E> {
+        Ok(v as f64)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<f64, E> {
+        Ok(v as f64)
+    }
+}
+
+struct U64Visitor;
+
+impl Visitor<'_> for U64Visitor {
+    type Value = u64;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a non-negative whole number")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<u64, E> {
+        Ok(v)
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<u64, E> {
+        u64::try_from(v).map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<u64, E> {
+        if v.fract() == 0.0 && v >= 0.0 && v <= u64::MAX as f64 {
+            Ok(v as u64)
+        } else {
+            Err(E::invalid_value(de::Unexpected::Float(v), &self))
+        }
+    }
+}
+
+fn seconds_to_duration<E: de::Error>(secs: f64) -> Result<Duration, E> {
+    Duration::try_from_secs_f64(secs)
+        .map_err(|_| E::invalid_value(de::Unexpected::Float(secs), &"a non-negative duration"))
+}
+
+// Seconds since the Unix epoch, negative before it.
+fn epoch_seconds(time: SystemTime) -> f64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(after) => after.as_secs_f64(),
+        Err(err) => -err.duration().as_secs_f64(),
+    }
+}
+
+// The inverse of `epoch_seconds`, failing rather than panicking on a time the
+// platform's `SystemTime` can't hold.
+fn from_epoch_seconds<E: de::Error>(secs: f64) -> Result<SystemTime, E> {
+    let time = if secs < 0.0 {
+        UNIX_EPOCH.checked_sub(seconds_to_duration::<E>(-secs)?)
+    } else {
+        UNIX_EPOCH.checked_add(seconds_to_duration::<E>(secs)?)
+    };
+    time.ok_or_else(|| {
+        E::invalid_value(
+            de::Unexpected::Float(secs),
+            &"a time within the range of SystemTime",
+        )
+    })
+}
+
+/* -------------------- HumanNumber -------------------- */
+
+impl Serialize for HumanNumber {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.number)
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanNumber {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(NumberVisitor)
+        } else {
+            deserializer.deserialize_f64(NumberVisitor)
+        }
+    }
+}
+
+// A number, or text such as "3.4M" from human-readable formats.
+struct NumberVisitor;
+
+impl Visitor<'_> for NumberVisitor {
+    type Value = HumanNumber;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a number or a humanized number such as \"3.4M\"")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<HumanNumber, E> {
+        parse_human_number(v)
+            .map(HumanNumber::from)
+            .map_err(de::Error::custom)
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<HumanNumber, E> {
+        Ok(HumanNumber::from(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<HumanNumber, E> {
+        Ok(HumanNumber::from(v as f64))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<HumanNumber, E> {
+        Ok(HumanNumber::from(v as f64))
+    }
+}
+
+/* -------------------- HumanSize -------------------- */
+
+impl Serialize for HumanSize {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Binary formats can't tell a u64 from an i64 when reading back, so
+        // the sign goes with the bytes
+        if !serializer.is_human_readable() {
+            return serializer.serialize_i128(self.signed_bytes());
+        }
+        if !self.negative || self.bytes == 0 {
+            return serializer.serialize_u64(self.bytes);
+        }
+        match i64::try_from(self.signed_bytes()) {
+            Ok(bytes) => serializer.serialize_i64(bytes),
+            // A difference of sizes can go past i64::MIN, which JSON readers
+            // would take back as a rounded float, so it is written as text
+            Err(_) => serializer.collect_str(&format_args!("-{}", self.bytes)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanSize {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(SizeVisitor)
+        } else {
+            deserializer.deserialize_i128(SizeVisitor)
+        }
+    }
+}
+
+// Like `U64Visitor`, but taking negative whole numbers as signed sizes and
+// text such as "5 MiB" or "-18446744073709551615" from human-readable formats.
+struct SizeVisitor;
+
+impl Visitor<'_> for SizeVisitor {
+    type Value = HumanSize;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a whole number of bytes or a size such as \"5 MiB\"")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<HumanSize, E> {
+        let (negative, unsigned) = match v.trim_start().strip_prefix(['-', '\u{2212}']) {
+            Some(unsigned) => (true, unsigned),
+            None => (false, v),
+        };
+        let bytes = i128::from(parse_size(unsigned).map_err(de::Error::custom)?);
+        Ok(HumanSize::from(0).with_signed_bytes(if negative { -bytes } else { bytes }))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<HumanSize, E> {
+        Ok(HumanSize::from(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<HumanSize, E> {
+        Ok(HumanSize::from_signed(v))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<HumanSize, E> {
+        if v.unsigned_abs() > u128::from(u64::MAX) {
+            return Err(E::invalid_value(de::Unexpected::Other("i128"), &self));
+        }
+        Ok(HumanSize::from(0).with_signed_bytes(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<HumanSize, E> {
+        if v < 0.0 && v.fract() == 0.0 && v >= i64::MIN as f64 {
+            Ok(HumanSize::from_signed(v as i64))
+        } else {
+            U64Visitor.visit_f64(v).map(HumanSize::from)
+        }
+    }
+}
+
+/* -------------------- HumanDuration -------------------- */
+
+impl Serialize for HumanDuration {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let secs = self
+            .timestamp()
+            .map(|st| match st.duration_since(UNIX_EPOCH) {
+                Ok(after) => after.as_secs_f64(),
+                Err(err) => -err.duration().as_secs_f64(),
+            });
+        secs.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanDuration {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let system_time = Option::<f64>::deserialize(deserializer)?
+            .map(from_epoch_seconds::<D::Error>)
+            .transpose()?;
+        Ok(HumanDuration::from(system_time))
+    }
+}
+
+/* -------------------- HumanTime -------------------- */
+
+impl Serialize for HumanTime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let secs = self.duration.as_secs_f64();
+        serializer.serialize_f64(if self.negative { -secs } else { secs })
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(TimeVisitor)
+        } else {
+            deserializer.deserialize_f64(TimeVisitor)
+        }
+    }
+}
+
+// Signed seconds, or text such as "1h 30m" from human-readable formats.
+struct TimeVisitor;
+
+impl Visitor<'_> for TimeVisitor {
+    type Value = HumanTime;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a number of seconds or a duration such as \"1h 30m\"")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<HumanTime, E> {
+        parse_duration(v)
+            .map(HumanTime::from)
+            .map_err(de::Error::custom)
+    }
+
+    fn visit_f64<E: de::Error>(self, secs: f64) -> Result<HumanTime, E> {
+        let duration = seconds_to_duration(secs.abs())?;
+        Ok(HumanTime::from_signed(duration, secs < 0.0))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<HumanTime, E> {
+        self.visit_f64(v as f64)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<HumanTime, E> {
+        self.visit_f64(v as f64)
+    }
+}
+
+/* -------------------- HumanPercent -------------------- */
+
+impl Serialize for HumanPercent {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.value)
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanPercent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(PercentVisitor)
+        } else {
+            deserializer.deserialize_f64(PercentVisitor)
+        }
+    }
+}
+
+// A percentage as a number, or text such as "45%" from human-readable
+// formats.
+struct PercentVisitor;
+
+impl Visitor<'_> for PercentVisitor {
+    type Value = HumanPercent;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a number or a percentage such as \"45%\"")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<HumanPercent, E> {
+        parse_percent(v)
+            .map_err(de::Error::custom)
+            .and_then(|value| self.visit_f64(value))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<HumanPercent, E> {
+        Ok(HumanPercent::from(v, DESERIALIZED_PERCENT_DECIMALS))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<HumanPercent, E> {
+        self.visit_f64(v as f64)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<HumanPercent, E> {
+        self.visit_f64(v as f64)
+    }
+}
+
+/* -------------------- HumanPermissions -------------------- */
+
+impl Serialize for HumanPermissions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u32(self.mode)
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanPermissions {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(PermissionsVisitor)
+        } else {
+            deserializer.deserialize_u32(PermissionsVisitor)
+        }
+    }
+}
+
+// The mode as a number, or symbolic text such as "rwxr-xr-x" from
+// human-readable formats.
+struct PermissionsVisitor;
+
+impl Visitor<'_> for PermissionsVisitor {
+    type Value = HumanPermissions;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a mode as a number or symbolic text such as \"rwxr-xr-x\"")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<HumanPermissions, E> {
+        v.parse().map_err(de::Error::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<HumanPermissions, E> {
+        u32::try_from(v)
+            .map(HumanPermissions::from)
+            .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<HumanPermissions, E> {
+        u32::try_from(v)
+            .map(HumanPermissions::from)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+}
+
+/* -------------------- HumanOrdinal -------------------- */
+
+impl Serialize for HumanOrdinal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.number)
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanOrdinal {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        i64::deserialize(deserializer).map(HumanOrdinal::from)
+    }
+}
+
+/* -------------------- HumanBitrate -------------------- */
+
+impl Serialize for HumanBitrate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.bits_per_sec.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanBitrate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bits_per_sec = Option::<f64>::deserialize(deserializer)?;
+        let mut bitrate = HumanBitrate::from_bits_per_sec(0);
+        bitrate.bits_per_sec = bits_per_sec;
+        Ok(bitrate)
+    }
+}
+
+/* -------------------- HumanFrequency -------------------- */
+
+impl Serialize for HumanFrequency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.hz)
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanFrequency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_f64(F64Visitor)
+            .map(HumanFrequency::from_hz)
+    }
+}
+
+/* -------------------- HumanDate -------------------- */
+
+impl Serialize for HumanDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(epoch_seconds(self.system_time))
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let secs = deserializer.deserialize_f64(F64Visitor)?;
+        Ok(HumanDate::from(from_epoch_seconds::<D::Error>(secs)?))
+    }
+}
+
+/* -------------------- HumanAge -------------------- */
+
+impl Serialize for HumanAge {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(epoch_seconds(self.birth))
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanAge {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let secs = deserializer.deserialize_f64(F64Visitor)?;
+        Ok(HumanAge::from(from_epoch_seconds::<D::Error>(secs)?))
+    }
+}
+
+/* -------------------- HumanDistance -------------------- */
+
+impl Serialize for HumanDistance {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.meters)
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanDistance {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_f64(F64Visitor)
+            .map(HumanDistance::from_meters)
+    }
+}
+
+/* -------------------- HumanFraction -------------------- */
+
+impl Serialize for HumanFraction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.value)
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanFraction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_f64(F64Visitor)
+            .map(HumanFraction::from)
+    }
+}
+
+/* -------------------- HumanMass -------------------- */
+
+impl Serialize for HumanMass {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.grams)
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanMass {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_f64(F64Visitor)
+            .map(HumanMass::from_grams)
+    }
+}
+
+/* -------------------- HumanMoney -------------------- */
+
+impl Serialize for HumanMoney {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.minor_units, self.currency.code()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanMoney {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (minor_units, code) = <(i64, String)>::deserialize(deserializer)?;
+        let currency = [Currency::USD, Currency::EUR, Currency::GBP, Currency::JPY]
+            .into_iter()
+            .find(|currency| currency.code() == code)
+            .ok_or_else(|| {
+                de::Error::invalid_value(de::Unexpected::Str(&code), &"USD, EUR, GBP or JPY")
+            })?;
+        Ok(HumanMoney::new(minor_units, currency))
+    }
+}
+
+/* -------------------- HumanQuantity -------------------- */
+
+impl Serialize for HumanQuantity {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.value, self.unit.as_ref()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanQuantity {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (value, unit) = <(f64, String)>::deserialize(deserializer)?;
+        Ok(HumanQuantity::new(value, unit))
+    }
+}
+
+/* -------------------- HumanTemperature -------------------- */
+
+impl Serialize for HumanTemperature {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.celsius)
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanTemperature {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_f64(F64Visitor)
+            .map(HumanTemperature::from_celsius)
+    }
+}
+
+/* -------------------- HumanCount -------------------- */
+
+// Binary formats can't tell an integer from a float when reading back, so they
+// get the count as one of these variants.
+const COUNT_VARIANTS: &[&str] = &["Integer", "Float"];
+
+impl Serialize for HumanCount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.count {
+            Count::Integer {
+                negative,
+                magnitude,
+            } if serializer.is_human_readable() => {
+                if !negative && let Ok(count) = u64::try_from(magnitude) {
+                    return serializer.serialize_u64(count);
+                }
+                if let Some(count) = i128::try_from(magnitude)
+                    .ok()
+                    .and_then(|magnitude| i64::try_from(-magnitude).ok())
+                {
+                    return serializer.serialize_i64(count);
+                }
+                // JSON readers would take a wider integer back as a rounded
+                // float, so it is written as text
+                let sign = if negative { "-" } else { "" };
+                serializer.collect_str(&format_args!("{}{}", sign, magnitude))
+            }
+            Count::Integer {
+                negative,
+                magnitude,
+            } => serializer.serialize_newtype_variant(
+                "HumanCount",
+                0,
+                COUNT_VARIANTS[0],
+                &(negative, magnitude),
+            ),
+            Count::Float(count) if serializer.is_human_readable() => {
+                serializer.serialize_f64(count)
+            }
+            Count::Float(count) => {
+                serializer.serialize_newtype_variant("HumanCount", 1, COUNT_VARIANTS[1], &count)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanCount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(CountVisitor)
+        } else {
+            deserializer.deserialize_enum("HumanCount", COUNT_VARIANTS, CountVisitor)
+        }
+    }
+}
+
+// A whole or fractional number, text such as "1,234,567" from human-readable
+// formats, or a `COUNT_VARIANTS` variant from binary ones.
+struct CountVisitor;
+
+impl<'de> Visitor<'de> for CountVisitor {
+    type Value = HumanCount;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a number or a count such as \"1,234,567\"")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<HumanCount, E> {
+        let digits: String = v.trim().chars().filter(|&c| c != ',').collect();
+        let (negative, magnitude) = match digits.strip_prefix('-') {
+            Some(magnitude) => (true, magnitude),
+            None => (false, digits.as_str()),
+        };
+        if let Ok(magnitude) = magnitude.parse::<u128>() {
+            return Ok(HumanCount::with(Count::Integer {
+                negative: negative && magnitude > 0,
+                magnitude,
+            }));
+        }
+        match digits.parse::<f64>() {
+            Ok(count) if count.is_finite() => Ok(HumanCount::from(count)),
+            _ => Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+        }
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<HumanCount, E> {
+        Ok(HumanCount::from(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<HumanCount, E> {
+        Ok(HumanCount::from(v))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<HumanCount, E> {
+        Ok(HumanCount::from(v))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<HumanCount, E> {
+        Ok(HumanCount::from(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<HumanCount, E> {
+        Ok(HumanCount::from(v))
+    }
+
+    fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> Result<HumanCount, A::Error> {
+        use ::serde::de::VariantAccess;
+
+        let (kind, variant) = data.variant::<CountKind>()?;
+        match kind {
+            CountKind::Integer => {
+                let (negative, magnitude) = variant.newtype_variant()?;
+                Ok(HumanCount::with(Count::Integer {
+                    negative,
+                    magnitude,
+                }))
+            }
+            CountKind::Float => variant.newtype_variant::<f64>().map(HumanCount::from),
+        }
+    }
+}
+
+enum CountKind {
+    Integer,
+    Float,
+}
+
+impl<'de> Deserialize<'de> for CountKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_identifier(CountKindVisitor)
+    }
+}
+
+// A `COUNT_VARIANTS` variant, by index or by name.
+struct CountKindVisitor;
+
+impl Visitor<'_> for CountKindVisitor {
+    type Value = CountKind;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a HumanCount variant")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<CountKind, E> {
+        match v {
+            0 => Ok(CountKind::Integer),
+            1 => Ok(CountKind::Float),
+            _ => Err(E::invalid_value(de::Unexpected::Unsigned(v), &self)),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<CountKind, E> {
+        match v {
+            "Integer" => Ok(CountKind::Integer),
+            "Float" => Ok(CountKind::Float),
+            _ => Err(E::unknown_variant(v, COUNT_VARIANTS)),
+        }
+    }
+}
+
+/* -------------------- HumanTimeOfDay -------------------- */
+
+impl Serialize for HumanTimeOfDay {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.hour, self.minute, self.second).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanTimeOfDay {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (hour, minute, second) = <(u32, u32, u32)>::deserialize(deserializer)?;
+        Ok(HumanTimeOfDay::from_hms(hour, minute, second))
+    }
+}
+
+/* -------------------- HumanCoordinate -------------------- */
+
+impl Serialize for HumanCoordinate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.latitude(), self.longitude()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanCoordinate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(CoordinateVisitor)
+        } else {
+            deserializer.deserialize_tuple(2, CoordinateVisitor)
+        }
+    }
+}
+
+// A `(latitude, longitude)` pair, or text such as "48°51′24″N 2°21′08″E".
+struct CoordinateVisitor;
+
+impl<'de> Visitor<'de> for CoordinateVisitor {
+    type Value = HumanCoordinate;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a latitude and longitude pair or a coordinate such as \"48.8567, 2.3522\"")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<HumanCoordinate, E> {
+        v.parse().map_err(de::Error::custom)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<HumanCoordinate, A::Error> {
+        let latitude: f64 = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let longitude: f64 = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        HumanCoordinate::from_lat_lon(latitude, longitude).map_err(de::Error::custom)
+    }
+}
+
+/* -------------------- HumanPace -------------------- */
+
+impl Serialize for HumanPace {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.meters, self.elapsed.as_secs_f64()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanPace {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (meters, secs) = <(f64, f64)>::deserialize(deserializer)?;
+        Ok(HumanPace::new(meters, seconds_to_duration(secs)?))
+    }
+}
+
+/* -------------------- HumanUptime -------------------- */
+
+impl Serialize for HumanUptime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.duration.as_secs_f64())
+    }
+}
+
+impl<'de> Deserialize<'de> for HumanUptime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let secs = deserializer.deserialize_f64(F64Visitor)?;
+        Ok(HumanUptime::from(seconds_to_duration(secs)?))
+    }
+}
+
+/* -------------------- Field adapters -------------------- */
+
+// A field written by one of the adapter modules: humanized text or the raw number.
+enum Field {
+    Text(String),
+    Unsigned(u64),
+    Signed(i64),
+    Float(f64),
+}
+
+impl Field {
+    fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(FieldVisitor)
+    }
+
+    // Hands a raw number on to `visitor`.
+    fn visit_number<'de, V: Visitor<'de>, E: de::Error>(self, visitor: V) -> Result<V::Value, E> {
+        match self {
+            Field::Text(text) => Err(E::invalid_type(de::Unexpected::Str(&text), &visitor)),
+            Field::Unsigned(v) => visitor.visit_u64(v),
+            Field::Signed(v) => visitor.visit_i64(v),
+            Field::Float(v) => visitor.visit_f64(v),
+        }
+    }
+}
+
+struct FieldVisitor;
+
+impl Visitor<'_> for FieldVisitor {
+    type Value = Field;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a humanized string or a number")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Field, E> {
+        Ok(Field::Text(v.to_string()))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Field, E> {
+        Ok(Field::Unsigned(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Field, E> {
+        Ok(Field::Signed(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Field, E> {
+        Ok(Field::Float(v))
+    }
+}
+
+// Formats with the default display settings, so the text parses back.
+fn humanize(format: impl FnOnce() -> String) -> String {
+    Config::with(Config::default(), |_| format())
+}
+
+/// `u64` byte counts as "512 MiB".
+pub mod size {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(bytes: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let text = humanize(|| HumanSize::from(*bytes).concise());
+            if parse_size(&text).ok() == Some(*bytes) {
+                return serializer.serialize_str(&text);
+            }
+        }
+        serializer.serialize_u64(*bytes)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        if !deserializer.is_human_readable() {
+            return deserializer.deserialize_u64(U64Visitor);
+        }
+        match Field::deserialize(deserializer)? {
+            Field::Text(text) => parse_size(&text).map_err(de::Error::custom),
+            number => number.visit_number(U64Visitor),
+        }
+    }
+}
+
+/// [`Duration`]s as "1h 30m"; raw numbers are seconds.
+pub mod duration_secs {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let text = humanize(|| HumanTime::from(*duration).concise());
+            if parse_duration(&text).ok() == Some(*duration) {
+                return serializer.serialize_str(&text);
+            }
+        }
+        serializer.serialize_f64(duration.as_secs_f64())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        if !deserializer.is_human_readable() {
+            return seconds_to_duration(deserializer.deserialize_f64(F64Visitor)?);
+        }
+        match Field::deserialize(deserializer)? {
+            Field::Text(text) => parse_duration(&text).map_err(de::Error::custom),
+            number => seconds_to_duration(number.visit_number(F64Visitor)?),
+        }
+    }
+}
+
+/// `f64` numbers as "1.8M".
+pub mod number {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(number: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let text = humanize(|| HumanNumber::from(*number).concise());
+            if parse_human_number(&text).ok() == Some(*number) {
+                return serializer.serialize_str(&text);
+            }
+        }
+        serializer.serialize_f64(*number)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        if !deserializer.is_human_readable() {
+            return deserializer.deserialize_f64(F64Visitor);
+        }
+        match Field::deserialize(deserializer)? {
+            Field::Text(text) => parse_human_number(&text).map_err(de::Error::custom),
+            number => number.visit_number(F64Visitor),
+        }
+    }
+}
+
+/// `f64` percentages as "12.5%"; the value is in percent, as for
+/// [`HumanPercent`].
+pub mod percent {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let text =
+                humanize(|| HumanPercent::from(*value, DESERIALIZED_PERCENT_DECIMALS).concise());
+            if parse_percent(&text).ok() == Some(*value) {
+                return serializer.serialize_str(&text);
+            }
+        }
+        serializer.serialize_f64(*value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        if !deserializer.is_human_readable() {
+            return deserializer.deserialize_f64(F64Visitor);
+        }
+        match Field::deserialize(deserializer)? {
+            Field::Text(text) => parse_percent(&text).map_err(de::Error::custom),
+            number => number.visit_number(F64Visitor),
+        }
+    }
+}
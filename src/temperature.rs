@@ -0,0 +1,133 @@
+use std::fmt::{self, Write};
+
+use crate::config::decimal_mark;
+use crate::core::{HumanFormat, Style, human_display, typeset, write_trimmed};
+
+/* -------------------- HumanTemperature -------------------- */
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Scale {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+}
+
+/// Temperatures: "23°C" concise, "23 degrees Celsius" full.
+///
+/// The value is kept in Celsius and converted to the scale picked with
+/// [`celsius`](HumanTemperature::celsius),
+/// [`fahrenheit`](HumanTemperature::fahrenheit) or
+/// [`kelvin`](HumanTemperature::kelvin) when formatted; by default it is shown
+/// in the scale it was created from. Temperatures below absolute zero and
+/// non-finite values render as "-".
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HumanTemperature {
+    pub(crate) celsius: f64,
+    scale: Scale,
+    precision: usize,
+    ascii: bool,
+}
+
+human_display!(HumanTemperature);
+
+impl HumanTemperature {
+    fn new(celsius: f64, scale: Scale) -> Self {
+        Self {
+            celsius,
+            scale,
+            precision: 1,
+            ascii: false,
+        }
+    }
+
+    pub fn from_celsius(celsius: f64) -> Self {
+        Self::new(celsius, Scale::Celsius)
+    }
+
+    pub fn from_fahrenheit(fahrenheit: f64) -> Self {
+        Self::new((fahrenheit - 32.0) * 5.0 / 9.0, Scale::Fahrenheit)
+    }
+
+    pub fn from_kelvin(kelvin: f64) -> Self {
+        Self::new(kelvin - 273.15, Scale::Kelvin)
+    }
+
+    /// Show the temperature in degrees Celsius.
+    pub fn celsius(mut self) -> Self {
+        self.scale = Scale::Celsius;
+        self
+    }
+
+    /// Show the temperature in degrees Fahrenheit.
+    pub fn fahrenheit(mut self) -> Self {
+        self.scale = Scale::Fahrenheit;
+        self
+    }
+
+    /// Show the temperature in kelvins.
+    pub fn kelvin(mut self) -> Self {
+        self.scale = Scale::Kelvin;
+        self
+    }
+
+    /// Maximum number of decimal places (default 1); trailing zeros are trimmed.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision.min(300);
+        self
+    }
+
+    /// Write "23 C" instead of "23°C" in concise output.
+    pub fn ascii(mut self) -> Self {
+        self.ascii = true;
+        self
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        if !self.celsius.is_finite() || self.celsius < -273.15 {
+            return w.write_str("-");
+        }
+
+        let value = match self.scale {
+            Scale::Celsius => self.celsius,
+            Scale::Fahrenheit => self.celsius * 9.0 / 5.0 + 32.0,
+            Scale::Kelvin => self.celsius + 273.15,
+        };
+        let multiplier = 10_f64.powi(self.precision as i32);
+        // Adding zero turns a rounded -0 into 0, so -0.04 isn't "-0°C"; a value
+        // too large to scale has no fraction left to round.
+        let rounded = match value * multiplier {
+            scaled if scaled.is_finite() => scaled.round() / multiplier + 0.0,
+            _ => value,
+        };
+        write_trimmed(w, rounded, self.precision, decimal_mark(None, style))?;
+
+        let degrees = if rounded.abs() == 1.0 {
+            "degree"
+        } else {
+            "degrees"
+        };
+        match (style, self.scale) {
+            (Style::Concise, Scale::Kelvin) => w.write_str(" K"),
+            (Style::Concise, Scale::Celsius) if self.ascii => w.write_str(" C"),
+            (Style::Concise, Scale::Fahrenheit) if self.ascii => w.write_str(" F"),
+            (Style::Concise, Scale::Celsius) => w.write_str("°C"),
+            (Style::Concise, Scale::Fahrenheit) => w.write_str("°F"),
+            (Style::Full, Scale::Celsius) => write!(w, " {} Celsius", degrees),
+            (Style::Full, Scale::Fahrenheit) => write!(w, " {} Fahrenheit", degrees),
+            (Style::Full, Scale::Kelvin) if rounded == 1.0 => w.write_str(" kelvin"),
+            (Style::Full, Scale::Kelvin) => w.write_str(" kelvins"),
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use std::fmt::{self, Write};
+
+use crate::config::{Config, decimal_mark};
+use crate::core::{Style, UnitSystem, collect, number_scale, write_localized};
+use crate::{HumanNumber, HumanSize};
+
+/* -------------------- Ticks -------------------- */
+
+/// A labelled position on a chart axis, from [`HumanNumber::ticks`] or
+/// [`HumanSize::ticks`]. `Display` writes the label.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Tick<T> {
+    pub value: T,
+    pub label: String,
+}
+
+impl<T> fmt::Display for Tick<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(&self.label)
+    }
+}
+
+// Round steps: 1, 2, 2.5 and 5 times a power of ten.
+const MULTIPLES: [f64; 4] = [1.0, 2.0, 2.5, 5.0];
+
+// Allowance for float error when counting multiples of a step.
+const EPSILON: f64 = 1e-9;
+
+// `multiple × 10^exponent`, dividing for negative exponents so 0.1 and 0.3
+// come out as close as an f64 gets.
+fn power_of_ten(multiple: f64, exponent: i32) -> f64 {
+    if exponent >= 0 {
+        multiple * 10_f64.powi(exponent)
+    } else {
+        multiple / 10_f64.powi(-exponent)
+    }
+}
+
+impl HumanNumber {
+    /// Up to `count` evenly spaced ticks between `min` and `max` on round
+    /// steps (1, 2, 2.5 or 5 × 10ⁿ), labelled like [`concise`](Self::concise):
+    /// "0", "1M", "2M", "3M".
+    ///
+    /// Every label uses the unit of the largest tick and as many decimals as
+    /// the step needs, so an axis reads "0.5M", "1.0M", "1.5M" rather than
+    /// mixing "500k" and "1M". Zero is always "0". Non-finite bounds or a
+    /// `count` of zero give no ticks.
+    ///
+    /// ```rust
+    /// use humanly::HumanNumber;
+    ///
+    /// let labels: Vec<String> = HumanNumber::ticks(0.0, 3_400_000.0, 4)
+    ///     .into_iter()
+    ///     .map(|tick| tick.label)
+    ///     .collect();
+    /// assert_eq!(labels, ["0", "1M", "2M", "3M"]);
+    /// ```
+    pub fn ticks(min: f64, max: f64, count: usize) -> Vec<Tick<f64>> {
+        if !(min.is_finite() && max.is_finite()) || count == 0 {
+            return Vec::new();
+        }
+        let (min, max) = (min.min(max), min.max(max));
+        if min == max {
+            let label = HumanNumber::from(min).concise();
+            return vec![Tick { value: min, label }];
+        }
+
+        // The smallest round step that fits in `count` ticks. Bounds near
+        // ±f64::MAX overflow `max - min`, so their span is measured in halves.
+        let span_log10 = match max - min {
+            span if span.is_finite() => span.log10(),
+            _ => (max / 2.0 - min / 2.0).log10() + 2_f64.log10(),
+        };
+        let mut exponent = (span_log10 - (count as f64).log10()).floor() as i32 - 1;
+        let (multiple, first, last) = 'search: loop {
+            for multiple in MULTIPLES {
+                let step = power_of_ten(multiple, exponent);
+                let first = (min / step - EPSILON).ceil() as i64;
+                let last = (max / step + EPSILON).floor() as i64;
+                if i128::from(last - first) < count as i128 {
+                    break 'search (multiple, first, last);
+                }
+            }
+            exponent += 1;
+        };
+        let values: Vec<f64> = (first..=last)
+            .map(|i| power_of_ten(i as f64 * multiple, exponent) + 0.0)
+            .collect();
+
+        let largest = values.iter().fold(0_f64, |largest, v| largest.max(v.abs()));
+        let (divisor, suffix, magnitude) = number_scale(largest);
+        let in_unit = exponent - 3 * magnitude as i32;
+        let decimals = (-in_unit + i32::from(multiple == 2.5)).max(0) as usize;
+
+        let mark = decimal_mark(None, Style::Concise);
+        let suffix = Config::current().suffix_case.apply(suffix);
+        values
+            .into_iter()
+            .map(|value| {
+                let label = collect(|out| {
+                    if value == 0.0 {
+                        return out.write_char('0');
+                    }
+                    let scaled = value / divisor;
+                    write_localized(out, mark, format_args!("{:.*}", decimals, scaled))?;
+                    out.write_str(suffix)
+                });
+                Tick { value, label }
+            })
+            .collect()
+    }
+
+    /// Ticks at the powers of ten between `min` and `max`, for logarithmic
+    /// axes: "1", "10", "100", "1k". Empty unless both bounds are finite and
+    /// above zero.
+    pub fn log_ticks(min: f64, max: f64) -> Vec<Tick<f64>> {
+        if !(min > 0.0 && max > 0.0 && min.is_finite() && max.is_finite()) {
+            return Vec::new();
+        }
+        let (min, max) = (min.min(max), min.max(max));
+
+        let mark = decimal_mark(None, Style::Concise);
+        let first = (min.log10() - EPSILON).ceil() as i32;
+        let last = (max.log10() + EPSILON).floor() as i32;
+        (first..=last)
+            .map(|exponent| {
+                let value = power_of_ten(1.0, exponent);
+                let label = if exponent < 0 {
+                    collect(|out| write_localized(out, mark, format_args!("{}", value)))
+                } else {
+                    HumanNumber::from(value).concise()
+                };
+                Tick { value, label }
+            })
+            .collect()
+    }
+}
+
+impl HumanSize {
+    /// Up to `count` evenly spaced ticks between `min` and `max` bytes:
+    /// "0 B", "256 MiB", "512 MiB", "768 MiB", "1024 MiB".
+    ///
+    /// Binary steps are powers of two and decimal steps 1, 2, 2.5 or 5 × 10ⁿ
+    /// bytes. Every label is in the unit of the step, so the whole axis has
+    /// the same precision. A `count` of zero gives no ticks.
+    ///
+    /// ```rust
+    /// use humanly::{HumanSize, UnitSystem};
+    ///
+    /// let labels: Vec<String> = HumanSize::ticks(0, 10_000_000, 5, UnitSystem::Decimal)
+    ///     .into_iter()
+    ///     .map(|tick| tick.label)
+    ///     .collect();
+    /// assert_eq!(labels, ["0 B", "2.5 MB", "5.0 MB", "7.5 MB", "10.0 MB"]);
+    /// ```
+    pub fn ticks(min: u64, max: u64, count: usize, system: UnitSystem) -> Vec<Tick<u64>> {
+        let (min, max) = (min.min(max), min.max(max));
+        if count == 0 {
+            return Vec::new();
+        }
+
+        // Whole-byte steps, smallest first, with the decimals they need in
+        // their own unit
+        let steps: Vec<(u64, usize)> = match system {
+            UnitSystem::Binary | UnitSystem::Jedec => {
+                (0..64).map(|shift| (1 << shift, 0)).collect()
+            }
+            UnitSystem::Decimal => (0..20)
+                .flat_map(|exponent| {
+                    let power = 10_u64.pow(exponent);
+                    // 2.5 × 10ⁿ needs a decimal when 10ⁿ is a whole unit
+                    let two_and_a_half = (exponent > 0)
+                        .then(|| (power / 2).checked_mul(5))
+                        .flatten()
+                        .map(|step| (step, usize::from(exponent % 3 == 0)));
+                    [
+                        Some((power, 0)),
+                        power.checked_mul(2).map(|step| (step, 0)),
+                        two_and_a_half,
+                        power.checked_mul(5).map(|step| (step, 0)),
+                    ]
+                })
+                .flatten()
+                .collect(),
+        };
+        let ticks_with =
+            |step: u64| (u128::from(max / step) + 1).saturating_sub(u128::from(min.div_ceil(step)));
+        let (step, decimals) = steps
+            .iter()
+            .copied()
+            .find(|&(step, _)| ticks_with(step) <= count as u128)
+            .unwrap_or(steps[steps.len() - 1]);
+
+        let (units_short, _, _) = system.units();
+        let magnitude = system.index(step);
+        let mark = decimal_mark(None, Style::Concise);
+        (min.div_ceil(step)..=max / step)
+            .take(count)
+            .map(|i| {
+                let value = i * step;
+                let label = collect(|out| match magnitude {
+                    _ if value == 0 => out.write_str("0 B"),
+                    0 => write!(out, "{} B", value),
+                    _ => {
+                        let scaled = system.in_unit(value, 0.0, magnitude).unwrap_or(f64::NAN);
+                        write_localized(out, mark, format_args!("{:.*}", decimals, scaled))?;
+                        write!(out, " {}", units_short[magnitude - 1])
+                    }
+                });
+                Tick { value, label }
+            })
+            .collect()
+    }
+
+    /// Ticks at the powers of 1024 (or 1000 for decimal units) between `min`
+    /// and `max` bytes, for logarithmic axes: "1 B", "1 KiB", "1 MiB".
+    pub fn log_ticks(min: u64, max: u64, system: UnitSystem) -> Vec<Tick<u64>> {
+        let (min, max) = (min.min(max), min.max(max));
+        let (units_short, _, _) = system.units();
+        (0..=units_short.len())
+            .map_while(|magnitude| Some((magnitude, system.power(magnitude)?)))
+            .filter(|&(_, value)| (min..=max).contains(&value))
+            .map(|(magnitude, value)| {
+                let label = match magnitude {
+                    0 => "1 B".to_string(),
+                    _ => format!("1 {}", units_short[magnitude - 1]),
+                };
+                Tick { value, label }
+            })
+            .collect()
+    }
+}
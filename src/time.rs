@@ -0,0 +1,44 @@
+//! Conversions from [`time`](https://docs.rs/time) types, enabled with the
+//! `time` feature.
+//!
+//! The types' own `from` constructors take std values, so convert with `into`:
+//!
+//! ```rust
+//! use humanly::HumanTime;
+//!
+//! let time: HumanTime = time::Duration::minutes(-90).into();
+//! assert_eq!(time.concise(), "-1h 30m");
+//! ```
+
+use std::time::{Duration, SystemTime};
+
+use ::time::{Date, OffsetDateTime};
+
+use crate::{HumanDate, HumanDuration, HumanTime};
+
+impl From<OffsetDateTime> for HumanDuration {
+    fn from(date_time: OffsetDateTime) -> Self {
+        HumanDuration::from(Some(SystemTime::from(date_time)))
+    }
+}
+
+/// Negative durations keep their sign: "-1h 30m".
+impl From<::time::Duration> for HumanTime {
+    fn from(duration: ::time::Duration) -> Self {
+        let std = Duration::try_from(duration.abs()).unwrap_or(Duration::MAX);
+        HumanTime::from_signed(std, duration.is_negative())
+    }
+}
+
+impl From<OffsetDateTime> for HumanDate {
+    fn from(date_time: OffsetDateTime) -> Self {
+        HumanDate::from(SystemTime::from(date_time))
+    }
+}
+
+/// The date at midnight UTC.
+impl From<Date> for HumanDate {
+    fn from(date: Date) -> Self {
+        HumanDate::from(SystemTime::from(date.midnight().assume_utc()))
+    }
+}
@@ -0,0 +1,151 @@
+use std::fmt::{self, Write};
+use std::time::SystemTime;
+
+use crate::core::{HumanFormat, Style, human_display, typeset};
+use crate::date::CivilDateTime;
+use crate::words::spell_cardinal;
+
+/* -------------------- HumanTimeOfDay -------------------- */
+
+/// Clock times: "3:15 pm" concise, "quarter past three" full.
+///
+/// Full output rounds to the nearest five minutes unless
+/// [`exact`](HumanTimeOfDay::exact) is set, switches from "past" to "to" after
+/// half past, and says "midnight" and "noon" for those hours. Concise output
+/// is the clock time to the minute, on a 12-hour clock unless
+/// [`twenty_four_hour`](HumanTimeOfDay::twenty_four_hour) is set. Out-of-range
+/// hours, minutes or seconds render as "-".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HumanTimeOfDay {
+    pub(crate) hour: u32,
+    pub(crate) minute: u32,
+    pub(crate) second: u32,
+    exact: bool,
+    twenty_four_hour: bool,
+    day_period: bool,
+}
+
+human_display!(HumanTimeOfDay);
+
+impl HumanTimeOfDay {
+    pub fn from_hms(hour: u32, minute: u32, second: u32) -> Self {
+        Self {
+            hour,
+            minute,
+            second,
+            exact: false,
+            twenty_four_hour: false,
+            day_period: false,
+        }
+    }
+
+    /// The wall-clock time of `time` at `offset_minutes` east of UTC.
+    pub fn from_system_time(time: SystemTime, offset_minutes: i32) -> Self {
+        let civil = CivilDateTime::from_system_time(time, offset_minutes);
+        Self::from_hms(civil.hour, civil.minute, civil.second)
+    }
+
+    /// Say the minute instead of rounding to five: "seventeen minutes past
+    /// three".
+    pub fn exact(mut self) -> Self {
+        self.exact = true;
+        self
+    }
+
+    /// "15:15" instead of "3:15 pm" in concise output.
+    pub fn twenty_four_hour(mut self) -> Self {
+        self.twenty_four_hour = true;
+        self
+    }
+
+    /// Add the part of the day to full output: "half past nine in the
+    /// evening".
+    pub fn day_period(mut self) -> Self {
+        self.day_period = true;
+        self
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        if self.hour > 23 || self.minute > 59 || self.second > 59 {
+            return w.write_str("-");
+        }
+
+        match style {
+            Style::Concise if self.twenty_four_hour => {
+                write!(w, "{:02}:{:02}", self.hour, self.minute)
+            }
+            Style::Concise => {
+                let hour = match self.hour % 12 {
+                    0 => 12,
+                    hour => hour,
+                };
+                let period = if self.hour < 12 { "am" } else { "pm" };
+                write!(w, "{}:{:02} {}", hour, self.minute, period)
+            }
+            Style::Full => self.write_words(w),
+        }
+    }
+
+    fn write_words(&self, w: &mut impl Write) -> fmt::Result {
+        // Minutes since midnight, rounded to the nearest 5 (or 1 when exact)
+        let step = if self.exact { 1 } else { 5 };
+        let seconds = self.hour * 3_600 + self.minute * 60 + self.second;
+        let minutes = (seconds + step * 30) / (step * 60) * step % (24 * 60);
+        let (hour, minute) = (minutes / 60, minutes % 60);
+
+        match minute {
+            0 => {}
+            15 => w.write_str("quarter past ")?,
+            30 => w.write_str("half past ")?,
+            45 => w.write_str("quarter to ")?,
+            m if m < 30 => write_minutes(w, m, "past")?,
+            m => write_minutes(w, 60 - m, "to")?,
+        }
+
+        // The hour the minutes are counted from or to
+        let named = if minute > 30 { (hour + 1) % 24 } else { hour };
+        match named {
+            0 => w.write_str("midnight")?,
+            12 => w.write_str("noon")?,
+            h => {
+                w.write_str(&spell_cardinal(u64::from(h % 12)))?;
+                if minute == 0 {
+                    w.write_str(" o'clock")?;
+                }
+            }
+        }
+
+        if self.day_period && named % 12 != 0 {
+            w.write_str(match hour {
+                5..=11 => " in the morning",
+                12..=16 => " in the afternoon",
+                17..=21 => " in the evening",
+                _ => " at night",
+            })?;
+        }
+        Ok(())
+    }
+}
+
+// "five past", "twenty-five to", "seventeen minutes past", "one minute to"
+fn write_minutes(w: &mut impl Write, minutes: u32, direction: &str) -> fmt::Result {
+    w.write_str(&spell_cardinal(u64::from(minutes)))?;
+    match minutes {
+        1 => w.write_str(" minute")?,
+        m if m % 5 != 0 => w.write_str(" minutes")?,
+        _ => {}
+    }
+    write!(w, " {} ", direction)
+}
@@ -0,0 +1,94 @@
+use std::fmt::{self, Write};
+use std::time::{Duration, SystemTime};
+
+use crate::HumanList;
+use crate::core::{HumanFormat, Style, human_display, typeset};
+
+/* -------------------- HumanUptime -------------------- */
+
+/// System uptime as `uptime(1)` prints it: "up 3 days, 4:05" concise, "up 3
+/// days, 4 hours and 5 minutes" full.
+///
+/// Like procps, the concise form gives days, then hours and minutes as H:MM,
+/// or only minutes under an hour ("up 1 day, 5 min", "up 27 min"). Under a
+/// minute it reads "up 42 sec", where procps would print "up 0 min".
+///
+/// ```rust
+/// use humanly::HumanUptime;
+/// use std::time::Duration;
+///
+/// let uptime = HumanUptime::from(Duration::from_secs(3 * 86_400 + 4 * 3_600 + 5 * 60));
+/// assert_eq!(uptime.concise(), "up 3 days, 4:05");
+/// assert_eq!(uptime.full(), "up 3 days, 4 hours and 5 minutes");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HumanUptime {
+    pub(crate) duration: Duration,
+}
+
+human_display!(HumanUptime);
+
+impl HumanUptime {
+    pub fn from(duration: Duration) -> Self {
+        Self { duration }
+    }
+
+    /// Uptime since a boot time; one in the future counts as just booted.
+    pub fn from_boot_time(boot_time: SystemTime) -> Self {
+        let duration = SystemTime::now()
+            .duration_since(boot_time)
+            .unwrap_or_default();
+        Self::from(duration)
+    }
+
+    pub fn concise(&self) -> String {
+        HumanFormat::concise(self)
+    }
+
+    pub fn full(&self) -> String {
+        HumanFormat::full(self)
+    }
+
+    pub fn write_into(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        typeset(w, |w| self.write_plain(w, style))
+    }
+
+    fn write_plain(&self, w: &mut impl Write, style: Style) -> fmt::Result {
+        let secs = self.duration.as_secs();
+        let days = secs / 86_400;
+        let hours = secs % 86_400 / 3_600;
+        let minutes = secs % 3_600 / 60;
+
+        w.write_str("up ")?;
+        match style {
+            Style::Concise => {
+                if secs < 60 {
+                    return write!(w, "{} sec", secs);
+                }
+                if days > 0 {
+                    write!(w, "{} day{}, ", days, if days == 1 { "" } else { "s" })?;
+                }
+                if hours > 0 {
+                    write!(w, "{}:{:02}", hours, minutes)
+                } else {
+                    write!(w, "{} min", minutes)
+                }
+            }
+            Style::Full => {
+                if secs < 60 {
+                    return write!(w, "{} second{}", secs, if secs == 1 { "" } else { "s" });
+                }
+                let parts: Vec<String> = [(days, "day"), (hours, "hour"), (minutes, "minute")]
+                    .into_iter()
+                    .filter(|&(count, _)| count > 0)
+                    .map(|(count, unit)| {
+                        format!("{} {}{}", count, unit, if count == 1 { "" } else { "s" })
+                    })
+                    .collect();
+                HumanList::from(&parts)
+                    .no_oxford_comma()
+                    .write_into(w, Style::Full)
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! [`wasm-bindgen`](https://docs.rs/wasm-bindgen) bindings, enabled with the
+//! `wasm` feature.
+//!
+//! Each function returns the concise form as a JavaScript string:
+//!
+//! ```js
+//! import { human_size, human_ago_unix_ms } from "humanly";
+//!
+//! human_size(5242880, false);            // "5 MiB"
+//! human_ago_unix_ms(Date.now() - 3e5);   // "5m ago"
+//! ```
+//!
+//! JavaScript numbers are `f64`; values that don't fit the underlying type
+//! (negative sizes, NaN) render as "-".
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{HumanDuration, HumanNumber, HumanSize, HumanTime};
+
+/// A byte count: "5 MiB", or "5.2 MB" with `decimal`.
+#[wasm_bindgen]
+pub fn human_size(bytes: f64, decimal: bool) -> String {
+    if !(bytes >= 0.0 && bytes <= u64::MAX as f64) {
+        return "-".to_string();
+    }
+    let size = HumanSize::from(bytes as u64);
+    if decimal {
+        size.decimal().concise()
+    } else {
+        size.binary().concise()
+    }
+}
+
+/// A number: "1.8M".
+#[wasm_bindgen]
+pub fn human_number(number: f64) -> String {
+    HumanNumber::from(number).concise()
+}
+
+/// A duration in seconds: "1h 30m".
+#[wasm_bindgen]
+pub fn human_time_secs(secs: f64) -> String {
+    match Duration::try_from_secs_f64(secs) {
+        Ok(duration) => HumanTime::from(duration).concise(),
+        Err(_) => "-".to_string(),
+    }
+}
+
+/// The time since a Unix timestamp in milliseconds, as from `Date.now()`:
+/// "5m ago".
+#[wasm_bindgen]
+pub fn human_ago_unix_ms(unix_ms: f64) -> String {
+    match (from_unix_ms(unix_ms), from_unix_ms(now_unix_ms())) {
+        (Some(time), Some(now)) => HumanDuration::from(Some(time)).as_of(now).concise(),
+        _ => "-".to_string(),
+    }
+}
+
+fn from_unix_ms(ms: f64) -> Option<SystemTime> {
+    let offset = Duration::try_from_secs_f64(ms.abs() / 1_000.0).ok()?;
+    if ms < 0.0 {
+        UNIX_EPOCH.checked_sub(offset)
+    } else {
+        UNIX_EPOCH.checked_add(offset)
+    }
+}
+
+// `SystemTime::now` panics on wasm32-unknown-unknown, so ask JavaScript.
+#[cfg(target_arch = "wasm32")]
+fn now_unix_ms() -> f64 {
+    js_sys::Date::now()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn now_unix_ms() -> f64 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(after) => after.as_secs_f64() * 1_000.0,
+        Err(err) => -err.duration().as_secs_f64() * 1_000.0,
+    }
+}
@@ -0,0 +1,105 @@
+/* -------------------- Number spelling -------------------- */
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const SCALES: [&str; 7] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+];
+
+// 0 < n < 1000: "forty-two", "one hundred ten"
+fn spell_below_thousand(n: u64, words: &mut Vec<String>) {
+    let hundreds = n / 100;
+    let rest = n % 100;
+
+    if hundreds > 0 {
+        words.push(ONES[hundreds as usize].to_string());
+        words.push("hundred".to_string());
+    }
+    if rest >= 20 {
+        let tens = TENS[(rest / 10) as usize];
+        match rest % 10 {
+            0 => words.push(tens.to_string()),
+            ones => words.push(format!("{}-{}", tens, ONES[ones as usize])),
+        }
+    } else if rest > 0 {
+        words.push(ONES[rest as usize].to_string());
+    }
+}
+
+/// Spells a whole number in American English without "and":
+/// 1_234 → "one thousand two hundred thirty-four".
+pub(crate) fn spell_cardinal(n: u64) -> String {
+    if n == 0 {
+        return ONES[0].to_string();
+    }
+
+    let mut groups = Vec::new();
+    let mut rest = n;
+    while rest > 0 {
+        groups.push(rest % 1000);
+        rest /= 1000;
+    }
+
+    let mut words = Vec::new();
+    for (scale, &group) in groups.iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        spell_below_thousand(group, &mut words);
+        if scale > 0 {
+            words.push(SCALES[scale].to_string());
+        }
+    }
+    words.join(" ")
+}
+
+/// "twenty-one" → "twenty-first", "one hundred" → "one hundredth".
+pub(crate) fn spell_ordinal(n: u64) -> String {
+    let cardinal = spell_cardinal(n);
+    let split = cardinal.rfind([' ', '-']).map_or(0, |i| i + 1);
+    let (head, last) = cardinal.split_at(split);
+
+    let last = match last {
+        "one" => "first".to_string(),
+        "two" => "second".to_string(),
+        "three" => "third".to_string(),
+        "five" => "fifth".to_string(),
+        "eight" => "eighth".to_string(),
+        "nine" => "ninth".to_string(),
+        "twelve" => "twelfth".to_string(),
+        tens if tens.ends_with('y') => format!("{}ieth", &tens[..tens.len() - 1]),
+        other => format!("{}th", other),
+    };
+    format!("{}{}", head, last)
+}
@@ -0,0 +1,50 @@
+/*
+ * Exercises the C bindings. From the repository root:
+ *
+ *   cargo rustc --release --features ffi --crate-type staticlib
+ *   cc -Iinclude tests/ffi/test.c target/release/libhumanly.a -lpthread -ldl -lm -o target/ffi-test
+ *   ./target/ffi-test
+ */
+#include <assert.h>
+#include <math.h>
+#include <stdio.h>
+#include <string.h>
+#include <time.h>
+
+#include "humanly.h"
+
+#define EXPECT(call, expected)                                          \
+    do {                                                                \
+        char buf[64];                                                   \
+        size_t n = call;                                                \
+        if (strcmp(buf, expected) != 0 || n != strlen(expected)) {      \
+            fprintf(stderr, "%s: got \"%s\" (%zu), want \"%s\"\n",      \
+                    #call, buf, n, expected);                           \
+            return 1;                                                   \
+        }                                                               \
+    } while (0)
+
+int main(void) {
+    EXPECT(humanly_size(5242880, buf, sizeof buf), "5 MiB");
+    EXPECT(humanly_number(1800000, buf, sizeof buf), "1.8M");
+    EXPECT(humanly_number(NAN, buf, sizeof buf), "-");
+    EXPECT(humanly_time_secs(5400, buf, sizeof buf), "1h 30m");
+    EXPECT(humanly_time_secs(-1, buf, sizeof buf), "-");
+    EXPECT(humanly_ago_unix_secs((int64_t)time(NULL) - 300, buf, sizeof buf), "5m ago");
+
+    /* Too small: the required length comes back and only a NUL is written. */
+    char small[4] = "xyz";
+    size_t needed = humanly_size(5242880, small, sizeof small);
+    assert(needed == strlen("5 MiB"));
+    assert(small[0] == '\0');
+
+    /* A null buffer only asks for the length. */
+    assert(humanly_number(1800000, NULL, 0) == strlen("1.8M"));
+
+    char exact[6];
+    assert(humanly_size(5242880, exact, sizeof exact) == 5);
+    assert(strcmp(exact, "5 MiB") == 0);
+
+    puts("ok");
+    return 0;
+}
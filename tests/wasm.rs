@@ -0,0 +1,30 @@
+//! Run with `wasm-pack test --node -- --features wasm`.
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use humanly::wasm::{human_ago_unix_ms, human_number, human_size, human_time_secs};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn size() {
+    assert_eq!(human_size(5_242_880.0, false), "5 MiB");
+    assert_eq!(human_size(5_242_880.0, true), "5.2 MB");
+    assert_eq!(human_size(-1.0, false), "-");
+}
+
+#[wasm_bindgen_test]
+fn number() {
+    assert_eq!(human_number(1_800_000.0), "1.8M");
+}
+
+#[wasm_bindgen_test]
+fn time_secs() {
+    assert_eq!(human_time_secs(5_400.0), "1h 30m");
+    assert_eq!(human_time_secs(f64::NAN), "-");
+}
+
+#[wasm_bindgen_test]
+fn ago_unix_ms() {
+    let now = js_sys::Date::now();
+    assert_eq!(human_ago_unix_ms(now - 300_000.0), "5m ago");
+    assert_eq!(human_ago_unix_ms(f64::INFINITY), "-");
+}